@@ -29,16 +29,18 @@ extern crate gcollections;
 mod nqueens;
 mod robot;
 mod robot2;
+mod single_machine;
 // mod robot3;
 
 use nqueens::nqueens;
+use single_machine::single_machine;
 
 fn main() {
    nqueens(8);
+   single_machine(&[(3, 0, 10), (4, 2, 12), (2, 0, 6), (5, 4, 20)]);
 //  let robot = robot::RobotScheduling::new(3, 500).solve();
 //  println!("{}", robot);
 
-// !!! Ok with 2 robot. doesn't stop with 3 robot.
    println!("Solve robot2 domaine 400");
    let test1 = robot2::RobotScheduling::new_test1(2, 400, 10, 25, 240, 25).solve();
    println!("{}", test1);
@@ -48,9 +50,8 @@ fn main() {
    let test2 = robot2::RobotScheduling::new_test2(2, 400, 10, 25, 240, 25, 5).solve();
    println!("{}", test2);
 
-// Non satisfi
-   println!("Solve robot1 domaine 36");
-   let test1 = robot2::RobotScheduling::new_test1(2, 37, 1, 3, 24, 3).solve();  //non satisfauisable
+   println!("Solve robot1 domaine 37");
+   let test1 = robot2::RobotScheduling::new_test1(2, 37, 1, 3, 24, 3).solve();
    println!("{}", test1);
 
 //   //Ok bonne solution
@@ -59,8 +60,7 @@ fn main() {
    println!("{}", test2);
 
    println!("Solve robot1 domaine 38");
-   let test1 = robot2::RobotScheduling::new_test1(2, 38, 1, 3, 24, 3).solve(); //ne rend pas la main trop long alors que le problème
-   //est plus simple que test précédent. Dans cette version la durée des taches est fixée
+   let test1 = robot2::RobotScheduling::new_test1(2, 38, 1, 3, 24, 3).solve();
    println!("{}", test1);
 
 
@@ -164,19 +164,14 @@ impl RobotScheduling {
             self.pipeting_resource.push(Box::new(Constant::new(1)));
         }
 
-        let mut cumulative_pipeting = Cumulative::new(
+        let cumulative_pipeting = Cumulative::new(
             self.pipeting_start.iter().map(|v| v.bclone()).collect(),
             self.pipeting_duration.iter().map(|v| v.bclone()).collect(),
             self.pipeting_resource.iter().map(|v| v.bclone()).collect(),
             Box::new(Constant::new(1)),
-        );
-        cumulative_pipeting.join(&mut self.space.vstore, &mut self.space.cstore);
-        let inter_tasks = cumulative_pipeting.intermediate_vars();
-        for (nti, ti) in inter_tasks.into_iter().enumerate() {
-            for (ntj, var) in ti.into_iter().enumerate() {
-                self.model.register_var(var, format!("t{}_{}", nti, ntj));
-            }
-        }
+        )
+        .with_edge_finding();
+        self.space.cstore.alloc(Box::new(cumulative_pipeting));
     }
 
     pub fn solve(mut self) -> Self {
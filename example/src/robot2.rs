@@ -135,7 +135,7 @@ impl Robot {
 
     pub fn get_pipeting_duration_at_rank(&self, i: usize) -> Var<VStore> {
         match self.robot_type {
-            RobotType::Simple => Box::new(Identity::new(self.durations[i])),
+            RobotType::Simple => Box::new(Constant::new(self.durations[i] as isize)),
             RobotType::Duration { ref vdurations, .. } => vdurations[i].bclone(),
         }
     }
@@ -330,20 +330,14 @@ impl RobotScheduling {
             self.pipeting_resource.push(Box::new(Constant::new(1)));
         }
 
-        let mut cumulative_pipeting = Cumulative::new(
+        let cumulative_pipeting = Cumulative::new(
             self.pipeting_start.iter().map(|v| v.bclone()).collect(),
             self.pipeting_duration.iter().map(|v| v.bclone()).collect(),
             self.pipeting_resource.iter().map(|v| v.bclone()).collect(),
             Box::new(Constant::new(1)),
-        );
-        cumulative_pipeting.join(&mut self.space.vstore, &mut self.space.cstore);
-
-        let inter_tasks = cumulative_pipeting.intermediate_vars();
-        for (nti, ti) in inter_tasks.into_iter().enumerate() {
-            for (ntj, var) in ti.into_iter().enumerate() {
-                self.model.register_var(var, format!("t{}_{}", nti, ntj));
-            }
-        }
+        )
+        .with_edge_finding();
+        self.space.cstore.alloc(Box::new(cumulative_pipeting));
         //    self.space.vstore.display(&self.model);
         //    self.space.cstore.display(&self.model);
         println!("\n");
@@ -0,0 +1,62 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// This example schedules tasks on a single machine (a resource of capacity one) with the `Disjunctive` propagator.
+// Each task has a fixed duration, a release date and a deadline, and the machine processes one task at a time.
+
+use gcollections::ops::*;
+use interval::interval_set::*;
+use interval::ops::Range;
+use pcp::concept::*;
+use pcp::kernel::*;
+use pcp::propagators::*;
+use pcp::search::search_tree_visitor::Status::*;
+use pcp::search::*;
+use pcp::term::*;
+use pcp::variable::ops::*;
+
+/// `tasks` are given as `(duration, release, deadline)`.
+pub fn single_machine(tasks: &[(isize, isize, isize)]) {
+    let mut space = FDSpace::empty();
+
+    let mut starts = vec![];
+    let mut durations = vec![];
+    for &(duration, release, deadline) in tasks {
+        // The task starts after its release date and ends before its deadline.
+        starts.push(Box::new(space.vstore.alloc(IntervalSet::new(release, deadline - duration))) as Var<VStore>);
+        durations.push(Box::new(Constant::new(duration)) as Var<VStore>);
+    }
+    space.cstore.alloc(Box::new(Disjunctive::new(starts, durations)));
+
+    // Search step.
+    let mut search = one_solution_engine();
+    search.start(&space);
+    let (frozen_space, status) = search.enter(space);
+    let space = frozen_space.unfreeze();
+
+    // Print result.
+    match status {
+        Satisfiable => {
+            print!("The {} tasks can be scheduled on a single machine, with the starting dates:\n[", tasks.len());
+            for dom in space.vstore.iter() {
+                print!("{}, ", dom.lower());
+            }
+            println!("]");
+        }
+        Unsatisfiable => println!("The {} tasks cannot be scheduled on a single machine.", tasks.len()),
+        EndOfSearch => println!("Search terminated or was interrupted."),
+        Unknown(_) => unreachable!(
+      "After the search step, the problem instance should be either satisfiable or unsatisfiable."),
+    }
+}
@@ -20,7 +20,7 @@ use term::ops::*;
 use propagation::events::*;
 use propagation::concept::*;
 use interval::ops::Range;
-use num::{Signed, Integer};
+use num::{Signed, Integer, NumCast};
 use std::ops::*;
 use std::fmt::Debug;
 
@@ -29,11 +29,12 @@ pub use variable::concept::*;
 pub trait IntBound:
   Integer + Clone + Debug
   + Signed // Due to the lack of Subtraction in term/
+  + NumCast
 {}
 
 impl<R> IntBound for R where
   R: Integer + Clone + Debug
-  + Signed
+  + Signed + NumCast
 {}
 
 pub trait IntDomain:
@@ -16,13 +16,17 @@ pub mod boolean;
 pub mod boolean_neg;
 pub mod conjunction;
 pub mod disjunction;
+pub mod negation;
 pub mod ops;
+pub mod reified;
 
 pub use logic::boolean::*;
 pub use logic::boolean_neg::*;
 pub use logic::conjunction::*;
 pub use logic::disjunction::*;
+pub use logic::negation::*;
 pub use logic::ops::*;
+pub use logic::reified::*;
 
 use gcollections::*;
 use concept::*;
@@ -0,0 +1,101 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `Negation` is the negation of a propagator without a dedicated one, typically a global constraint.
+//! It does not filter the domains: it only fails once the propagator is entailed. It is thus a correct negation when all the variables are assigned, but a weak one during the search.
+
+use trilean::SKleene;
+use trilean::SKleene::*;
+use kernel::*;
+use model::*;
+use logic::NotFormula;
+use propagation::*;
+use propagation::events::*;
+use concept::*;
+
+/// The negation of `p`, returned by `NotFormula::not` for the propagators without a dedicated negation.
+/// It is a weak negation: it never filters the domains and only fails once `p` is entailed, so it is only a check when all the variables are assigned.
+#[derive(Clone, Debug)]
+pub struct Negation<P> {
+  p: P
+}
+
+impl<P> Negation<P>
+{
+  pub fn new(p: P) -> Self {
+    Negation { p }
+  }
+}
+
+impl<P> DisplayStateful<Model> for Negation<P> where
+ P: DisplayStateful<Model>
+{
+  fn display(&self, model: &Model) {
+    print!("not (");
+    self.p.display(model);
+    print!(")");
+  }
+}
+
+impl<VStore, P> NotFormula<VStore> for Negation<P> where
+ P: PropagatorConcept<VStore, FDEvent> + Clone + 'static
+{
+  fn not(&self) -> Formula<VStore> {
+    Box::new(self.p.clone())
+  }
+}
+
+impl<VStore, P> Subsumption<VStore> for Negation<P> where
+ P: Subsumption<VStore>
+{
+  fn is_subsumed(&self, store: &VStore) -> SKleene {
+    !self.p.is_subsumed(store)
+  }
+}
+
+impl<VStore, P> Propagator<VStore> for Negation<P> where
+ P: Subsumption<VStore>
+{
+  fn propagate(&mut self, store: &mut VStore) -> bool {
+    self.p.is_subsumed(store) != True
+  }
+}
+
+impl<P> PropagatorDependencies<FDEvent> for Negation<P> where
+ P: PropagatorDependencies<FDEvent>
+{
+  fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+    self.p.dependencies()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use interval::interval::*;
+  use propagators::test::*;
+  use propagators::Distinct;
+
+  #[test]
+  fn negation_test() {
+    let one = (1, 1).to_interval();
+    let two = (2, 2).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+    let not_distinct = |vars| Negation::new(Distinct::new(vars));
+
+    nary_propagator_test(1, not_distinct, vec![dom0_1, dom0_1], Unknown, Unknown, vec![], true);
+    nary_propagator_test(2, not_distinct, vec![one, two], False, False, vec![], false);
+    nary_propagator_test(3, not_distinct, vec![one, one], True, True, vec![], true);
+  }
+}
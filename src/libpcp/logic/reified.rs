@@ -0,0 +1,508 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reification links the entailment of a propagator to a boolean variable: `Reified` is the equivalence `b <-> p` and `HalfReified` the implication `b -> p`, with `b` taking its value in `{0, 1}`.
+//!
+//! They only rely on `Subsumption::is_subsumed` to fix `b` and on `NotFormula::not` to propagate the negation of `p` when `b = 0`, so any propagator implementing its negation can be reified.
+//! The propagators without a dedicated negation, such as `XEqYMulZ` and the global constraints (`Circuit`, `Cumulative`, `GlobalCardinality`, ...), return a `Negation` which only fails once `p` is entailed.
+//! `Reified` of these propagators therefore only checks `p` when `b = 0`: the domains are not filtered until every variable of `p` is assigned.
+
+use trilean::SKleene;
+use trilean::SKleene::*;
+use kernel::*;
+use model::*;
+use logic::{NotFormula, Conjunction};
+use propagation::*;
+use propagation::events::*;
+use propagators::XEqY;
+use term::Constant;
+use gcollections::*;
+use std::fmt::{Debug, Formatter, Result};
+use concept::*;
+
+/// Restricts `b` to `{0, 1}` and returns its new domain, or `None` if it became empty.
+fn read_boolean<VStore, Domain, Bound>(b: &mut Var<VStore>, store: &mut VStore) -> Option<Domain> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound
+{
+  let dom = b.read(store).intersection(&Domain::new(Bound::zero(), Bound::one()));
+  if b.update(store, dom.clone()) { Some(dom) }
+  else { None }
+}
+
+/// The value of `b` if it is assigned to `0` or `1`, `Err` if it cannot be a boolean.
+fn boolean_value<VStore, Domain, Bound>(b: &Var<VStore>, store: &VStore) -> ::std::result::Result<Option<bool>, ()> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound
+{
+  let dom = b.read(store).intersection(&Domain::new(Bound::zero(), Bound::one()));
+  if dom.is_empty() { Err(()) }
+  else if dom.is_singleton() { Ok(Some(dom.lower() == Bound::one())) }
+  else { Ok(None) }
+}
+
+/// `b <-> p`: `p` is propagated if `b = 1`, its negation if `b = 0`, and `b` is assigned as soon as `p` is entailed or disentailed.
+pub struct Reified<VStore, P> {
+  b: Var<VStore>,
+  p: P,
+  not_p: Formula<VStore>,
+  /// If true, this is the equivalence `b <-> not p`.
+  negated: bool
+}
+
+impl<VStore, P> Reified<VStore, P> where
+ P: NotFormula<VStore>
+{
+  pub fn new(b: Var<VStore>, p: P) -> Self {
+    let not_p = p.not();
+    Reified {
+      b,
+      p,
+      not_p,
+      negated: false
+    }
+  }
+}
+
+impl<VStore, P> Debug for Reified<VStore, P> where
+ P: Debug
+{
+  fn fmt(&self, fmt: &mut Formatter) -> Result {
+    fmt.debug_struct("Reified")
+      .field("b", &self.b)
+      .field("p", &self.p)
+      .field("not_p", &self.not_p)
+      .field("negated", &self.negated)
+      .finish()
+  }
+}
+
+impl<VStore, P> Clone for Reified<VStore, P> where
+ VStore: Collection,
+ P: Clone
+{
+  fn clone(&self) -> Self {
+    Reified {
+      b: self.b.bclone(),
+      p: self.p.clone(),
+      not_p: self.not_p.bclone(),
+      negated: self.negated
+    }
+  }
+}
+
+impl<VStore, P> DisplayStateful<Model> for Reified<VStore, P> where
+ P: DisplayStateful<Model>
+{
+  fn display(&self, model: &Model) {
+    self.b.display(model);
+    print!(" <-> (");
+    if self.negated { self.not_p.display(model); }
+    else { self.p.display(model); }
+    print!(")");
+  }
+}
+
+impl<VStore, Domain, Bound, P> NotFormula<VStore> for Reified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain> + 'static,
+ Domain: IntDomain<Item=Bound> + 'static,
+ Bound: IntBound + 'static,
+ P: PropagatorConcept<VStore, FDEvent> + Clone + 'static
+{
+  /// `not (b <-> p)` is `b <-> not p`.
+  fn not(&self) -> Formula<VStore> {
+    let mut not_self = self.clone();
+    not_self.negated = !self.negated;
+    Box::new(not_self)
+  }
+}
+
+impl<VStore, P> Reified<VStore, P> where
+ P: Propagator<VStore> + Subsumption<VStore>
+{
+  /// Entailment of the formula equivalent to `b`.
+  fn entailment(&self, store: &VStore) -> SKleene {
+    if self.negated { self.not_p.is_subsumed(store) }
+    else { self.p.is_subsumed(store) }
+  }
+
+  /// Propagates the formula equivalent to `b` if `value` is true, its negation otherwise.
+  fn propagate_formula(&mut self, store: &mut VStore, value: bool) -> bool {
+    if value != self.negated { self.p.propagate(store) }
+    else { self.not_p.propagate(store) }
+  }
+}
+
+impl<VStore, Domain, Bound, P> Subsumption<VStore> for Reified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound,
+ P: Propagator<VStore> + Subsumption<VStore>
+{
+  fn is_subsumed(&self, store: &VStore) -> SKleene {
+    match boolean_value(&self.b, store) {
+      Err(()) => False,
+      Ok(Some(true)) => self.entailment(store),
+      Ok(Some(false)) => !self.entailment(store),
+      Ok(None) => Unknown
+    }
+  }
+}
+
+impl<VStore, Domain, Bound, P> Propagator<VStore> for Reified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound,
+ P: Propagator<VStore> + Subsumption<VStore>
+{
+  fn propagate(&mut self, store: &mut VStore) -> bool {
+    let b = match read_boolean(&mut self.b, store) {
+      Some(b) => b,
+      None => return false
+    };
+    if b.is_singleton() {
+      let value = b.lower() == Bound::one();
+      self.propagate_formula(store, value)
+    }
+    else {
+      match self.entailment(store) {
+        True => self.b.update(store, Domain::singleton(Bound::one())),
+        False => self.b.update(store, Domain::singleton(Bound::zero())),
+        Unknown => true
+      }
+    }
+  }
+}
+
+impl<VStore, P> PropagatorDependencies<FDEvent> for Reified<VStore, P> where
+ P: PropagatorDependencies<FDEvent>
+{
+  fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+    let mut deps = self.b.dependencies(FDEvent::Bound);
+    deps.append(&mut self.p.dependencies());
+    deps.append(&mut self.not_p.dependencies());
+    deps.sort();
+    deps.dedup();
+    deps
+  }
+}
+
+/// `b -> p`: `p` is propagated if `b = 1`, and `b` is assigned to `0` if `p` is disentailed.
+pub struct HalfReified<VStore, P> {
+  b: Var<VStore>,
+  p: P
+}
+
+impl<VStore, P> HalfReified<VStore, P>
+{
+  pub fn new(b: Var<VStore>, p: P) -> Self {
+    HalfReified { b, p }
+  }
+}
+
+impl<VStore, P> Debug for HalfReified<VStore, P> where
+ P: Debug
+{
+  fn fmt(&self, fmt: &mut Formatter) -> Result {
+    fmt.debug_struct("HalfReified")
+      .field("b", &self.b)
+      .field("p", &self.p)
+      .finish()
+  }
+}
+
+impl<VStore, P> Clone for HalfReified<VStore, P> where
+ VStore: Collection,
+ P: Clone
+{
+  fn clone(&self) -> Self {
+    HalfReified::new(self.b.bclone(), self.p.clone())
+  }
+}
+
+impl<VStore, P> DisplayStateful<Model> for HalfReified<VStore, P> where
+ P: DisplayStateful<Model>
+{
+  fn display(&self, model: &Model) {
+    self.b.display(model);
+    print!(" -> (");
+    self.p.display(model);
+    print!(")");
+  }
+}
+
+impl<VStore, Domain, Bound, P> NotFormula<VStore> for HalfReified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain> + 'static,
+ Domain: IntDomain<Item=Bound> + 'static,
+ Bound: IntBound + 'static,
+ P: NotFormula<VStore>
+{
+  /// `not (b -> p)` is `b = 1 /\ not p`.
+  fn not(&self) -> Formula<VStore> {
+    let one = Box::new(Constant::new(Bound::one())) as Var<VStore>;
+    Box::new(Conjunction::new(vec![
+      Box::new(XEqY::new(self.b.bclone(), one)),
+      self.p.not()
+    ]))
+  }
+}
+
+impl<VStore, Domain, Bound, P> Subsumption<VStore> for HalfReified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound,
+ P: Subsumption<VStore>
+{
+  fn is_subsumed(&self, store: &VStore) -> SKleene {
+    match boolean_value(&self.b, store) {
+      Err(()) => False,
+      Ok(Some(false)) => True,
+      Ok(Some(true)) => self.p.is_subsumed(store),
+      Ok(None) => {
+        if self.p.is_subsumed(store) == True { True }
+        else { Unknown }
+      }
+    }
+  }
+}
+
+impl<VStore, Domain, Bound, P> Propagator<VStore> for HalfReified<VStore, P> where
+ VStore: VStoreConcept<Item=Domain>,
+ Domain: IntDomain<Item=Bound>,
+ Bound: IntBound,
+ P: Propagator<VStore> + Subsumption<VStore>
+{
+  fn propagate(&mut self, store: &mut VStore) -> bool {
+    let b = match read_boolean(&mut self.b, store) {
+      Some(b) => b,
+      None => return false
+    };
+    if b.is_singleton() {
+      b.lower() == Bound::zero() || self.p.propagate(store)
+    }
+    else if self.p.is_subsumed(store) == False {
+      self.b.update(store, Domain::singleton(Bound::zero()))
+    }
+    else { true }
+  }
+}
+
+impl<VStore, P> PropagatorDependencies<FDEvent> for HalfReified<VStore, P> where
+ P: PropagatorDependencies<FDEvent>
+{
+  fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+    let mut deps = self.b.dependencies(FDEvent::Bound);
+    deps.append(&mut self.p.dependencies());
+    deps.sort();
+    deps.dedup();
+    deps
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use gcollections::ops::*;
+  use interval::interval::*;
+  use interval::interval_set::*;
+  use gcollections::VectorStack;
+  use propagation::events::FDEvent::*;
+  use propagators::test::*;
+  use propagators::{Count, Distinct, XEqYMulZ, XEqYPlusZ, XLessY, XNeqY};
+  use search::*;
+  use search::branching::*;
+  use search::engine::all_solution::*;
+  use search::engine::one_solution::*;
+  use search::monitor::*;
+  use search::propagation::*;
+  use search::statistics::*;
+  use variable::VStoreFD;
+  use variable::store::test::consume_delta;
+
+  // b <-> x < y
+  fn reified_less(mut vars: Vec<FDVar>) -> Reified<VStoreFD, XLessY<VStoreFD>> {
+    let y = vars.pop().unwrap();
+    let x = vars.pop().unwrap();
+    Reified::new(vars.pop().unwrap(), XLessY::new(x, y))
+  }
+
+  // b -> x < y
+  fn half_reified_less(mut vars: Vec<FDVar>) -> HalfReified<VStoreFD, XLessY<VStoreFD>> {
+    let y = vars.pop().unwrap();
+    let x = vars.pop().unwrap();
+    HalfReified::new(vars.pop().unwrap(), XLessY::new(x, y))
+  }
+
+  #[test]
+  fn reified_test() {
+    let zero = (0, 0).to_interval();
+    let one = (1, 1).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+    let dom0_3 = (0, 3).to_interval();
+    let dom0_5 = (0, 5).to_interval();
+    let dom0_10 = (0, 10).to_interval();
+    let dom3_10 = (3, 10).to_interval();
+    let dom5_10 = (5, 10).to_interval();
+
+    nary_propagator_test(1, reified_less, vec![dom0_1, dom0_10, dom0_10], Unknown, Unknown, vec![], true);
+    nary_propagator_test(2, reified_less, vec![dom0_1, dom0_3, dom5_10], Unknown, True, vec![(0, Assignment)], true);
+    nary_propagator_test(3, reified_less, vec![dom0_1, dom5_10, dom0_5], Unknown, True, vec![(0, Assignment)], true);
+    nary_propagator_test(4, reified_less, vec![one, dom0_10, dom0_10], Unknown, Unknown, vec![(1, Bound), (2, Bound)], true);
+    nary_propagator_test(5, reified_less, vec![zero, dom0_5, dom3_10], Unknown, Unknown, vec![(1, Bound), (2, Bound)], true);
+    nary_propagator_test(6, reified_less, vec![one, dom5_10, dom0_5], False, False, vec![], false);
+    nary_propagator_test(7, reified_less, vec![zero, dom0_3, dom5_10], False, False, vec![], false);
+    nary_propagator_test(8, reified_less, vec![(2, 3).to_interval(), dom0_10, dom0_10], False, False, vec![], false);
+    nary_propagator_test(9, reified_less, vec![(0, 4).to_interval(), dom0_10, dom0_10], Unknown, Unknown, vec![(0, Bound)], true);
+  }
+
+  #[test]
+  fn reified_not_test() {
+    let one = (1, 1).to_interval();
+    let zero = (0, 0).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+    // b <-> not (x < y)
+    let not_reified_less = |vars| reified_less(vars).not();
+
+    formula_test(1, not_reified_less, vec![one, (0, 5).to_interval(), (3, 10).to_interval()], Unknown, Unknown, vec![(1, Bound), (2, Bound)], true);
+    formula_test(2, not_reified_less, vec![dom0_1, (0, 3).to_interval(), (5, 10).to_interval()], Unknown, True, vec![(0, Assignment)], true);
+    formula_test(3, not_reified_less, vec![zero, (5, 10).to_interval(), (0, 5).to_interval()], False, False, vec![], false);
+    formula_test(4, |vars| reified_less(vars).not().not(), vec![dom0_1, (0, 3).to_interval(), (5, 10).to_interval()], Unknown, True, vec![(0, Assignment)], true);
+  }
+
+  #[test]
+  fn half_reified_test() {
+    let zero = (0, 0).to_interval();
+    let one = (1, 1).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+    let dom0_3 = (0, 3).to_interval();
+    let dom0_5 = (0, 5).to_interval();
+    let dom0_10 = (0, 10).to_interval();
+    let dom5_10 = (5, 10).to_interval();
+
+    nary_propagator_test(1, half_reified_less, vec![dom0_1, dom0_10, dom0_10], Unknown, Unknown, vec![], true);
+    nary_propagator_test(2, half_reified_less, vec![dom0_1, dom0_3, dom5_10], True, True, vec![], true);
+    nary_propagator_test(3, half_reified_less, vec![dom0_1, dom5_10, dom0_5], Unknown, True, vec![(0, Assignment)], true);
+    nary_propagator_test(4, half_reified_less, vec![one, dom0_10, dom0_10], Unknown, Unknown, vec![(1, Bound), (2, Bound)], true);
+    nary_propagator_test(5, half_reified_less, vec![zero, dom5_10, dom0_5], True, True, vec![], true);
+    nary_propagator_test(6, half_reified_less, vec![one, dom5_10, dom0_5], False, False, vec![], false);
+    // not (b -> x < y) is b = 1 /\ x >= y.
+    formula_test(7, |vars| half_reified_less(vars).not(), vec![dom0_1, dom0_10, dom0_10], Unknown, Unknown, vec![(0, Assignment)], true);
+  }
+
+  // b <-> x = y + z
+  fn reified_plus(mut vars: Vec<FDVar>) -> Reified<VStoreFD, XEqYPlusZ<VStoreFD>> {
+    let z = vars.pop().unwrap();
+    let y = vars.pop().unwrap();
+    let x = vars.pop().unwrap();
+    Reified::new(vars.pop().unwrap(), XEqYPlusZ::new(x, y, z))
+  }
+
+  // b <-> x = y * z
+  fn reified_mul(mut vars: Vec<FDVar>) -> Reified<VStoreFD, XEqYMulZ<VStoreFD>> {
+    let z = vars.pop().unwrap();
+    let y = vars.pop().unwrap();
+    let x = vars.pop().unwrap();
+    Reified::new(vars.pop().unwrap(), XEqYMulZ::new(x, y, z))
+  }
+
+  // b <-> count([x1, x2], 1, c)
+  fn reified_count(mut vars: Vec<FDVar>) -> Reified<VStoreFD, Count<VStoreFD>> {
+    let c = vars.pop().unwrap();
+    let x2 = vars.pop().unwrap();
+    let x1 = vars.pop().unwrap();
+    Reified::new(vars.pop().unwrap(), Count::new(vec![x1, x2], 1, c))
+  }
+
+  #[test]
+  fn reified_arithmetic_test() {
+    let zero = (0, 0).to_interval();
+    let one = (1, 1).to_interval();
+    let two = (2, 2).to_interval();
+    let three = (3, 3).to_interval();
+    let five = (5, 5).to_interval();
+    let six = (6, 6).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+
+    nary_propagator_test(1, reified_plus, vec![one, five, two, (0, 5).to_interval()], Unknown, True, vec![(3, Assignment)], true);
+    nary_propagator_test(2, reified_plus, vec![zero, (5, 6).to_interval(), two, three], Unknown, True, vec![(1, Assignment)], true);
+    nary_propagator_test(3, reified_plus, vec![dom0_1, five, two, three], Unknown, True, vec![(0, Assignment)], true);
+    nary_propagator_test(4, reified_plus, vec![zero, five, two, three], False, False, vec![], false);
+
+    nary_propagator_test(5, reified_mul, vec![one, (0, 10).to_interval(), two, three], Unknown, True, vec![(1, Assignment)], true);
+    nary_propagator_test(6, reified_mul, vec![zero, (0, 10).to_interval(), two, three], Unknown, Unknown, vec![], true);
+    nary_propagator_test(7, reified_mul, vec![zero, six, two, three], False, False, vec![], false);
+    nary_propagator_test(8, reified_mul, vec![dom0_1, five, two, three], Unknown, True, vec![(0, Assignment)], true);
+  }
+
+  #[test]
+  fn reified_global_test() {
+    let zero = (0, 0).to_interval();
+    let one = (1, 1).to_interval();
+    let dom0_1 = (0, 1).to_interval();
+    let dom1_2 = (1, 2).to_interval();
+
+    nary_propagator_test(1, reified_count, vec![one, one, dom0_1, one], Unknown, True, vec![(2, Assignment)], true);
+    nary_propagator_test(2, reified_count, vec![zero, one, dom0_1, dom1_2], Unknown, Unknown, vec![], true);
+    // The number of occurrences is 1, thus `c = 2`.
+    nary_propagator_test(3, reified_count, vec![zero, one, zero, dom1_2], Unknown, True, vec![(3, Assignment)], true);
+    nary_propagator_test(4, reified_count, vec![dom0_1, one, one, one], Unknown, True, vec![(0, Assignment)], true);
+  }
+
+  /// Same as `nary_propagator_test` for formulas obtained with `NotFormula::not`.
+  fn formula_test<FnProp>(test_num: u32, make_prop: FnProp, doms: Vec<Interval<isize>>,
+    before: SKleene, after: SKleene, delta_expected: Vec<(usize, FDEvent)>, propagate_success: bool) where
+   FnProp: FnOnce(Vec<FDVar>) -> Formula<VStoreFD>
+  {
+    println!("Test number {}", test_num);
+    let mut vstore = VStoreFD::empty();
+    let vars = doms.into_iter()
+      .map(|d| Box::new(vstore.alloc(d)) as FDVar)
+      .collect();
+    let mut prop = make_prop(vars);
+    assert_eq!(prop.is_subsumed(&vstore), before);
+    assert_eq!(prop.propagate(&mut vstore), propagate_success);
+    if propagate_success {
+      consume_delta(&mut vstore, delta_expected);
+    }
+    assert_eq!(prop.is_subsumed(&vstore), after);
+  }
+
+  #[test]
+  fn reified_search_test() {
+    // b1 <-> x < y, b2 <-> y < z and b1 != b2 over the permutations of [0, 2]: they have a single ascent except 012 and 210.
+    let mut space = FDSpace::empty();
+    let mut alloc = |lb, ub| Box::new(space.vstore.alloc((lb, ub).to_interval_set())) as Var<VStore>;
+    let x = alloc(0, 2);
+    let y = alloc(0, 2);
+    let z = alloc(0, 2);
+    let b1 = alloc(0, 1);
+    let b2 = alloc(0, 1);
+    space.cstore.alloc(Box::new(Distinct::new(vec![x.bclone(), y.bclone(), z.bclone()])));
+    space.cstore.alloc(Box::new(Reified::new(b1.bclone(), XLessY::new(x, y.bclone()))));
+    space.cstore.alloc(Box::new(Reified::new(b2.bclone(), XLessY::new(y, z))));
+    space.cstore.alloc(Box::new(XNeqY::new(b1, b2)));
+    let mut statistics = Statistics::new();
+    {
+      let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+        AllSolution::new(OneSolution::new(Monitor::new(&mut statistics,
+          Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)))));
+      search.start(&space);
+      let (_, status) = search.enter(space);
+      assert_eq!(status, Status::EndOfSearch);
+    }
+    assert_eq!(statistics.num_solution, 6 - 2);
+  }
+}
@@ -85,14 +85,32 @@ impl Model
   }
 
   pub fn display_global<VStore>(&self, name: &str, args: &Vec<Var<VStore>>) {
+    self.display_call(name, args);
+    print!(" (decomposed)");
+  }
+
+  /// Displays the array `args` as `[x1, x2, ...]`.
+  pub fn display_array<VStore>(&self, args: &[Var<VStore>]) {
+    print!("[");
+    for (i, arg) in args.iter().enumerate() {
+      if i > 0 {
+        print!(", ");
+      }
+      arg.display(self);
+    }
+    print!("]");
+  }
+
+  /// Same as `display_global` but for constraints implemented by a dedicated propagator.
+  /// An empty `args` is displayed as `name()`.
+  pub fn display_call<VStore>(&self, name: &str, args: &[Var<VStore>]) {
     print!("{}(", name);
-    let mut i = 0;
-    while i < args.len() - 1 {
-      args[i].display(self);
-      print!(", ");
-      i += 1;
+    for (i, arg) in args.iter().enumerate() {
+      if i > 0 {
+        print!(", ");
+      }
+      arg.display(self);
     }
-    args[i].display(self);
-    print!(") (decomposed)");
+    print!(")");
   }
 }
@@ -30,3 +30,20 @@ pub trait PropagatorDependencies<Event>
   /// Each event on a variable that can change the result of the `is_subsumed` method should be listed here.
   fn dependencies(&self) -> Vec<(usize, Event)>;
 }
+
+/// Information on the propagators of a constraint store, for the search heuristics learning from failures.
+pub trait PropagatorIncidence
+{
+  /// Index of the propagator that failed during the last consistency check, if any.
+  fn failed_propagator(&self) -> Option<usize>;
+
+  /// For each of the `num_vars` variables, the indexes of the active propagators depending on it.
+  fn incidence(&self, num_vars: usize) -> Vec<Vec<usize>>;
+}
+
+/// Variables of a constraint store reduced during propagation, for the search heuristics learning from domain reductions.
+pub trait ModifiedVariables
+{
+  /// Indexes of the variables modified during the last consistency check, in increasing order.
+  fn modified_variables(&self) -> Vec<usize>;
+}
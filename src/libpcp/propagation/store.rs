@@ -34,6 +34,8 @@ pub struct Store<VStore, Event, Reactor, Scheduler> {
     active: BitSet,
     reactor: Reactor,
     scheduler: Scheduler,
+    failed: Option<usize>,
+    modified: BitSet,
 }
 
 impl<VStore, Event, R, S> Empty for Store<VStore, Event, R, S>
@@ -48,6 +50,8 @@ where
             active: BitSet::new(),
             reactor: Reactor::new(0, 0),
             scheduler: Scheduler::new(0),
+            failed: None,
+            modified: BitSet::new(),
         }
     }
 }
@@ -123,6 +127,8 @@ where
     S: Scheduler,
 {
     fn prepare(&mut self, vstore: &VStore) {
+        self.failed = None;
+        self.modified.clear();
         self.init_reactor(vstore);
         self.init_scheduler();
     }
@@ -153,6 +159,7 @@ where
         while !self.scheduler.is_empty() && consistent {
             while let Some(p_idx) = self.scheduler.pop() {
                 if !self.propagate_one(p_idx, vstore) {
+                    self.failed = Some(p_idx);
                     consistent = false;
                     break;
                 }
@@ -190,6 +197,7 @@ where
 
     fn react(&mut self, vstore: &mut VStore) {
         for (v, ev) in vstore.drain_delta() {
+            self.modified.insert(v);
             let reactions = self.reactor.react(v, ev);
             for p in reactions.into_iter() {
                 self.scheduler.schedule(p);
@@ -257,6 +265,31 @@ where
     }
 }
 
+impl<VStore, Event, R, S> PropagatorIncidence for Store<VStore, Event, R, S> {
+    fn failed_propagator(&self) -> Option<usize> {
+        self.failed
+    }
+
+    fn incidence(&self, num_vars: usize) -> Vec<Vec<usize>> {
+        let mut incidence = vec![vec![]; num_vars];
+        for p_idx in self.active.iter() {
+            for (v, _) in self[p_idx].dependencies() {
+                // A variable can be listed with several events.
+                if incidence[v].last() != Some(&p_idx) {
+                    incidence[v].push(p_idx);
+                }
+            }
+        }
+        incidence
+    }
+}
+
+impl<VStore, Event, R, S> ModifiedVariables for Store<VStore, Event, R, S> {
+    fn modified_variables(&self) -> Vec<usize> {
+        self.modified.iter().collect()
+    }
+}
+
 impl<VStore, Event, R, S> Clone for Store<VStore, Event, R, S>
 where
     Event: EventIndex,
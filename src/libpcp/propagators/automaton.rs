@@ -0,0 +1,461 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic finite automata over integer symbols, used by the `Regular` propagator.
+//!
+//! A `Dfa` can be given explicitly by its transitions or built from a `Regex`:
+//!
+//! ```rust
+//! use pcp::propagators::automaton::*;
+//!
+//! // At most two `1` in a row: (0 | 1 0 | 1 1 0)* (1 | 1 1)?
+//! let ones = Regex::symbol(1).repeat(1, 2);
+//! let regex = Regex::symbol(0)
+//!     .or(ones.clone().concat(Regex::symbol(0)))
+//!     .star()
+//!     .concat(ones.optional());
+//! let dfa = regex.to_dfa();
+//! assert!(dfa.accepts(&[1, 1, 0, 1, 0, 0, 1, 1]));
+//! assert!(!dfa.accepts(&[0, 1, 1, 1]));
+//! ```
+
+use concept::*;
+use std::collections::HashMap;
+
+/// Automaton with states `0..num_states`. The transition function is partial: a missing transition rejects the word.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dfa<Bound> {
+    initial: usize,
+    // `transitions[q]` contains the pairs `(v, q')` sorted by `v`.
+    transitions: Vec<Vec<(Bound, usize)>>,
+    accepting: Vec<bool>,
+}
+
+impl<Bound> Dfa<Bound>
+where
+    Bound: Ord + Clone,
+{
+    /// `transitions` contains the triples `(q, v, q')` meaning that `q` goes to `q'` on the symbol `v`.
+    /// Precondition: all states are smaller than `num_states` and there is at most one transition for each pair `(q, v)`.
+    pub fn new(
+        num_states: usize,
+        initial: usize,
+        transitions: Vec<(usize, Bound, usize)>,
+        accepting: Vec<usize>,
+    ) -> Self {
+        assert!(initial < num_states, "Initial state of `Dfa` out of range.");
+        let mut dfa = Dfa {
+            initial,
+            transitions: vec![vec![]; num_states],
+            accepting: vec![false; num_states],
+        };
+        for (from, v, to) in transitions {
+            assert!(
+                from < num_states && to < num_states,
+                "Transition of `Dfa` out of range."
+            );
+            dfa.transitions[from].push((v, to));
+        }
+        for out in &mut dfa.transitions {
+            out.sort_by(|a, b| a.0.cmp(&b.0));
+            assert!(
+                out.windows(2).all(|w| w[0].0 != w[1].0),
+                "`Dfa` must have at most one transition per state and symbol."
+            );
+        }
+        for q in accepting {
+            assert!(q < num_states, "Accepting state of `Dfa` out of range.");
+            dfa.accepting[q] = true;
+        }
+        dfa
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.accepting.len()
+    }
+
+    pub fn initial(&self) -> usize {
+        self.initial
+    }
+
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.accepting[state]
+    }
+
+    /// Transitions `(v, q')` leaving `state`, sorted by `v`.
+    pub fn transitions(&self, state: usize) -> &[(Bound, usize)] {
+        &self.transitions[state]
+    }
+
+    pub fn next(&self, state: usize, symbol: &Bound) -> Option<usize> {
+        let out = &self.transitions[state];
+        out.binary_search_by(|t| t.0.cmp(symbol))
+            .ok()
+            .map(|i| out[i].1)
+    }
+
+    pub fn accepts(&self, word: &[Bound]) -> bool {
+        match word.iter().try_fold(self.initial, |q, v| self.next(q, v)) {
+            Some(q) => self.accepting[q],
+            None => false,
+        }
+    }
+
+    /// Automaton accepting the words over `alphabet` (sorted in increasing order) rejected by `self`.
+    /// The missing transitions on `alphabet` go to a new rejecting state, then the accepting and rejecting states are swapped.
+    pub fn complement(&self, alphabet: &[Bound]) -> Self {
+        let sink = self.num_states();
+        let mut transitions = self.transitions.clone();
+        transitions.push(vec![]);
+        for out in &mut transitions {
+            for v in alphabet {
+                if let Err(i) = out.binary_search_by(|t| t.0.cmp(v)) {
+                    out.insert(i, (v.clone(), sink));
+                }
+            }
+        }
+        let mut accepting: Vec<bool> = self.accepting.iter().map(|a| !a).collect();
+        accepting.push(true);
+        Dfa {
+            initial: self.initial,
+            transitions,
+            accepting,
+        }
+    }
+}
+
+/// Regular expressions over integer symbols.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Regex<Bound> {
+    /// The empty word.
+    Epsilon,
+    /// Any of the symbols (the empty language if there is none).
+    Symbols(Vec<Bound>),
+    Concat(Vec<Regex<Bound>>),
+    Union(Vec<Regex<Bound>>),
+    Star(Box<Regex<Bound>>),
+}
+
+impl<Bound> Regex<Bound>
+where
+    Bound: Ord + Clone,
+{
+    pub fn symbol(v: Bound) -> Self {
+        Regex::Symbols(vec![v])
+    }
+
+    pub fn one_of(vs: Vec<Bound>) -> Self {
+        Regex::Symbols(vs)
+    }
+
+    pub fn concat(self, other: Self) -> Self {
+        Regex::Concat(vec![self, other])
+    }
+
+    pub fn or(self, other: Self) -> Self {
+        Regex::Union(vec![self, other])
+    }
+
+    pub fn star(self) -> Self {
+        Regex::Star(Box::new(self))
+    }
+
+    pub fn plus(self) -> Self {
+        self.clone().concat(self.star())
+    }
+
+    pub fn optional(self) -> Self {
+        self.or(Regex::Epsilon)
+    }
+
+    /// Between `min` and `max` repetitions of `self`.
+    /// Precondition: `min <= max`.
+    pub fn repeat(self, min: usize, max: usize) -> Self {
+        assert!(min <= max, "`Regex::repeat` requires `min <= max`.");
+        let mut seq = vec![self.clone(); min];
+        seq.extend(vec![self.optional(); max - min]);
+        Regex::Concat(seq)
+    }
+
+    /// Thompson construction of an NFA followed by the subset construction.
+    /// The resulting automaton is deterministic but not necessarily minimal.
+    pub fn to_dfa(&self) -> Dfa<Bound> {
+        let mut nfa = Nfa::new();
+        let (start, end) = nfa.compile(self);
+        let mut alphabet = vec![];
+        self.collect_symbols(&mut alphabet);
+        alphabet.sort();
+        alphabet.dedup();
+
+        let initial = nfa.closure(vec![start]);
+        let mut states = HashMap::new();
+        states.insert(initial.clone(), 0);
+        let mut pending = vec![initial];
+        let mut accepting = vec![];
+        let mut transitions = vec![];
+        while let Some(set) = pending.pop() {
+            let from = states[&set];
+            if set.binary_search(&end).is_ok() {
+                accepting.push(from);
+            }
+            for v in &alphabet {
+                let moved = nfa.step(&set, v);
+                if moved.is_empty() {
+                    continue;
+                }
+                let target = nfa.closure(moved);
+                let num_states = states.len();
+                let to = *states.entry(target.clone()).or_insert_with(|| {
+                    pending.push(target);
+                    num_states
+                });
+                transitions.push((from, v.clone(), to));
+            }
+        }
+        Dfa::new(states.len(), 0, transitions, accepting)
+    }
+
+    fn collect_symbols(&self, alphabet: &mut Vec<Bound>) {
+        match *self {
+            Regex::Epsilon => (),
+            Regex::Symbols(ref vs) => alphabet.extend(vs.iter().cloned()),
+            Regex::Concat(ref rs) | Regex::Union(ref rs) => {
+                for r in rs {
+                    r.collect_symbols(alphabet);
+                }
+            }
+            Regex::Star(ref r) => r.collect_symbols(alphabet),
+        }
+    }
+}
+
+impl<Bound> Regex<Bound>
+where
+    Bound: IntBound,
+{
+    /// Any symbol in `[lb..ub]`.
+    pub fn range(lb: Bound, ub: Bound) -> Self {
+        let mut vs = vec![];
+        let mut v = lb;
+        while v <= ub {
+            vs.push(v.clone());
+            v = v + Bound::one();
+        }
+        Regex::Symbols(vs)
+    }
+}
+
+/// NFA with epsilon transitions built by the Thompson construction.
+struct Nfa<Bound> {
+    symbols: Vec<Vec<(Bound, usize)>>,
+    epsilons: Vec<Vec<usize>>,
+}
+
+impl<Bound> Nfa<Bound>
+where
+    Bound: Ord + Clone,
+{
+    fn new() -> Self {
+        Nfa {
+            symbols: vec![],
+            epsilons: vec![],
+        }
+    }
+
+    fn state(&mut self) -> usize {
+        self.symbols.push(vec![]);
+        self.epsilons.push(vec![]);
+        self.symbols.len() - 1
+    }
+
+    /// Returns the start and end states of the automaton recognizing `regex`.
+    fn compile(&mut self, regex: &Regex<Bound>) -> (usize, usize) {
+        let start = self.state();
+        let end = self.state();
+        match *regex {
+            Regex::Epsilon => self.epsilons[start].push(end),
+            Regex::Symbols(ref vs) => {
+                for v in vs {
+                    self.symbols[start].push((v.clone(), end));
+                }
+            }
+            Regex::Concat(ref rs) => {
+                let mut last = start;
+                for r in rs {
+                    let (s, e) = self.compile(r);
+                    self.epsilons[last].push(s);
+                    last = e;
+                }
+                self.epsilons[last].push(end);
+            }
+            Regex::Union(ref rs) => {
+                for r in rs {
+                    let (s, e) = self.compile(r);
+                    self.epsilons[start].push(s);
+                    self.epsilons[e].push(end);
+                }
+            }
+            Regex::Star(ref r) => {
+                let (s, e) = self.compile(r);
+                self.epsilons[start].push(s);
+                self.epsilons[start].push(end);
+                self.epsilons[e].push(s);
+                self.epsilons[e].push(end);
+            }
+        }
+        (start, end)
+    }
+
+    /// Sorted set of the states reachable from `states` with epsilon transitions.
+    fn closure(&self, mut states: Vec<usize>) -> Vec<usize> {
+        let mut visited = vec![false; self.epsilons.len()];
+        let mut stack = states.clone();
+        for &q in &states {
+            visited[q] = true;
+        }
+        while let Some(q) = stack.pop() {
+            for &to in &self.epsilons[q] {
+                if !visited[to] {
+                    visited[to] = true;
+                    states.push(to);
+                    stack.push(to);
+                }
+            }
+        }
+        states.sort();
+        states
+    }
+
+    fn step(&self, states: &[usize], symbol: &Bound) -> Vec<usize> {
+        let mut moved: Vec<usize> = states
+            .iter()
+            .flat_map(|&q| self.symbols[q].iter())
+            .filter(|t| t.0 == *symbol)
+            .map(|t| t.1)
+            .collect();
+        moved.sort();
+        moved.dedup();
+        moved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn words(len: usize, alphabet: &[isize]) -> Vec<Vec<isize>> {
+        let mut words = vec![vec![]];
+        for _ in 0..len {
+            words = words
+                .into_iter()
+                .flat_map(|w| {
+                    alphabet.iter().map(move |&v| {
+                        let mut w = w.clone();
+                        w.push(v);
+                        w
+                    })
+                })
+                .collect();
+        }
+        words
+    }
+
+    #[test]
+    fn explicit_dfa_test() {
+        // Words over {0, 1} with an even number of `1`.
+        let dfa = Dfa::new(
+            2,
+            0,
+            vec![(0, 0, 0), (0, 1, 1), (1, 0, 1), (1, 1, 0)],
+            vec![0],
+        );
+        assert!(dfa.accepts(&[]));
+        assert!(dfa.accepts(&[1, 0, 1]));
+        assert!(!dfa.accepts(&[1, 0, 0]));
+        assert!(!dfa.accepts(&[2]));
+        assert_eq!(dfa.next(1, &1), Some(0));
+        assert_eq!(dfa.next(1, &2), None);
+    }
+
+    #[test]
+    fn complement_test() {
+        // Words over {0, 1} with an odd number of `1`, since the symbol `2` is not in the alphabet.
+        let dfa = Dfa::new(
+            2,
+            0,
+            vec![(0, 0, 0), (0, 1, 1), (1, 0, 1), (1, 1, 0)],
+            vec![0],
+        );
+        let odd = dfa.complement(&[0, 1]);
+        for word in words(3, &[0, 1, 2]) {
+            let over_alphabet = word.iter().all(|&v| v < 2);
+            assert_eq!(odd.accepts(&word), over_alphabet && !dfa.accepts(&word));
+        }
+        assert!(odd.complement(&[0, 1]).accepts(&[1, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_deterministic_dfa_test() {
+        Dfa::new(2, 0, vec![(0, 0, 0), (0, 0, 1)], vec![1]);
+    }
+
+    #[test]
+    fn regex_to_dfa_test() {
+        // Shifts: 0 is a day off, 1 a day shift and 2 a night shift.
+        // At most 3 nights in a row, followed by 2 days off.
+        let nights = Regex::symbol(2).repeat(1, 3);
+        let regex = Regex::one_of(vec![0, 1])
+            .or(nights.clone().concat(Regex::symbol(0).repeat(2, 2)))
+            .star()
+            .concat(nights.optional());
+        let dfa = regex.to_dfa();
+        let ok = |w: &[isize]| {
+            // `pending` is the number of days off still required after a night block.
+            let (mut nights, mut pending) = (0, 0);
+            for &v in w {
+                match v {
+                    2 if pending > 0 || nights == 3 => return false,
+                    2 => nights += 1,
+                    0 if nights > 0 => {
+                        nights = 0;
+                        pending = 1;
+                    }
+                    0 if pending > 0 => pending -= 1,
+                    1 if nights > 0 || pending > 0 => return false,
+                    _ => (),
+                }
+            }
+            pending == 0
+        };
+        for len in 0..8 {
+            for w in words(len, &[0, 1, 2]) {
+                assert_eq!(dfa.accepts(&w), ok(&w), "word {:?}", w);
+            }
+        }
+    }
+
+    #[test]
+    fn regex_combinators_test() {
+        let dfa = Regex::range(1, 3).plus().to_dfa();
+        assert!(!dfa.accepts(&[]));
+        assert!(dfa.accepts(&[1, 3, 2]));
+        assert!(!dfa.accepts(&[1, 4]));
+        let dfa = Regex::<isize>::Union(vec![]).to_dfa();
+        assert!(!dfa.accepts(&[]));
+        let dfa = Regex::<isize>::Epsilon.star().to_dfa();
+        assert!(dfa.accepts(&[]));
+        assert_eq!(dfa.num_states(), 1);
+    }
+}
@@ -0,0 +1,511 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// The item `i` of size `sizes[i]` is packed in the bin `items[i]`, and `loads[j]` is the sum of the sizes of the items packed in the bin `j`.
+/// The bins are numbered from `0` to `loads.len() - 1`.
+///
+/// The filtering follows Shaw (2004):
+///  * The load of a bin lies between the sizes of the items packed in it and of the items that can be packed in it.
+///  * The sum of the loads is the sum of the sizes.
+///  * An item is removed from a bin if it does not fit in anymore, and packed in a bin if its load could not be reached otherwise.
+///  * The problem fails if the lower bound `L2` of Martello and Toth (1990) on the number of bins needed exceeds the number of bins. The bins are given the largest capacity and the items already packed are replaced by one item per bin filling the difference.
+pub struct BinPacking<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    items: Vec<Var<VStore>>,
+    sizes: Vec<<VStore::Item as Collection>::Item>,
+    loads: Vec<Var<VStore>>,
+}
+
+impl<VStore, Domain, Bound> BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Precondition: `items.len() == sizes.len()` and the sizes are non-negative.
+    pub fn new(items: Vec<Var<VStore>>, sizes: Vec<Bound>, loads: Vec<Var<VStore>>) -> Self {
+        assert_eq!(
+            items.len(),
+            sizes.len(),
+            "`BinPacking` must have one size per item."
+        );
+        assert!(
+            sizes.iter().all(|s| *s >= Bound::zero()),
+            "Item sizes in `BinPacking` must be non-negative."
+        );
+        BinPacking {
+            items,
+            sizes,
+            loads,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("BinPacking")
+            .field("items", &self.items)
+            .field("sizes", &self.sizes)
+            .field("loads", &self.loads)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        BinPacking {
+            items: self.items.iter().map(|v| v.bclone()).collect(),
+            sizes: self.sizes.clone(),
+            loads: self.loads.iter().map(|v| v.bclone()).collect(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        print!("bin_packing(");
+        model.display_array(&self.items);
+        print!(", {:?}, ", self.sizes);
+        model.display_array(&self.loads);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+/// Lower bound `L2` of Martello and Toth on the number of bins of capacity `capacity > 0` needed to pack the items of sizes `sizes`.
+fn l2_lower_bound<Bound>(capacity: &Bound, sizes: &[Bound]) -> Bound
+where
+    Bound: IntBound,
+{
+    let zero = Bound::zero();
+    let two = Bound::one() + Bound::one();
+    let mut thresholds: Vec<Bound> = sizes
+        .iter()
+        .filter(|s| two.clone() * (*s).clone() <= *capacity)
+        .cloned()
+        .collect();
+    thresholds.push(zero.clone());
+    thresholds.sort();
+    thresholds.dedup();
+    thresholds
+        .into_iter()
+        .map(|k| {
+            // The items larger than `capacity - k` are alone in their bins, the ones larger than `capacity / 2` cannot share a bin, and the free space of these last bins is filled by the items of size at least `k`.
+            let mut alone = zero.clone();
+            let mut large = zero.clone();
+            let mut large_free = zero.clone();
+            let mut small_sizes = zero.clone();
+            for s in sizes {
+                if *s > capacity.clone() - k.clone() {
+                    alone = alone + Bound::one();
+                } else if two.clone() * s.clone() > *capacity {
+                    large = large + Bound::one();
+                    large_free = large_free + capacity.clone() - s.clone();
+                } else if *s >= k {
+                    small_sizes = small_sizes + s.clone();
+                }
+            }
+            let overflow = small_sizes - large_free;
+            let extra = if overflow > zero {
+                overflow.div_ceil(capacity)
+            } else {
+                zero.clone()
+            };
+            alone + large + extra
+        })
+        .max()
+        .unwrap()
+}
+
+fn read_all<VStore>(vars: &[Var<VStore>], store: &VStore) -> Vec<VStore::Item>
+where
+    VStore: VStoreConcept,
+{
+    vars.iter().map(|v| v.read(store)).collect()
+}
+
+impl<VStore, Domain, Bound> BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn bins(&self) -> Vec<Bound> {
+        (0..self.loads.len()).map(bound_of_usize).collect()
+    }
+
+    /// For each bin, the sizes of the items packed in it and of the items that can be packed in it.
+    fn packed(&self, items: &[Domain], bins: &[Bound]) -> Vec<(Bound, Bound)> {
+        let mut packed = vec![(Bound::zero(), Bound::zero()); bins.len()];
+        for (dom, size) in items.iter().zip(&self.sizes) {
+            for (bin, load) in bins.iter().zip(packed.iter_mut()) {
+                if dom.contains(bin) {
+                    if dom.is_singleton() {
+                        load.0 = load.0.clone() + size.clone();
+                    }
+                    load.1 = load.1.clone() + size.clone();
+                }
+            }
+        }
+        packed
+    }
+
+    fn restrict_items(&mut self, store: &mut VStore, bins: &[Bound]) -> bool {
+        let last = bins.last().cloned().unwrap_or_else(|| -Bound::one());
+        for item in &mut self.items {
+            let dom = item.read(store);
+            if !item.update(
+                store,
+                dom.shrink_left(Bound::zero()).shrink_right(last.clone()),
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn filter_loads(&mut self, store: &mut VStore, packed: &[(Bound, Bound)]) -> bool {
+        for (load, (required, possible)) in self.loads.iter_mut().zip(packed) {
+            let dom = load.read(store);
+            let dom = dom
+                .shrink_left(required.clone())
+                .shrink_right(possible.clone());
+            if !load.update(store, dom) {
+                return false;
+            }
+        }
+        let total = self
+            .sizes
+            .iter()
+            .fold(Bound::zero(), |acc, s| acc + s.clone());
+        let loads = read_all(&self.loads, store);
+        let sum_lower = loads.iter().fold(Bound::zero(), |acc, l| acc + l.lower());
+        let sum_upper = loads.iter().fold(Bound::zero(), |acc, l| acc + l.upper());
+        for (load, dom) in self.loads.iter_mut().zip(loads) {
+            let lb = total.clone() - (sum_upper.clone() - dom.upper());
+            let ub = total.clone() - (sum_lower.clone() - dom.lower());
+            let dom = load.read(store).shrink_left(lb).shrink_right(ub);
+            if !load.update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The bounds `packed` computed before this filtering remain valid since the sizes of the packed items only increase and the ones of the possible items only decrease.
+    fn filter_items(
+        &mut self,
+        store: &mut VStore,
+        bins: &[Bound],
+        packed: &[(Bound, Bound)],
+    ) -> bool {
+        let loads = read_all(&self.loads, store);
+        for (item, size) in self.items.iter_mut().zip(&self.sizes) {
+            if item.read(store).is_singleton() {
+                continue;
+            }
+            for (bin, (load, (required, possible))) in bins.iter().zip(loads.iter().zip(packed)) {
+                let dom = item.read(store);
+                if !dom.contains(bin) {
+                    continue;
+                }
+                let dom = if required.clone() + size.clone() > load.upper() {
+                    dom.difference(bin)
+                } else if possible.clone() - size.clone() < load.lower() {
+                    Domain::singleton(bin.clone())
+                } else {
+                    continue;
+                };
+                if !item.update(store, dom) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn check_lower_bound(&self, store: &VStore, bins: &[Bound]) -> bool {
+        let items = read_all(&self.items, store);
+        let loads = read_all(&self.loads, store);
+        let capacity = match loads.iter().map(|l| l.upper()).max() {
+            Some(capacity) if capacity > Bound::zero() => capacity,
+            _ => return true,
+        };
+        let packed = self.packed(&items, bins);
+        let mut sizes: Vec<Bound> = items
+            .iter()
+            .zip(&self.sizes)
+            .filter(|(dom, _)| !dom.is_singleton())
+            .map(|(_, size)| size.clone())
+            .collect();
+        for (load, (required, _)) in loads.iter().zip(packed) {
+            let virtual_size = capacity.clone() - (load.upper() - required);
+            if virtual_size > Bound::zero() {
+                sizes.push(virtual_size);
+            }
+        }
+        l2_lower_bound(&capacity, &sizes) <= bound_of_usize(bins.len())
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let bins = self.bins();
+        let items = read_all(&self.items, store);
+        let loads = read_all(&self.loads, store);
+        let packed = self.packed(&items, &bins);
+        let out_of_bins = items
+            .iter()
+            .any(|dom| bins.iter().all(|bin| !dom.contains(bin)));
+        let wrong_load = loads
+            .iter()
+            .zip(&packed)
+            .any(|(load, (required, possible))| {
+                *required > load.upper() || *possible < load.lower()
+            });
+        if out_of_bins || wrong_load {
+            False
+        } else if items.iter().chain(loads.iter()).all(|d| d.is_singleton()) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for BinPacking<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let bins = self.bins();
+        if !self.restrict_items(store, &bins) {
+            return false;
+        }
+        let packed = self.packed(&read_all(&self.items, store), &bins);
+        self.filter_loads(store, &packed)
+            && self.filter_items(store, &bins, &packed)
+            && self.check_lower_bound(store, &bins)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for BinPacking<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .items
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect();
+        deps.extend(
+            self.loads
+                .iter()
+                .flat_map(|v| v.dependencies(FDEvent::Bound)),
+        );
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+
+    #[test]
+    fn l2_lower_bound_test() {
+        assert_eq!(l2_lower_bound(&4, &[3, 2, 2]), 2);
+        assert_eq!(l2_lower_bound(&5, &[3, 3, 3]), 3);
+        assert_eq!(l2_lower_bound(&10, &[6, 6, 2, 2, 2]), 2);
+        assert_eq!(l2_lower_bound(&10, &[6, 6, 4, 4, 4]), 3);
+        assert_eq!(l2_lower_bound(&10, &[]), 0);
+    }
+
+    #[test]
+    fn bin_packing_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let bins = (0, 1).to_interval();
+        let dom0_4 = (0, 4).to_interval();
+
+        bin_packing_test_one(
+            1,
+            vec![3, 2, 2],
+            vec![bins, bins, bins, dom0_4, dom0_4],
+            Unknown,
+            Unknown,
+            vec![(3, Bound), (4, Bound)],
+            true,
+        );
+        bin_packing_test_one(
+            2,
+            vec![3, 2, 2],
+            vec![zero, bins, bins, dom0_4, dom0_4],
+            Unknown,
+            Unknown,
+            vec![(1, Assignment), (2, Assignment), (3, Bound), (4, Bound)],
+            true,
+        );
+        // Two items of size 3 cannot share a bin of capacity 5.
+        bin_packing_test_one(
+            3,
+            vec![3, 3, 3],
+            vec![bins, bins, bins, (0, 5).to_interval(), (0, 5).to_interval()],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+        bin_packing_test_one(
+            4,
+            vec![3, 2, 2],
+            vec![zero, one, one, (3, 3).to_interval(), (4, 4).to_interval()],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        bin_packing_test_one(
+            5,
+            vec![3, 2, 2],
+            vec![bins, bins, bins, (0, 2).to_interval(), dom0_4],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+        bin_packing_test_one(
+            6,
+            vec![3, 2, 2],
+            vec![(0, 5).to_interval(), one, one, (0, 9).to_interval(), dom0_4],
+            Unknown,
+            True,
+            vec![(0, Assignment), (3, Assignment), (4, Assignment)],
+            true,
+        );
+    }
+
+    #[test]
+    fn bin_packing_search_test() {
+        // Packing the sizes [4, 3, 3, 2, 2] in 2 bins of capacity 7: a bin contains either 4 and a 3, or a 3 and both 2.
+        let mut space = FDSpace::empty();
+        let items: Vec<Var<VStore>> = (0..5)
+            .map(|_| Box::new(space.vstore.alloc((0, 1).to_interval_set())) as Var<VStore>)
+            .collect();
+        let loads: Vec<Var<VStore>> = (0..2)
+            .map(|_| Box::new(space.vstore.alloc((0, 7).to_interval_set())) as Var<VStore>)
+            .collect();
+        space
+            .cstore
+            .alloc(Box::new(BinPacking::new(items, vec![4, 3, 3, 2, 2], loads)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 4);
+    }
+
+    fn bin_packing_test_one(
+        test_num: u32,
+        sizes: Vec<isize>,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        // The items are followed by the loads.
+        let make_bin_packing = |mut items: Vec<FDVar>| {
+            let loads = items.split_off(sizes.len());
+            BinPacking::new(items, sizes, loads)
+        };
+        nary_propagator_test(
+            test_num,
+            make_bin_packing,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
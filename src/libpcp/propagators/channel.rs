@@ -0,0 +1,577 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Channeling constraints keep two models of the same problem synchronized, such as the successor and predecessor views of an assignment.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+fn read_all<VStore>(vars: &[Var<VStore>], store: &VStore) -> Vec<VStore::Item>
+where
+    VStore: VStoreConcept,
+{
+    vars.iter().map(|v| v.read(store)).collect()
+}
+
+/// The indices `0..n` as bounds.
+fn indices<Bound: IntBound>(n: usize) -> Vec<Bound> {
+    (0..n).map(bound_of_usize).collect()
+}
+
+/// `xs[i] = j <-> ys[j] = i` where `xs` and `ys` have the same length `n` and take their values in `0..n`.
+/// It implies that `xs` and `ys` are permutations inverse of each other.
+///
+/// The filtering is domain consistent on the channeling: `j` is removed from `xs[i]` if `i` is not in `ys[j]`, and conversely, until a fixpoint is reached.
+/// An assignment `xs[i] = j` also assigns `ys[j] = i`, which removes `j` from the other variables of `xs`.
+#[derive(Debug)]
+pub struct Inverse<VStore> {
+    xs: Vec<Var<VStore>>,
+    ys: Vec<Var<VStore>>,
+}
+
+impl<VStore> Inverse<VStore> {
+    /// Precondition: `xs.len() == ys.len()`.
+    pub fn new(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>) -> Self {
+        assert_eq!(
+            xs.len(),
+            ys.len(),
+            "`Inverse` must be given two arrays of the same length."
+        );
+        Inverse { xs, ys }
+    }
+}
+
+impl<VStore> Clone for Inverse<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Inverse::new(
+            self.xs.iter().map(|v| v.bclone()).collect(),
+            self.ys.iter().map(|v| v.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Inverse<VStore> {
+    fn display(&self, model: &Model) {
+        print!("inverse(");
+        model.display_array(&self.xs);
+        print!(", ");
+        model.display_array(&self.ys);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Inverse<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+/// Restricts `to[j]` to the indices `i` such that `j` is in `from[i]`, or to `i` alone if `from[i]` is assigned to `j`.
+fn channel<VStore, Domain, Bound>(
+    store: &mut VStore,
+    from: &[Var<VStore>],
+    to: &mut [Var<VStore>],
+    nodes: &[Bound],
+) -> bool
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    let doms = read_all(from, store);
+    for (j, var) in to.iter_mut().enumerate() {
+        let mut supports = vec![];
+        for (i, dom) in doms.iter().enumerate() {
+            if dom.contains(&nodes[j]) {
+                if dom.is_singleton() {
+                    supports = vec![nodes[i].clone()];
+                    break;
+                }
+                supports.push(nodes[i].clone());
+            }
+        }
+        let dom = var.read(store);
+        if !var.update(store, restrict_to_values(dom, &supports)) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether each assigned variable of `from` is assigned to an index `j` such that `i` is in `to[j]`.
+fn consistent<Domain, Bound>(from: &[Domain], to: &[Domain], nodes: &[Bound]) -> bool
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    from.iter().zip(nodes).all(|(dom, i)| {
+        !dom.is_singleton()
+            || match nodes.binary_search(&dom.lower()) {
+                Ok(j) => to[j].contains(i),
+                Err(_) => false,
+            }
+    })
+}
+
+impl<VStore, Domain, Bound> Inverse<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Sum of the sizes of the domains, bounded by `n + 1` for each variable.
+    fn size(&self, store: &VStore) -> usize {
+        let n = self.xs.len();
+        self.xs
+            .iter()
+            .chain(self.ys.iter())
+            .map(|v| bounded_size(&v.read(store), n + 1))
+            .sum()
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Inverse<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let nodes = indices(self.xs.len());
+        let xs = read_all(&self.xs, store);
+        let ys = read_all(&self.ys, store);
+        if !consistent(&xs, &ys, &nodes) || !consistent(&ys, &xs, &nodes) {
+            False
+        } else if xs.iter().chain(ys.iter()).all(|d| d.is_singleton()) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Inverse<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let nodes = indices(self.xs.len());
+        loop {
+            let size = self.size(store);
+            if !channel(store, &self.xs, &mut self.ys, &nodes)
+                || !channel(store, &self.ys, &mut self.xs, &nodes)
+            {
+                return false;
+            }
+            if self.size(store) == size {
+                return true;
+            }
+        }
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Inverse<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .chain(self.ys.iter())
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+/// `x = i <-> bs[i] = 1` where the variables of `bs` take their values in `{0, 1}`, for instance `logic::Boolean` variables.
+/// The variable `x` takes its values in `0..bs.len()`.
+///
+/// The filtering is domain consistent: `i` is removed from `x` if `bs[i] = 0`, and `bs[i]` is assigned to `0` if `i` is not in `x`.
+#[derive(Debug)]
+pub struct BoolChannel<VStore> {
+    x: Var<VStore>,
+    bs: Vec<Var<VStore>>,
+}
+
+impl<VStore> BoolChannel<VStore> {
+    pub fn new(x: Var<VStore>, bs: Vec<Var<VStore>>) -> Self {
+        BoolChannel { x, bs }
+    }
+}
+
+impl<VStore> Clone for BoolChannel<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        BoolChannel::new(
+            self.x.bclone(),
+            self.bs.iter().map(|v| v.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for BoolChannel<VStore> {
+    fn display(&self, model: &Model) {
+        print!("bool_channel(");
+        self.x.display(model);
+        print!(", ");
+        model.display_array(&self.bs);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for BoolChannel<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> BoolChannel<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// The values of `x` supported by `bs`, or `None` if several variables of `bs` are assigned to `1`.
+    fn supported_values(&self, bs: &[Domain], nodes: &[Bound]) -> Option<Vec<Bound>> {
+        let one = Bound::one();
+        let assigned: Vec<Bound> = bs
+            .iter()
+            .zip(nodes)
+            .filter(|(b, _)| b.is_singleton() && b.lower() == one)
+            .map(|(_, i)| i.clone())
+            .collect();
+        match assigned.len() {
+            0 => Some(
+                bs.iter()
+                    .zip(nodes)
+                    .filter(|(b, _)| b.contains(&one))
+                    .map(|(_, i)| i.clone())
+                    .collect(),
+            ),
+            1 => Some(assigned),
+            _ => None,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for BoolChannel<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let nodes = indices(self.bs.len());
+        let x = self.x.read(store);
+        let bs = read_all(&self.bs, store);
+        let zero_one = Domain::new(Bound::zero(), Bound::one());
+        if bs.iter().any(|b| !b.overlap(&zero_one)) {
+            return False;
+        }
+        match self.supported_values(&bs, &nodes) {
+            Some(ref values) if values.iter().any(|v| x.contains(v)) => {
+                if x.is_singleton() && bs.iter().all(|b| b.is_singleton()) {
+                    True
+                } else {
+                    Unknown
+                }
+            }
+            _ => False,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for BoolChannel<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let nodes = indices(self.bs.len());
+        let zero_one = Domain::new(Bound::zero(), Bound::one());
+        for b in &mut self.bs {
+            let dom = b.read(store).intersection(&zero_one);
+            if !b.update(store, dom) {
+                return false;
+            }
+        }
+        let values = match self.supported_values(&read_all(&self.bs, store), &nodes) {
+            Some(values) => values,
+            None => return false,
+        };
+        let x = self.x.read(store);
+        if !self.x.update(store, restrict_to_values(x, &values)) {
+            return false;
+        }
+        let x = self.x.read(store);
+        for (b, i) in self.bs.iter_mut().zip(&nodes) {
+            let value = if !x.contains(i) {
+                Bound::zero()
+            } else if x.is_singleton() {
+                Bound::one()
+            } else {
+                continue;
+            };
+            let dom = b.read(store).intersection(&Domain::singleton(value));
+            if !b.update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for BoolChannel<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps = self.x.dependencies(FDEvent::Inner);
+        deps.extend(self.bs.iter().flat_map(|b| b.dependencies(FDEvent::Bound)));
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn inverse_test() {
+        let make_inverse = |mut xs: Vec<FDVar>| {
+            let ys = xs.split_off(xs.len() / 2);
+            Inverse::new(xs, ys)
+        };
+        let d = |v: isize| (v, v).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_inverse,
+            vec![dom0_2; 6],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_inverse,
+            vec![d(1), dom0_2, dom0_2, dom0_2, dom0_2, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(3, Bound), (4, Assignment), (5, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            make_inverse,
+            vec![d(0), dom0_2, dom0_2, d(1), dom0_2, dom0_2],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            4,
+            make_inverse,
+            vec![d(1), d(2), d(0), d(2), d(0), d(1)],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            5,
+            make_inverse,
+            vec![(5, 6).to_interval(), dom0_2, dom0_2, dom0_2, dom0_2, dom0_2],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+        // The variables of `xs` out of `0..n` are restricted, and the fixpoint assigns the whole permutation.
+        nary_propagator_test(
+            6,
+            make_inverse,
+            vec![
+                d(1),
+                (0, 9).to_interval(),
+                (1, 2).to_interval(),
+                dom0_2,
+                (0, 1).to_interval(),
+                dom0_2,
+            ],
+            Unknown,
+            True,
+            vec![
+                (1, Assignment),
+                (2, Assignment),
+                (3, Assignment),
+                (4, Assignment),
+                (5, Assignment),
+            ],
+            true,
+        );
+    }
+
+    #[test]
+    fn inverse_domain_test() {
+        let mut store = VStoreSet::empty();
+        let vars: Vec<Var<VStoreSet>> = (0..6)
+            .map(|_| Box::new(store.alloc((0, 2).to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let mut inverse = Inverse::new(
+            vars[..3].iter().map(|v| v.bclone()).collect(),
+            vars[3..].iter().map(|v| v.bclone()).collect(),
+        );
+        assert!(vars[0]
+            .bclone()
+            .update(&mut store, (1, 1).to_interval_set()));
+        assert!(inverse.propagate(&mut store));
+        // `xs[0] = 1` implies `ys[1] = 0`, so 1 is removed from the other variables of `xs`.
+        assert_eq!(store[1], vec![(0, 0), (2, 2)].to_interval_set());
+        assert_eq!(store[2], vec![(0, 0), (2, 2)].to_interval_set());
+        assert_eq!(store[3], (1, 2).to_interval_set());
+        assert_eq!(store[4], (0, 0).to_interval_set());
+    }
+
+    #[test]
+    fn bool_channel_test() {
+        let make_bool_channel = |mut bs: Vec<FDVar>| {
+            let x = bs.remove(0);
+            BoolChannel::new(x, bs)
+        };
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let b = (0, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_bool_channel,
+            vec![dom0_2, b, b, b],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_bool_channel,
+            vec![one, b, b, b],
+            Unknown,
+            True,
+            vec![(1, Assignment), (2, Assignment), (3, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            make_bool_channel,
+            vec![dom0_2, zero, b, b],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            make_bool_channel,
+            vec![dom0_2, b, b, one],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Assignment), (2, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            5,
+            make_bool_channel,
+            vec![dom0_2, one, one, b],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            6,
+            make_bool_channel,
+            vec![(0, 5).to_interval(), b, b, b],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn inverse_search_test() {
+        // Permutations of size 4 with their inverses, and `xs[0]` channeled to booleans.
+        let mut space = FDSpace::empty();
+        let mut alloc = |ub| Box::new(space.vstore.alloc((0, ub).to_interval_set())) as Var<VStore>;
+        let xs: Vec<_> = (0..4).map(|_| alloc(3)).collect();
+        let ys: Vec<_> = (0..4).map(|_| alloc(3)).collect();
+        let bs: Vec<_> = (0..4).map(|_| alloc(1)).collect();
+        space
+            .cstore
+            .alloc(Box::new(BoolChannel::new(xs[0].bclone(), bs)));
+        space.cstore.alloc(Box::new(Inverse::new(xs, ys)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 24);
+        assert_eq!(statistics.num_failed_node, 0);
+    }
+}
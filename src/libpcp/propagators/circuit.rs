@@ -0,0 +1,691 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::distinct::*;
+use propagators::graph::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// Successor variables `vars` forming a single cycle visiting every node: `vars[i] = j` means that the node `j` follows the node `i`, and the nodes are numbered from 0.
+///
+/// The filtering combines:
+///  * `Distinct` on the successors with domain consistency,
+///  * the no-subtour rule: if the assigned successors form a path from `s` to `e` not covering every node, then `vars[e] != s`,
+///  * the strongly connected components of the successor graph: an arc between two components cannot belong to a cycle, and every node must be in the same component.
+pub struct Circuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    vars: Vec<Var<VStore>>,
+    // `nodes[i]` is the value representing the node `i`.
+    nodes: Vec<<VStore::Item as Collection>::Item>,
+    distinct: Distinct<VStore>,
+    // Nodes with `vars[i] = i` are outside of the cycle.
+    subcircuit: bool,
+}
+
+impl<VStore, Domain, Bound> Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// Precondition: `vars.len() > 1`.
+    pub fn new(vars: Vec<Var<VStore>>) -> Self {
+        assert!(
+            vars.len() > 1,
+            "Variable array in `Circuit` must have at least two nodes."
+        );
+        Circuit::make(vars, false)
+    }
+
+    fn make(vars: Vec<Var<VStore>>, subcircuit: bool) -> Self {
+        let mut nodes = vec![];
+        let mut node = Bound::zero();
+        for _ in 0..vars.len() {
+            nodes.push(node.clone());
+            node = node + Bound::one();
+        }
+        let distinct = Distinct::with_strength(
+            vars.iter().map(|v| v.bclone()).collect(),
+            DistinctStrength::Domain,
+        );
+        Circuit {
+            vars,
+            nodes,
+            distinct,
+            subcircuit,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("Circuit")
+            .field("vars", &self.vars)
+            .field("subcircuit", &self.subcircuit)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Circuit {
+            vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            nodes: self.nodes.clone(),
+            distinct: self.distinct.clone(),
+            subcircuit: self.subcircuit,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Circuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn display(&self, model: &Model) {
+        let name = if self.subcircuit {
+            "subcircuit"
+        } else {
+            "circuit"
+        };
+        model.display_call(name, &self.vars);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read_all(&self, store: &VStore) -> Vec<Domain> {
+        self.vars.iter().map(|v| v.read(store)).collect()
+    }
+
+    fn is_mandatory(&self, doms: &[Domain], i: usize) -> bool {
+        !doms[i].contains(&self.nodes[i])
+    }
+
+    /// The node `j` such that `vars[i] = j` with `j != i`, if it is assigned.
+    fn assigned_successor(&self, doms: &[Domain], i: usize) -> Option<usize> {
+        if doms[i].is_singleton() {
+            self.nodes
+                .binary_search(&doms[i].lower())
+                .ok()
+                .filter(|&j| j != i)
+        } else {
+            None
+        }
+    }
+
+    /// Restricts the successors to the nodes, and forbids the self-loops in a circuit.
+    fn restrict_to_nodes(&mut self, store: &mut VStore) -> bool {
+        let n = self.nodes.len();
+        let nodes = Domain::new(self.nodes[0].clone(), self.nodes[n - 1].clone());
+        for i in 0..n {
+            let mut dom = self.vars[i].read(store).intersection(&nodes);
+            if !self.subcircuit {
+                dom = dom.difference(&self.nodes[i]);
+            }
+            if !self.vars[i].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn update_node(&mut self, store: &mut VStore, i: usize, dom: Domain) -> bool {
+        let dom = self.vars[i].read(store).intersection(&dom);
+        self.vars[i].update(store, dom)
+    }
+
+    /// Follows the chains of assigned successors: closing a chain is forbidden if a mandatory node is outside of it, and a closed cycle forces the other nodes out of the circuit.
+    fn prune_subtours(&mut self, store: &mut VStore) -> bool {
+        let n = self.nodes.len();
+        let doms = self.read_all(store);
+        let next: Vec<Option<usize>> = (0..n).map(|i| self.assigned_successor(&doms, i)).collect();
+        let mut has_pred = vec![false; n];
+        for j in next.iter().flatten() {
+            has_pred[*j] = true;
+        }
+        let num_mandatory = (0..n).filter(|&i| self.is_mandatory(&doms, i)).count();
+        let mut visited = vec![false; n];
+        for start in 0..n {
+            if has_pred[start] || next[start].is_none() {
+                continue;
+            }
+            let mut end = start;
+            let mut mandatory_in_chain = 0;
+            visited[start] = true;
+            while let Some(j) = next[end] {
+                if self.is_mandatory(&doms, end) {
+                    mandatory_in_chain += 1;
+                }
+                end = j;
+                visited[end] = true;
+            }
+            if self.is_mandatory(&doms, end) {
+                mandatory_in_chain += 1;
+            }
+            if mandatory_in_chain < num_mandatory {
+                let dom = self.vars[end].read(store).difference(&self.nodes[start]);
+                if !self.vars[end].update(store, dom) {
+                    return false;
+                }
+            }
+        }
+        // The assigned successors not visited yet form cycles.
+        for first in 0..n {
+            if visited[first] || next[first].is_none() {
+                continue;
+            }
+            let mut in_cycle = vec![false; n];
+            let mut i = first;
+            while !in_cycle[i] {
+                in_cycle[i] = true;
+                visited[i] = true;
+                i = next[i].expect("Every node of a cycle has an assigned successor.");
+            }
+            for (j, &in_cycle) in in_cycle.iter().enumerate() {
+                if !in_cycle {
+                    let self_loop = Domain::singleton(self.nodes[j].clone());
+                    if !self.update_node(store, j, self_loop) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Removes the arcs between strongly connected components, and forces the nodes outside of the component of the mandatory nodes out of the circuit.
+    fn prune_components(&mut self, store: &mut VStore) -> bool {
+        let n = self.nodes.len();
+        let doms = self.read_all(store);
+        let succ: Vec<Vec<usize>> = doms
+            .iter()
+            .enumerate()
+            .map(|(i, dom)| {
+                (0..n)
+                    .filter(|&j| j != i && dom.contains(&self.nodes[j]))
+                    .collect()
+            })
+            .collect();
+        let component = strongly_connected_components(&succ);
+        let mut mandatory_component = None;
+        for (i, &c) in component.iter().enumerate() {
+            if self.is_mandatory(&doms, i) {
+                match mandatory_component {
+                    None => mandatory_component = Some(c),
+                    Some(m) if m != c => return false,
+                    _ => (),
+                }
+            }
+        }
+        for i in 0..n {
+            let supported: Vec<Bound> = if mandatory_component.is_none()
+                || mandatory_component == Some(component[i])
+            {
+                (0..n)
+                    .filter(|&j| {
+                        doms[i].contains(&self.nodes[j]) && (j == i || component[j] == component[i])
+                    })
+                    .map(|j| self.nodes[j].clone())
+                    .collect()
+            } else {
+                vec![self.nodes[i].clone()]
+            };
+            let dom = self.vars[i].read(store);
+            if !self.vars[i].update(store, restrict_to_values(dom, &supported)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks that the assigned successors `next` form a valid circuit.
+    fn is_circuit(&self, next: &[usize]) -> bool {
+        let n = next.len();
+        let in_circuit: Vec<usize> = (0..n).filter(|&i| next[i] != i).collect();
+        if !self.subcircuit && in_circuit.len() < n {
+            return false;
+        }
+        match in_circuit.first() {
+            None => true,
+            Some(&first) => {
+                // The walk from `first` must come back after visiting every node of the circuit once.
+                let mut i = first;
+                let mut len = 0;
+                loop {
+                    i = next[i];
+                    len += 1;
+                    if i == first || len >= in_circuit.len() {
+                        break;
+                    }
+                }
+                i == first && len == in_circuit.len()
+            }
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let doms = self.read_all(store);
+        if !doms.iter().all(|d| d.is_singleton()) {
+            return Unknown;
+        }
+        let next: Option<Vec<usize>> = doms
+            .iter()
+            .map(|d| self.nodes.binary_search(&d.lower()).ok())
+            .collect();
+        match next {
+            Some(ref next) if self.is_circuit(next) => True,
+            _ => False,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Circuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.restrict_to_nodes(store)
+            && self.distinct.propagate(store)
+            && self.prune_subtours(store)
+            && self.prune_components(store)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Circuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.vars
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+/// Successor variables `vars` forming a cycle over a subset of the nodes, the nodes outside of the cycle are their own successor (`vars[i] = i`).
+/// The filtering is the one of `Circuit` where a node is only required to be in the cycle if it cannot be its own successor.
+#[derive(Debug)]
+pub struct Subcircuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    circuit: Circuit<VStore>,
+}
+
+impl<VStore, Domain, Bound> Subcircuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// Precondition: `vars.len() > 0`.
+    pub fn new(vars: Vec<Var<VStore>>) -> Self {
+        assert!(
+            !vars.is_empty(),
+            "Variable array in `Subcircuit` must be non-empty."
+        );
+        Subcircuit {
+            circuit: Circuit::make(vars, true),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Subcircuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Subcircuit {
+            circuit: self.circuit.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Subcircuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn display(&self, model: &Model) {
+        self.circuit.display(model);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Subcircuit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.circuit.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for Subcircuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Circuit<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        self.circuit.is_subsumed(store)
+    }
+}
+
+impl<VStore> Propagator<VStore> for Subcircuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Circuit<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.circuit.propagate(store)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Subcircuit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.circuit.dependencies()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branch_and_bound::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use term::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn circuit_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+
+        circuit_test_one(
+            1,
+            vec![one, dom0_2, dom0_2],
+            Unknown,
+            True,
+            vec![(1, Assignment), (2, Assignment)],
+            true,
+        );
+        // Subtour 0 -> 1 -> 0.
+        circuit_test_one(
+            2,
+            vec![one, zero, (2, 3).to_interval(), (2, 3).to_interval()],
+            Unknown,
+            False,
+            vec![],
+            false,
+        );
+        // {0, 1, 2} and {3, 4, 5} are strongly connected components.
+        circuit_test_one(
+            3,
+            vec![
+                (1, 2).to_interval(),
+                dom0_2,
+                (0, 1).to_interval(),
+                (4, 5).to_interval(),
+                (3, 5).to_interval(),
+                (3, 4).to_interval(),
+            ],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn subcircuit_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+        let dom2_3 = (2, 3).to_interval();
+        let dom3_4 = (3, 4).to_interval();
+
+        subcircuit_test_one(1, vec![dom0_1, dom0_1, two], Unknown, Unknown, vec![], true);
+        subcircuit_test_one(
+            2,
+            vec![one, (1, 2).to_interval(), dom0_2],
+            Unknown,
+            True,
+            vec![(1, Assignment), (2, Assignment)],
+            true,
+        );
+        // The cycle 0 -> 1 -> 0 is closed.
+        subcircuit_test_one(
+            3,
+            vec![one, zero, dom2_3, dom2_3],
+            Unknown,
+            True,
+            vec![(2, Assignment), (3, Assignment)],
+            true,
+        );
+        // The node 0 is in the circuit, thus {3, 4} cannot be.
+        subcircuit_test_one(
+            4,
+            vec![(1, 2).to_interval(), dom0_2, dom0_2, dom3_4, dom3_4],
+            Unknown,
+            Unknown,
+            vec![(3, Assignment), (4, Assignment)],
+            true,
+        );
+    }
+
+    #[test]
+    fn no_subtour_test() {
+        let mut store = VStoreSet::empty();
+        let vars: Vec<Var<VStoreSet>> = vec![(1, 1), (0, 3), (0, 3), (0, 3)]
+            .into_iter()
+            .map(|d| Box::new(store.alloc(d.to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let mut circuit = Circuit::new(vars);
+        assert!(circuit.propagate(&mut store));
+        assert_eq!(store[1], (2, 3).to_interval_set());
+        assert_eq!(store[2], vec![(0, 0), (3, 3)].to_interval_set());
+        assert_eq!(store[3], vec![(0, 0), (2, 2)].to_interval_set());
+    }
+
+    fn count_solutions<F>(n: usize, make: F) -> usize
+    where
+        F: FnOnce(Vec<Var<VStore>>) -> Formula<VStore>,
+    {
+        let mut space = FDSpace::empty();
+        let vars: Vec<Var<VStore>> = (0..n)
+            .map(|_| {
+                Box::new(space.vstore.alloc((0, n as isize - 1).to_interval_set())) as Var<VStore>
+            })
+            .collect();
+        space.cstore.alloc(make(vars));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        statistics.num_solution
+    }
+
+    #[test]
+    fn circuit_count_test() {
+        // There are (n-1)! Hamiltonian circuits in a complete graph, and `sum_{k=2..n} C(n, k)*(k-1)! + 1` subcircuits (with the empty one).
+        let circuits = [1, 2, 6, 24];
+        let subcircuits = [2, 6, 21, 85];
+        for n in 2..6 {
+            assert_eq!(
+                count_solutions(n, |vars| Box::new(Circuit::new(vars))),
+                circuits[n - 2]
+            );
+            assert_eq!(
+                count_solutions(n, |vars| Box::new(Subcircuit::new(vars))),
+                subcircuits[n - 2]
+            );
+        }
+    }
+
+    #[test]
+    fn tsp_test() {
+        // Symmetric instance, the optimal tour is 0 -> 1 -> 2 -> 4 -> 3 -> 0 of cost 3 + 4 + 5 + 6 + 8 = 26.
+        let distances = vec![
+            vec![0, 3, 9, 8, 12],
+            vec![3, 0, 4, 11, 10],
+            vec![9, 4, 0, 7, 5],
+            vec![8, 11, 7, 0, 6],
+            vec![12, 10, 5, 6, 0],
+        ];
+        let n = distances.len();
+        let mut space = FDSpace::empty();
+        let succ: Vec<Var<VStore>> = (0..n)
+            .map(|_| {
+                Box::new(space.vstore.alloc((0, n as isize - 1).to_interval_set())) as Var<VStore>
+            })
+            .collect();
+        space.cstore.alloc(Box::new(Circuit::new(
+            succ.iter().map(|s| s.bclone()).collect(),
+        )));
+        let legs: Vec<Var<VStore>> = succ
+            .iter()
+            .zip(distances)
+            .map(|(s, row)| Box::new(Element::constant(s.bclone(), row)) as Var<VStore>)
+            .collect();
+        let cost = Box::new(Sum::new(legs)) as Var<VStore>;
+
+        let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+            AllSolution::new(OneSolution::new(BranchAndBound::new(
+                Mode::Minimize,
+                cost,
+                Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+            )));
+        search.start(&space);
+        let (_, status) = search.enter(space);
+        assert_eq!(status, Status::EndOfSearch);
+        assert_eq!(search.child.child.value, Some(26));
+    }
+
+    fn circuit_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        nary_propagator_test(
+            test_num,
+            Circuit::new,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+
+    fn subcircuit_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        nary_propagator_test(
+            test_num,
+            Subcircuit::new,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
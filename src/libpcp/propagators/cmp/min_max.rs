@@ -0,0 +1,338 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::cmp::x_eq_y::*;
+use term;
+use trilean::SKleene;
+
+/// `z = min(xs[0], ..., xs[n-1])`.
+/// It is the constraint `z = y` where `y` is the view `term::Min`, and it is bounds consistent.
+#[derive(Debug)]
+pub struct ArrayMin<VStore> {
+    eq: XEqY<VStore>,
+}
+
+impl<VStore, Domain, Bound> ArrayMin<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// Precondition: `xs.len() > 0`.
+    pub fn new(z: Var<VStore>, xs: Vec<Var<VStore>>) -> Self {
+        ArrayMin {
+            eq: XEqY::new(z, Box::new(term::Min::new(xs))),
+        }
+    }
+}
+
+impl<VStore> Clone for ArrayMin<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        ArrayMin {
+            eq: self.eq.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for ArrayMin<VStore> {
+    fn display(&self, model: &Model) {
+        self.eq.display(model);
+    }
+}
+
+impl<VStore> NotFormula<VStore> for ArrayMin<VStore>
+where
+    XEqY<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.eq.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for ArrayMin<VStore>
+where
+    XEqY<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        self.eq.is_subsumed(vstore)
+    }
+}
+
+impl<VStore> Propagator<VStore> for ArrayMin<VStore>
+where
+    XEqY<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        self.eq.propagate(vstore)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for ArrayMin<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.eq.dependencies()
+    }
+}
+
+/// `z = max(xs[0], ..., xs[n-1])`.
+/// It is the constraint `z = y` where `y` is the view `term::Max`, and it is bounds consistent.
+#[derive(Debug)]
+pub struct ArrayMax<VStore> {
+    eq: XEqY<VStore>,
+}
+
+impl<VStore, Domain, Bound> ArrayMax<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// Precondition: `xs.len() > 0`.
+    pub fn new(z: Var<VStore>, xs: Vec<Var<VStore>>) -> Self {
+        ArrayMax {
+            eq: XEqY::new(z, Box::new(term::Max::new(xs))),
+        }
+    }
+}
+
+impl<VStore> Clone for ArrayMax<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        ArrayMax {
+            eq: self.eq.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for ArrayMax<VStore> {
+    fn display(&self, model: &Model) {
+        self.eq.display(model);
+    }
+}
+
+impl<VStore> NotFormula<VStore> for ArrayMax<VStore>
+where
+    XEqY<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.eq.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for ArrayMax<VStore>
+where
+    XEqY<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        self.eq.is_subsumed(vstore)
+    }
+}
+
+impl<VStore> Propagator<VStore> for ArrayMax<VStore>
+where
+    XEqY<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        self.eq.propagate(vstore)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for ArrayMax<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.eq.dependencies()
+    }
+}
+
+/// `z = min(x, y)` is `ArrayMin` over `[x, y]`.
+pub fn min<VStore, Domain, Bound>(
+    z: Var<VStore>,
+    x: Var<VStore>,
+    y: Var<VStore>,
+) -> ArrayMin<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    ArrayMin::new(z, vec![x, y])
+}
+
+/// `z = max(x, y)` is `ArrayMax` over `[x, y]`.
+pub fn max<VStore, Domain, Bound>(
+    z: Var<VStore>,
+    x: Var<VStore>,
+    y: Var<VStore>,
+) -> ArrayMax<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    ArrayMax::new(z, vec![x, y])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use trilean::SKleene::*;
+
+    #[test]
+    fn array_min_test() {
+        let make_min = |mut xs: Vec<FDVar>| {
+            let z = xs.remove(0);
+            ArrayMin::new(z, xs)
+        };
+        let dom0_10 = (0, 10).to_interval();
+        let dom2_8 = (2, 8).to_interval();
+        let dom5_6 = (5, 6).to_interval();
+        let dom7_9 = (7, 9).to_interval();
+        let four = (4, 4).to_interval();
+
+        // z, xs...
+        nary_propagator_test(
+            1,
+            make_min,
+            vec![dom0_10, dom2_8, dom5_6, dom7_9],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_min,
+            vec![four, dom2_8, dom5_6, dom7_9],
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            make_min,
+            vec![dom7_9, dom2_8, dom5_6, dom7_9],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            4,
+            make_min,
+            vec![(6, 10).to_interval(), dom2_8, dom5_6, dom7_9],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Bound), (2, Assignment)],
+            true,
+        );
+    }
+
+    #[test]
+    fn array_max_test() {
+        let make_max = |mut xs: Vec<FDVar>| {
+            let z = xs.remove(0);
+            ArrayMax::new(z, xs)
+        };
+        let dom0_10 = (0, 10).to_interval();
+        let dom2_8 = (2, 8).to_interval();
+        let dom5_6 = (5, 6).to_interval();
+        let dom7_9 = (7, 9).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_max,
+            vec![dom0_10, dom2_8, dom5_6, dom7_9],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_max,
+            vec![dom5_6, dom2_8, dom5_6, dom7_9],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            3,
+            make_max,
+            vec![(9, 9).to_interval(), dom2_8, dom5_6, dom7_9],
+            Unknown,
+            True,
+            vec![(3, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            make_max,
+            vec![dom0_10, (9, 12).to_interval(), dom5_6, dom7_9],
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn binary_min_max_test() {
+        let dom0_10 = (0, 10).to_interval();
+        let dom3_5 = (3, 5).to_interval();
+        let dom4_9 = (4, 9).to_interval();
+        let make_min = |mut xs: Vec<FDVar>| {
+            let y = xs.pop().unwrap();
+            let x = xs.pop().unwrap();
+            min(xs.pop().unwrap(), x, y)
+        };
+        let make_max = |mut xs: Vec<FDVar>| {
+            let y = xs.pop().unwrap();
+            let x = xs.pop().unwrap();
+            max(xs.pop().unwrap(), x, y)
+        };
+        nary_propagator_test(
+            1,
+            make_min,
+            vec![dom0_10, dom3_5, dom4_9],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_max,
+            vec![dom0_10, dom3_5, dom4_9],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+    }
+}
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod min_max;
+pub mod x_eq_abs_y;
 pub mod x_eq_y;
+pub mod x_eq_y_div_z;
+pub mod x_eq_y_mod_z;
 pub mod x_eq_y_mul_z;
 pub mod x_eq_y_plus_z;
+pub mod x_eq_y_pow_k;
 pub mod x_greater_y_plus_z;
 pub mod x_less_y;
 pub mod x_less_y_plus_z;
@@ -22,9 +27,14 @@ pub mod x_neq_y;
 
 use concept::*;
 use gcollections::*;
+pub use propagators::cmp::min_max::{max, min, ArrayMax, ArrayMin};
+pub use propagators::cmp::x_eq_abs_y::XEqAbsY;
 pub use propagators::cmp::x_eq_y::XEqY;
+pub use propagators::cmp::x_eq_y_div_z::XEqYDivZ;
+pub use propagators::cmp::x_eq_y_mod_z::XEqYModZ;
 pub use propagators::cmp::x_eq_y_mul_z::XEqYMulZ;
 pub use propagators::cmp::x_eq_y_plus_z::XEqYPlusZ;
+pub use propagators::cmp::x_eq_y_pow_k::XEqYPowK;
 pub use propagators::cmp::x_greater_y_plus_z::XGreaterYPlusZ;
 pub use propagators::cmp::x_less_y::XLessY;
 pub use propagators::cmp::x_less_y_plus_z::XLessYPlusZ;
@@ -0,0 +1,160 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::cmp::x_eq_y::*;
+use term;
+use trilean::SKleene;
+
+/// `x = |y|`.
+/// It is the constraint `XEqY` between `x` and the view `term::Abs` of `y`, and it is bounds consistent.
+#[derive(Debug)]
+pub struct XEqAbsY<VStore> {
+    eq: XEqY<VStore>,
+}
+
+impl<VStore, Domain, Bound> XEqAbsY<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    pub fn new(x: Var<VStore>, y: Var<VStore>) -> Self {
+        XEqAbsY {
+            eq: XEqY::new(x, Box::new(term::Abs::new(y))),
+        }
+    }
+}
+
+impl<VStore> Clone for XEqAbsY<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        XEqAbsY {
+            eq: self.eq.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for XEqAbsY<VStore> {
+    fn display(&self, model: &Model) {
+        self.eq.display(model);
+    }
+}
+
+impl<VStore> NotFormula<VStore> for XEqAbsY<VStore>
+where
+    XEqY<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.eq.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for XEqAbsY<VStore>
+where
+    XEqY<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        self.eq.is_subsumed(vstore)
+    }
+}
+
+impl<VStore> Propagator<VStore> for XEqAbsY<VStore>
+where
+    XEqY<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        self.eq.propagate(vstore)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for XEqAbsY<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.eq.dependencies()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use trilean::SKleene::*;
+
+    #[test]
+    fn abs_test() {
+        let dom0_10 = (0, 10).to_interval();
+        let dom5_3 = (-5, 3).to_interval();
+
+        abs_test_one(1, dom0_10, dom5_3, Unknown, Unknown, vec![(0, Bound)], true);
+        abs_test_one(
+            2,
+            (4, 10).to_interval(),
+            dom5_3,
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        abs_test_one(
+            3,
+            (2, 2).to_interval(),
+            (-1, 3).to_interval(),
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        abs_test_one(4, (6, 9).to_interval(), dom5_3, False, False, vec![], false);
+        abs_test_one(
+            5,
+            (3, 3).to_interval(),
+            (-3, -3).to_interval(),
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    fn abs_test_one(
+        test_num: u32,
+        x: Interval<isize>,
+        y: Interval<isize>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        binary_propagator_test(
+            test_num,
+            XEqAbsY::new,
+            x,
+            y,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
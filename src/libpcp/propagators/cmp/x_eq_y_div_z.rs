@@ -0,0 +1,371 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use std::cmp::max;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// Bounds of the negative and positive values of `z`, zero excluded.
+fn nonzero_parts<Domain, Bound>(z: &Domain) -> Vec<(Bound, Bound)>
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    let one = Bound::one();
+    [
+        z.clone().shrink_right(-one.clone()),
+        z.clone().shrink_left(one),
+    ]
+    .iter()
+    .filter(|part| !part.is_empty())
+    .map(|part| (part.lower(), part.upper()))
+    .collect()
+}
+
+/// Bounds of `y / z` for `y` in `[yl, yu]` and `z` in `parts`.
+/// The truncated division is monotone in `y` and in `z` on each side of zero, so the bounds are reached at the corners.
+fn quotient<Bound>(yl: Bound, yu: Bound, parts: &[(Bound, Bound)]) -> (Bound, Bound)
+where
+    Bound: IntBound,
+{
+    let corners: Vec<Bound> = parts
+        .iter()
+        .flat_map(|(zl, zu)| {
+            vec![
+                yl.clone() / zl.clone(),
+                yl.clone() / zu.clone(),
+                yu.clone() / zl.clone(),
+                yu.clone() / zu.clone(),
+            ]
+        })
+        .collect();
+    (
+        corners.iter().min().unwrap().clone(),
+        corners.iter().max().unwrap().clone(),
+    )
+}
+
+/// Bounds of `y` such that `y / z` is in `[xl, xu]` for `z` in `parts`.
+/// We have `y = x * z + r` where `r` has the sign of `y` and `|r| < |z|`.
+fn dividend<Bound>(xl: Bound, xu: Bound, parts: &[(Bound, Bound)]) -> (Bound, Bound)
+where
+    Bound: IntBound,
+{
+    let zero = Bound::zero();
+    let mut bounds = vec![];
+    for (zl, zu) in parts {
+        let slack = max(zl.abs(), zu.abs()) - Bound::one();
+        let products = [
+            xl.clone() * zl.clone(),
+            xl.clone() * zu.clone(),
+            xu.clone() * zl.clone(),
+            xu.clone() * zu.clone(),
+        ];
+        let pl = products.iter().min().unwrap().clone();
+        let ph = products.iter().max().unwrap().clone();
+        let lower = if pl <= zero { pl - slack.clone() } else { pl };
+        let upper = if ph >= zero { ph + slack } else { ph };
+        bounds.push((lower, upper));
+    }
+    (
+        bounds.iter().map(|b| b.0.clone()).min().unwrap(),
+        bounds.iter().map(|b| b.1.clone()).max().unwrap(),
+    )
+}
+
+/// `x = y / z` where the division is truncated towards zero, as in Rust.
+/// The filtering is bounds consistent on `x` and `y`, and `z` is restricted to non-zero values; the propagation fails if `z` can only be zero.
+#[derive(Debug)]
+pub struct XEqYDivZ<VStore> {
+    x: Var<VStore>,
+    y: Var<VStore>,
+    z: Var<VStore>,
+}
+
+impl<VStore> XEqYDivZ<VStore> {
+    pub fn new(x: Var<VStore>, y: Var<VStore>, z: Var<VStore>) -> Self {
+        XEqYDivZ { x, y, z }
+    }
+}
+
+impl<VStore> Clone for XEqYDivZ<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        XEqYDivZ::new(self.x.bclone(), self.y.bclone(), self.z.bclone())
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for XEqYDivZ<VStore> {
+    fn display(&self, model: &Model) {
+        self.x.display(model);
+        print!(" = ");
+        self.y.display(model);
+        print!(" / ");
+        self.z.display(model);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for XEqYDivZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// There is no view on `y / z`, so the negation only fails once `x = y / z` is entailed.
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for XEqYDivZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let x = self.x.read(store);
+        let y = self.y.read(store);
+        let z = self.z.read(store);
+        let parts = nonzero_parts(&z);
+        if parts.is_empty() {
+            return False;
+        }
+        let (ql, qu) = quotient(y.lower(), y.upper(), &parts);
+        if x.is_disjoint(&Domain::new(ql.clone(), qu.clone())) {
+            False
+        } else if x.is_singleton() && ql == qu && !z.contains(&Bound::zero()) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for XEqYDivZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let z = self.z.read(store).difference(&Bound::zero());
+        if !self.z.update(store, z) {
+            return false;
+        }
+        let parts = nonzero_parts(&self.z.read(store));
+        if parts.is_empty() {
+            return false;
+        }
+        let y = self.y.read(store);
+        let (ql, qu) = quotient(y.lower(), y.upper(), &parts);
+        let x = self.x.read(store).intersection(&Domain::new(ql, qu));
+        if !self.x.update(store, x) {
+            return false;
+        }
+        let x = self.x.read(store);
+        let (yl, yu) = dividend(x.lower(), x.upper(), &parts);
+        let y = self.y.read(store).intersection(&Domain::new(yl, yu));
+        if !self.y.update(store, y) {
+            return false;
+        }
+        // A non-zero quotient requires `|z| <= |y|`.
+        if !x.contains(&Bound::zero()) {
+            let y = self.y.read(store);
+            let m = max(y.lower().abs(), y.upper().abs());
+            let z = self.z.read(store).intersection(&Domain::new(-m.clone(), m));
+            if !self.z.update(store, z) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for XEqYDivZ<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps = self.x.dependencies(FDEvent::Bound);
+        deps.append(&mut self.y.dependencies(FDEvent::Bound));
+        // Removing zero from `z` changes the bounds of its non-zero parts.
+        deps.append(&mut self.z.dependencies(FDEvent::Inner));
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+
+    #[test]
+    fn quotient_dividend_test() {
+        let parts = vec![(-3, -2), (1, 4)];
+        assert_eq!(quotient(-7, 9, &parts), (-7, 9));
+        assert_eq!(quotient(7, 9, &parts), (-4, 9));
+        assert_eq!(quotient(7, 9, &parts[..1]), (-4, -2));
+        assert_eq!(dividend(2, 2, &[(3, 3)]), (6, 8));
+        assert_eq!(dividend(-2, -2, &[(3, 3)]), (-8, -6));
+        assert_eq!(dividend(0, 0, &[(-3, -3)]), (-2, 2));
+        assert_eq!(dividend(1, 2, &[(-3, -2)]), (-8, -2));
+    }
+
+    #[test]
+    fn x_eq_y_div_z_test() {
+        let dom0_10 = (0, 10).to_interval();
+        let dom_m20_20 = (-20, 20).to_interval();
+        let zero = (0, 0).to_interval();
+        let three = (3, 3).to_interval();
+        let minus_three = (-3, -3).to_interval();
+
+        trinary_propagator_test(
+            1,
+            XEqYDivZ::new,
+            dom_m20_20,
+            (6, 8).to_interval(),
+            three,
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        trinary_propagator_test(
+            2,
+            XEqYDivZ::new,
+            dom_m20_20,
+            (-9, 7).to_interval(),
+            minus_three,
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            3,
+            XEqYDivZ::new,
+            (2, 2).to_interval(),
+            (-20, 20).to_interval(),
+            three,
+            Unknown,
+            True,
+            vec![(1, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            4,
+            XEqYDivZ::new,
+            (-2, -2).to_interval(),
+            (-20, 20).to_interval(),
+            three,
+            Unknown,
+            True,
+            vec![(1, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            5,
+            XEqYDivZ::new,
+            dom0_10,
+            dom0_10,
+            zero,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            6,
+            XEqYDivZ::new,
+            dom0_10,
+            (4, 4).to_interval(),
+            (0, 2).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            7,
+            XEqYDivZ::new,
+            (5, 10).to_interval(),
+            (1, 9).to_interval(),
+            (2, 4).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            8,
+            XEqYDivZ::new,
+            (1, 1).to_interval(),
+            (2, 3).to_interval(),
+            (-10, 10).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(2, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn not_x_eq_y_div_z_test() {
+        let not_div = |x, y, z| Negation::new(XEqYDivZ::new(x, y, z));
+        let three = (3, 3).to_interval();
+
+        trinary_propagator_test(
+            1,
+            not_div,
+            (2, 2).to_interval(),
+            (6, 8).to_interval(),
+            three,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            2,
+            not_div,
+            (2, 2).to_interval(),
+            (9, 9).to_interval(),
+            three,
+            True,
+            True,
+            vec![],
+            true,
+        );
+        trinary_propagator_test(
+            3,
+            not_div,
+            (0, 10).to_interval(),
+            (6, 8).to_interval(),
+            three,
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+    }
+}
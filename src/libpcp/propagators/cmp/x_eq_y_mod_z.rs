@@ -0,0 +1,314 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use std::cmp::{max, min};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// `x = y % z` where the remainder has the sign of `y`, as in Rust.
+/// The filtering is bounds consistent on `x` with respect to the bounds of `y` and `z`: `|x| < |z|`, `|x| <= |y|` and `x` has the sign of `y`.
+/// Conversely, a non-zero remainder bounds `y` and `|z|` from below. `z` is restricted to non-zero values and the propagation fails if `z` can only be zero.
+#[derive(Debug)]
+pub struct XEqYModZ<VStore> {
+    x: Var<VStore>,
+    y: Var<VStore>,
+    z: Var<VStore>,
+}
+
+impl<VStore> XEqYModZ<VStore> {
+    pub fn new(x: Var<VStore>, y: Var<VStore>, z: Var<VStore>) -> Self {
+        XEqYModZ { x, y, z }
+    }
+}
+
+impl<VStore> Clone for XEqYModZ<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        XEqYModZ::new(self.x.bclone(), self.y.bclone(), self.z.bclone())
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for XEqYModZ<VStore> {
+    fn display(&self, model: &Model) {
+        self.x.display(model);
+        print!(" = ");
+        self.y.display(model);
+        print!(" % ");
+        self.z.display(model);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for XEqYModZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// There is no view on `y % z`, so the negation only fails once `x = y % z` is entailed.
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> XEqYModZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Smallest and largest absolute values of the non-zero values of `z`, or `None` if `z` can only be zero.
+    fn divisor_bounds(&self, store: &VStore) -> Option<(Bound, Bound)> {
+        let z = self.z.read(store);
+        let one = Bound::one();
+        let negative = z.clone().shrink_right(-one.clone());
+        let positive = z.shrink_left(one);
+        match (negative.is_empty(), positive.is_empty()) {
+            (true, true) => None,
+            (false, true) => Some((-negative.upper(), -negative.lower())),
+            (true, false) => Some((positive.lower(), positive.upper())),
+            (false, false) => Some((
+                min(-negative.upper(), positive.lower()),
+                max(-negative.lower(), positive.upper()),
+            )),
+        }
+    }
+
+    /// Bounds of `y % z` given the bounds of `y` and the non-zero values of `z`, or `None` if `z` can only be zero.
+    fn remainder(&self, store: &VStore) -> Option<Domain> {
+        let (_, largest) = self.divisor_bounds(store)?;
+        let y = self.y.read(store);
+        let z = self.z.read(store);
+        let one = Bound::one();
+        let lower = if y.lower() < Bound::zero() {
+            max(-(largest.clone() - one.clone()), y.lower())
+        } else {
+            Bound::zero()
+        };
+        let upper = if y.upper() > Bound::zero() {
+            min(largest - one, y.upper())
+        } else {
+            Bound::zero()
+        };
+        if y.is_singleton() && z.is_singleton() {
+            let r = y.lower() % z.lower();
+            Some(Domain::singleton(r))
+        } else {
+            Some(Domain::new(lower, upper))
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for XEqYModZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let x = self.x.read(store);
+        match self.remainder(store) {
+            None => False,
+            Some(ref r) if x.is_disjoint(r) => False,
+            Some(ref r) if x.is_singleton() && r.is_singleton() => True,
+            Some(_) => Unknown,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for XEqYModZ<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let zero = Bound::zero();
+        let z = self.z.read(store).difference(&zero);
+        if !self.z.update(store, z) {
+            return false;
+        }
+        let r = match self.remainder(store) {
+            Some(r) => r,
+            None => return false,
+        };
+        let x = self.x.read(store).intersection(&r);
+        if !self.x.update(store, x) {
+            return false;
+        }
+        let x = self.x.read(store);
+        let y = self.y.read(store);
+        let z = self.z.read(store);
+        // If `|y| < |z|` then `x = y`.
+        let (smallest, _) = self.divisor_bounds(store).unwrap();
+        if -smallest.clone() < y.lower() && y.upper() < smallest {
+            let y = y.intersection(&x);
+            if !self.y.update(store, y.clone()) {
+                return false;
+            }
+            return self.x.update(store, x.intersection(&y));
+        }
+        // A non-zero remainder has the sign of `y`, and `|z| > |x|`.
+        let y = if x.lower() > zero {
+            y.shrink_left(x.lower())
+        } else if x.upper() < zero {
+            y.shrink_right(x.upper())
+        } else {
+            y
+        };
+        if !self.y.update(store, y) {
+            return false;
+        }
+        if !x.contains(&zero) {
+            let a = min(x.lower().abs(), x.upper().abs());
+            let z = z.difference(&Domain::new(-a.clone(), a));
+            if !self.z.update(store, z) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for XEqYModZ<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps = self.x.dependencies(FDEvent::Inner);
+        deps.append(&mut self.y.dependencies(FDEvent::Bound));
+        deps.append(&mut self.z.dependencies(FDEvent::Inner));
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+
+    #[test]
+    fn x_eq_y_mod_z_test() {
+        let dom_m20_20 = (-20, 20).to_interval();
+        let dom_m10_10 = (-10, 10).to_interval();
+        let three = (3, 3).to_interval();
+
+        trinary_propagator_test(
+            1,
+            XEqYModZ::new,
+            dom_m20_20,
+            (7, 9).to_interval(),
+            three,
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            2,
+            XEqYModZ::new,
+            dom_m20_20,
+            (-2, 1).to_interval(),
+            (5, 6).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            3,
+            XEqYModZ::new,
+            (2, 2).to_interval(),
+            dom_m10_10,
+            (-5, 5).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+        trinary_propagator_test(
+            4,
+            XEqYModZ::new,
+            (3, 4).to_interval(),
+            dom_m10_10,
+            (-3, 2).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            5,
+            XEqYModZ::new,
+            dom_m10_10,
+            dom_m10_10,
+            (0, 0).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            6,
+            XEqYModZ::new,
+            (1, 1).to_interval(),
+            (7, 7).to_interval(),
+            three,
+            True,
+            True,
+            vec![],
+            true,
+        );
+        trinary_propagator_test(
+            7,
+            XEqYModZ::new,
+            (-5, 5).to_interval(),
+            (-7, -7).to_interval(),
+            three,
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        trinary_propagator_test(
+            8,
+            XEqYModZ::new,
+            (0, 5).to_interval(),
+            (-7, -7).to_interval(),
+            three,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        trinary_propagator_test(
+            9,
+            XEqYModZ::new,
+            (2, 5).to_interval(),
+            dom_m10_10,
+            (0, 4).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound), (2, Bound)],
+            true,
+        );
+    }
+}
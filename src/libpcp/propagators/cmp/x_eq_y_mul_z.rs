@@ -59,9 +59,14 @@ impl<VStore> DisplayStateful<Model> for XEqYMulZ<VStore> {
     }
 }
 
-impl<VStore> NotFormula<VStore> for XEqYMulZ<VStore> {
+impl<VStore, Dom> NotFormula<VStore> for XEqYMulZ<VStore>
+where
+    VStore: VStoreConcept<Item = Dom> + 'static,
+    Dom: Bounded + IsSingleton + Mul<Output = Dom> + Overlap + Intersection<Output = Dom> + 'static,
+{
+    /// There is no view on `y * z`, so the negation only fails once `x = y * z` is entailed.
     fn not(&self) -> Formula<VStore> {
-        unimplemented!();
+        Box::new(Negation::new(self.clone()))
     }
 }
 
@@ -144,6 +149,78 @@ mod test {
             vec![(0, Assignment)],
             true,
         );
+        // Intervals crossing zero.
+        x_eq_y_mul_z_test_one(
+            6,
+            (-20, 20).to_interval(),
+            (-2, 3).to_interval(),
+            (-4, 1).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        x_eq_y_mul_z_test_one(
+            7,
+            (-5, 5).to_interval(),
+            (-1, 1).to_interval(),
+            (-3, -3).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        x_eq_y_mul_z_test_one(
+            8,
+            (-30, -13).to_interval(),
+            (-2, 3).to_interval(),
+            (-4, 1).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        x_eq_y_mul_z_test_one(
+            9,
+            (0, 0).to_interval(),
+            (-2, 3).to_interval(),
+            (-4, 1).to_interval(),
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        // Negative intervals.
+        x_eq_y_mul_z_test_one(
+            10,
+            (5, 9).to_interval(),
+            (-2, -1).to_interval(),
+            (-4, -3).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        x_eq_y_mul_z_test_one(
+            11,
+            (9, 12).to_interval(),
+            (-2, -1).to_interval(),
+            (-4, -3).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        x_eq_y_mul_z_test_one(
+            12,
+            (-12, 12).to_interval(),
+            (-2, -1).to_interval(),
+            (3, 4).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
     }
 
     fn x_eq_y_mul_z_test_one(
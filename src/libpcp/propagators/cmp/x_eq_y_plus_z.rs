@@ -67,12 +67,15 @@ where
     }
 }
 
-impl<VStore> NotFormula<VStore> for XEqYPlusZ<VStore>
+impl<VStore, Domain, Bound> NotFormula<VStore> for XEqYPlusZ<VStore>
 where
-    VStore: Collection,
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
 {
+    /// `x < y + z \/ x > y + z`.
     fn not(&self) -> Formula<VStore> {
-        unimplemented!()
+        Box::new(Disjunction::new(vec![self.geq.not(), self.leq.not()]))
     }
 }
 
@@ -0,0 +1,368 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use std::cmp::{max, min};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+fn pow<Bound: IntBound>(b: Bound, k: u32) -> Bound {
+    (0..k).fold(Bound::one(), |acc, _| acc * b.clone())
+}
+
+/// Whether `b^k <= v` for `b >= 0`, without overflowing when `b^k` is much larger than `v`.
+fn pow_leq<Bound: IntBound>(b: Bound, k: u32, v: &Bound) -> bool {
+    let mut acc = Bound::one();
+    for _ in 0..k {
+        if !b.is_zero() && acc > v.clone() / b.clone() {
+            return false;
+        }
+        acc = acc * b.clone();
+    }
+    acc <= *v
+}
+
+/// Largest `r >= 0` such that `r^k <= v`, where `v >= 0` and `k > 0`.
+fn root_floor<Bound: IntBound>(v: Bound, k: u32) -> Bound {
+    let two = Bound::one() + Bound::one();
+    let mut lo = Bound::zero();
+    let mut hi = v.clone();
+    while lo < hi {
+        // Rounded up so that `lo` progresses, without computing `hi + 1`.
+        let d = hi.clone() - lo.clone();
+        let mid = lo.clone() + d.clone() / two.clone() + d % two.clone();
+        if pow_leq(mid.clone(), k, &v) {
+            lo = mid;
+        } else {
+            hi = mid - Bound::one();
+        }
+    }
+    lo
+}
+
+/// Smallest `r >= 0` such that `r^k >= v`, where `v >= 0` and `k > 0`.
+fn root_ceil<Bound: IntBound>(v: Bound, k: u32) -> Bound {
+    let r = root_floor(v.clone(), k);
+    if pow(r.clone(), k) == v {
+        r
+    } else {
+        r + Bound::one()
+    }
+}
+
+/// `x = y^k` where `k` is a constant.
+/// The filtering is bounds consistent: `x` is restricted to the powers of the bounds of `y`, and `y` to the `k`-th roots of the bounds of `x`.
+/// For an even `k`, the values of `y` whose absolute value is too small are also removed if the domain can represent holes.
+#[derive(Debug)]
+pub struct XEqYPowK<VStore> {
+    x: Var<VStore>,
+    y: Var<VStore>,
+    k: u32,
+}
+
+impl<VStore> XEqYPowK<VStore> {
+    pub fn new(x: Var<VStore>, y: Var<VStore>, k: u32) -> Self {
+        XEqYPowK { x, y, k }
+    }
+}
+
+impl<VStore> Clone for XEqYPowK<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        XEqYPowK::new(self.x.bclone(), self.y.bclone(), self.k)
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for XEqYPowK<VStore> {
+    fn display(&self, model: &Model) {
+        self.x.display(model);
+        print!(" = ");
+        self.y.display(model);
+        print!("^{}", self.k);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for XEqYPowK<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// There is no view on `y^k`, so the negation only fails once `x = y^k` is entailed.
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> XEqYPowK<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Bounds of `y^k`.
+    /// Precondition: `y` is restricted by `root`, so the powers of its bounds lie between the bounds of `x` and do not overflow.
+    fn power(&self, y: &Domain) -> Domain {
+        let (yl, yu) = (y.lower(), y.upper());
+        if self.k % 2 == 1 {
+            Domain::new(pow(yl, self.k), pow(yu, self.k))
+        } else {
+            let zero = Bound::zero();
+            let smallest = if yl <= zero && zero <= yu {
+                zero
+            } else {
+                min(yl.abs(), yu.abs())
+            };
+            let largest = max(yl.abs(), yu.abs());
+            Domain::new(pow(smallest, self.k), pow(largest, self.k))
+        }
+    }
+
+    /// Bounds of `y` such that `y^k` is in `x`, restricted on `y`.
+    fn root(&self, x: &Domain, y: Domain) -> Domain {
+        let zero = Bound::zero();
+        let (xl, xu) = (x.lower(), x.upper());
+        if self.k % 2 == 1 {
+            let lower = if xl >= zero {
+                root_ceil(xl, self.k)
+            } else {
+                -root_floor(-xl, self.k)
+            };
+            let upper = if xu >= zero {
+                root_floor(xu, self.k)
+            } else {
+                -root_ceil(-xu, self.k)
+            };
+            y.shrink_left(lower).shrink_right(upper)
+        } else if xu < zero {
+            Domain::empty()
+        } else {
+            let r = root_floor(xu, self.k);
+            let y = y.shrink_left(-r.clone()).shrink_right(r);
+            let c = root_ceil(max(xl, zero.clone()), self.k);
+            if c > zero {
+                let gap = c - Bound::one();
+                y.difference(&Domain::new(-gap.clone(), gap))
+            } else {
+                y
+            }
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for XEqYPowK<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let x = self.x.read(store);
+        let y = self.y.read(store);
+        if self.k == 0 {
+            return if !x.contains(&Bound::one()) {
+                False
+            } else if x.is_singleton() {
+                True
+            } else {
+                Unknown
+            };
+        }
+        let y_root = self.root(&x, y.clone());
+        if y_root.is_empty() || x.is_disjoint(&self.power(&y_root)) {
+            False
+        } else if x.is_singleton() && y.is_singleton() {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for XEqYPowK<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        if self.k == 0 {
+            let x = self
+                .x
+                .read(store)
+                .intersection(&Domain::singleton(Bound::one()));
+            return self.x.update(store, x);
+        }
+        // `y` is restricted first so that `power` does not overflow.
+        let y = self.root(&self.x.read(store), self.y.read(store));
+        if !self.y.update(store, y) {
+            return false;
+        }
+        let y = self.y.read(store);
+        let x = self.x.read(store).intersection(&self.power(&y));
+        if !self.x.update(store, x) {
+            return false;
+        }
+        let x = self.x.read(store);
+        let y = self.root(&x, self.y.read(store));
+        self.y.update(store, y)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for XEqYPowK<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps = self.x.dependencies(FDEvent::Bound);
+        // For an even `k`, removing values around zero changes the lower bound of `x`.
+        deps.append(&mut self.y.dependencies(FDEvent::Inner));
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+
+    #[test]
+    fn roots_test() {
+        assert_eq!(root_floor(30, 2), 5);
+        assert_eq!(root_ceil(30, 2), 6);
+        assert_eq!(root_floor(27, 3), 3);
+        assert_eq!(root_ceil(27, 3), 3);
+        assert_eq!(root_floor(0, 3), 0);
+        assert_eq!(root_floor(1, 5), 1);
+        assert_eq!(root_floor(isize::MAX, 2), 3037000499);
+    }
+
+    #[test]
+    fn x_eq_y_pow_k_test() {
+        let dom_m10_10 = (-10, 10).to_interval();
+
+        let unit = |x, y| XEqYPowK::new(x, y, 0);
+        let square = |x, y| XEqYPowK::new(x, y, 2);
+        let cube = |x, y| XEqYPowK::new(x, y, 3);
+
+        binary_propagator_test(
+            1,
+            square,
+            (0, 100).to_interval(),
+            (-3, 5).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        binary_propagator_test(
+            2,
+            square,
+            (5, 30).to_interval(),
+            dom_m10_10,
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        binary_propagator_test(
+            3,
+            square,
+            (5, 30).to_interval(),
+            (1, 10).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        binary_propagator_test(
+            4,
+            cube,
+            (-30, 30).to_interval(),
+            (-5, 2).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        binary_propagator_test(
+            5,
+            cube,
+            (10, 20).to_interval(),
+            (-5, 5).to_interval(),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        binary_propagator_test(
+            6,
+            cube,
+            (-8, -8).to_interval(),
+            dom_m10_10,
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        binary_propagator_test(
+            7,
+            square,
+            (-4, -1).to_interval(),
+            dom_m10_10,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        binary_propagator_test(
+            8,
+            unit,
+            (0, 5).to_interval(),
+            dom_m10_10,
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        // The power of the upper bound of `y` overflows.
+        binary_propagator_test(
+            9,
+            cube,
+            (0, 1000).to_interval(),
+            (0, isize::MAX / 2).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+        binary_propagator_test(
+            10,
+            square,
+            (4, 4).to_interval(),
+            (isize::MIN / 2, isize::MAX / 2).to_interval(),
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+    }
+}
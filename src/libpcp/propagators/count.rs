@@ -0,0 +1,852 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constraints on the number of variables taking some values: `Among`, `Count`, `AtMost` and `AtLeast`.
+//!
+//! They share the same filtering: a variable is *mandatory* if its domain is included in the counted values, and *possible* if it intersects them. If the mandatory variables already reach the maximal number of occurrences, the counted values are removed from the other variables, and if the possible variables are just enough to reach the minimal number of occurrences, they are restricted to the counted values.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+enum Limit<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    Exactly(Var<VStore>),
+    /// Negation of `Exactly`: the number of occurrences is different from the count.
+    Different(Var<VStore>),
+    AtMost(<VStore::Item as Collection>::Item),
+    AtLeast(<VStore::Item as Collection>::Item),
+}
+
+impl<VStore, Domain, Bound> Clone for Limit<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            Limit::Exactly(ref count) => Limit::Exactly(count.bclone()),
+            Limit::Different(ref count) => Limit::Different(count.bclone()),
+            Limit::AtMost(ref limit) => Limit::AtMost(limit.clone()),
+            Limit::AtLeast(ref limit) => Limit::AtLeast(limit.clone()),
+        }
+    }
+}
+
+/// The number of variables in `vars` taking a value in `values` is equal to `count`.
+pub struct Among<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    vars: Vec<Var<VStore>>,
+    values: Vec<<VStore::Item as Collection>::Item>,
+    limit: Limit<VStore>,
+}
+
+impl<VStore, Domain, Bound> Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    pub fn new(vars: Vec<Var<VStore>>, values: Vec<Bound>, count: Var<VStore>) -> Self {
+        Among::make(vars, values, Limit::Exactly(count))
+    }
+
+    fn make(vars: Vec<Var<VStore>>, mut values: Vec<Bound>, limit: Limit<VStore>) -> Self {
+        values.sort();
+        values.dedup();
+        Among {
+            vars,
+            values,
+            limit,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        let mut fmt = fmt.debug_struct("Among");
+        fmt.field("vars", &self.vars).field("values", &self.values);
+        match self.limit {
+            Limit::Exactly(ref count) => fmt.field("count", count),
+            Limit::Different(ref count) => fmt.field("not_count", count),
+            Limit::AtMost(ref limit) => fmt.field("at_most", limit),
+            Limit::AtLeast(ref limit) => fmt.field("at_least", limit),
+        };
+        fmt.finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Among {
+            vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            values: self.values.clone(),
+            limit: self.limit.clone(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        if self.values.len() == 1 {
+            model.display_call("count", &self.vars);
+            print!(" of {:?}", self.values[0]);
+        } else {
+            model.display_call("among", &self.vars);
+            print!(" of {:?}", self.values);
+        }
+        match self.limit {
+            Limit::Exactly(ref count) => {
+                print!(" = ");
+                count.display(model);
+            }
+            Limit::Different(ref count) => {
+                print!(" != ");
+                count.display(model);
+            }
+            Limit::AtMost(ref limit) => print!(" <= {:?}", limit),
+            Limit::AtLeast(ref limit) => print!(" >= {:?}", limit),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        let limit = match self.limit {
+            Limit::Exactly(ref count) => Limit::Different(count.bclone()),
+            Limit::Different(ref count) => Limit::Exactly(count.bclone()),
+            Limit::AtMost(ref limit) => Limit::AtLeast(limit.clone() + Bound::one()),
+            Limit::AtLeast(ref limit) => Limit::AtMost(limit.clone() - Bound::one()),
+        };
+        Box::new(Among::make(
+            self.vars.iter().map(|v| v.bclone()).collect(),
+            self.values.clone(),
+            limit,
+        ))
+    }
+}
+
+impl<VStore, Domain, Bound> Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Numbers of mandatory and possible variables.
+    fn occurrences(&self, store: &VStore) -> (Bound, Bound) {
+        let mut mandatory = 0;
+        let mut possible = 0;
+        for var in &self.vars {
+            let dom = var.read(store);
+            if self.values.iter().any(|v| dom.contains(v)) {
+                possible += 1;
+                if is_subset_of_values(&dom, &self.values) {
+                    mandatory += 1;
+                }
+            }
+        }
+        (bound_of_usize(mandatory), bound_of_usize(possible))
+    }
+
+    /// Removes the counted values from the undecided variables (`inside == false`), or restricts them to the counted values (`inside == true`).
+    fn decide_all(&mut self, store: &mut VStore, inside: bool) -> bool {
+        for i in 0..self.vars.len() {
+            let dom = self.vars[i].read(store);
+            let dom = if inside {
+                if !self.values.iter().any(|v| dom.contains(v)) {
+                    continue;
+                }
+                restrict_to_values(dom, &self.values)
+            } else if !is_subset_of_values(&dom, &self.values) {
+                remove_values(dom, &self.values)
+            } else {
+                continue;
+            };
+            if !self.vars[i].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let (mandatory, possible) = self.occurrences(store);
+        match self.limit {
+            Limit::Exactly(ref count) => {
+                let count = count.read(store);
+                if mandatory > count.upper() || possible < count.lower() {
+                    False
+                } else if mandatory == possible && count.is_singleton() {
+                    True
+                } else {
+                    Unknown
+                }
+            }
+            Limit::Different(ref count) => {
+                let count = count.read(store);
+                if mandatory > count.upper() || possible < count.lower() {
+                    True
+                } else if mandatory == possible && count.is_singleton() {
+                    False
+                } else {
+                    Unknown
+                }
+            }
+            Limit::AtMost(ref limit) if mandatory > *limit => False,
+            Limit::AtMost(ref limit) if possible <= *limit => True,
+            Limit::AtLeast(ref limit) if possible < *limit => False,
+            Limit::AtLeast(ref limit) if mandatory >= *limit => True,
+            _ => Unknown,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Among<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let (mandatory, possible) = self.occurrences(store);
+        let (lb, ub) = match self.limit {
+            Limit::Exactly(ref mut count) => {
+                let dom = count
+                    .read(store)
+                    .intersection(&Domain::new(mandatory.clone(), possible.clone()));
+                if !count.update(store, dom) {
+                    return false;
+                }
+                let dom = count.read(store);
+                (dom.lower(), dom.upper())
+            }
+            Limit::Different(ref mut count) => {
+                // The number of occurrences is only known once all the variables are decided.
+                if mandatory == possible {
+                    let dom = count.read(store).difference(&mandatory);
+                    return count.update(store, dom);
+                }
+                return true;
+            }
+            Limit::AtMost(ref limit) => (Bound::zero(), limit.clone()),
+            Limit::AtLeast(ref limit) => (limit.clone(), possible.clone()),
+        };
+        if mandatory > ub || possible < lb {
+            false
+        } else if mandatory == ub {
+            self.decide_all(store, false)
+        } else if possible == lb {
+            self.decide_all(store, true)
+        } else {
+            true
+        }
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Among<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .vars
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect();
+        match self.limit {
+            Limit::Exactly(ref count) | Limit::Different(ref count) => {
+                deps.append(&mut count.dependencies(FDEvent::Bound))
+            }
+            Limit::AtMost(_) | Limit::AtLeast(_) => (),
+        }
+        deps
+    }
+}
+
+/// The number of variables in `vars` equal to `value` is `count`.
+pub struct Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    among: Among<VStore>,
+}
+
+impl<VStore, Domain, Bound> Count<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    pub fn new(vars: Vec<Var<VStore>>, value: Bound, count: Var<VStore>) -> Self {
+        Count {
+            among: Among::new(vars, vec![value], count),
+        }
+    }
+}
+
+/// At most `limit` variables in `vars` are equal to `value`.
+pub struct AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    among: Among<VStore>,
+}
+
+impl<VStore, Domain, Bound> AtMost<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    pub fn new(vars: Vec<Var<VStore>>, value: Bound, limit: Bound) -> Self {
+        AtMost {
+            among: Among::make(vars, vec![value], Limit::AtMost(limit)),
+        }
+    }
+}
+
+/// At least `limit` variables in `vars` are equal to `value`.
+pub struct AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    among: Among<VStore>,
+}
+
+impl<VStore, Domain, Bound> AtLeast<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    pub fn new(vars: Vec<Var<VStore>>, value: Bound, limit: Bound) -> Self {
+        AtLeast {
+            among: Among::make(vars, vec![value], Limit::AtLeast(limit)),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Count<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        self.among.fmt(fmt)
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Count<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Count {
+            among: self.among.clone(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for AtMost<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        self.among.fmt(fmt)
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for AtMost<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        AtMost {
+            among: self.among.clone(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for AtLeast<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        self.among.fmt(fmt)
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for AtLeast<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        AtLeast {
+            among: self.among.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: DisplayStateful<Model>,
+{
+    fn display(&self, model: &Model) {
+        self.among.display(model);
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: DisplayStateful<Model>,
+{
+    fn display(&self, model: &Model) {
+        self.among.display(model);
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: DisplayStateful<Model>,
+{
+    fn display(&self, model: &Model) {
+        self.among.display(model);
+    }
+}
+
+impl<VStore> NotFormula<VStore> for Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.among.not()
+    }
+}
+
+impl<VStore> NotFormula<VStore> for AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.among.not()
+    }
+}
+
+impl<VStore> NotFormula<VStore> for AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.among.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        self.among.is_subsumed(store)
+    }
+}
+
+impl<VStore> Subsumption<VStore> for AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        self.among.is_subsumed(store)
+    }
+}
+
+impl<VStore> Subsumption<VStore> for AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        self.among.is_subsumed(store)
+    }
+}
+
+impl<VStore> Propagator<VStore> for Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.among.propagate(store)
+    }
+}
+
+impl<VStore> Propagator<VStore> for AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.among.propagate(store)
+    }
+}
+
+impl<VStore> Propagator<VStore> for AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+    Among<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.among.propagate(store)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Count<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.among.dependencies()
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for AtMost<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.among.dependencies()
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for AtLeast<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.among.dependencies()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn among_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+        let dom1_2 = (1, 2).to_interval();
+
+        // among([x1, x2, x3], [1, 2], count) where `count` is the last variable.
+        among_test_one(
+            1,
+            vec![dom0_3, dom1_2, zero, dom0_3],
+            Unknown,
+            Unknown,
+            vec![(3, Bound)],
+            true,
+        );
+        among_test_one(
+            2,
+            vec![(1, 3).to_interval(), dom1_2, zero, one],
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        among_test_one(
+            3,
+            vec![(0, 1).to_interval(), dom0_3, two, (3, 3).to_interval()],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Bound)],
+            true,
+        );
+        among_test_one(
+            4,
+            vec![dom1_2, dom1_2, zero, zero],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        among_test_one(5, vec![one, two, zero, two], True, True, vec![], true);
+        // count([x1, x2, x3], 1, count)
+        let make_count = |mut vars: Vec<FDVar>| {
+            let count = vars.pop().unwrap();
+            Count::new(vars, 1, count)
+        };
+        nary_propagator_test(
+            6,
+            make_count,
+            vec![one, dom0_1, dom0_1, (0, 1).to_interval()],
+            Unknown,
+            True,
+            vec![(1, Assignment), (2, Assignment), (3, Assignment)],
+            true,
+        );
+    }
+
+    #[test]
+    fn at_most_at_least_test() {
+        let one = (1, 1).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom1_3 = (1, 3).to_interval();
+
+        let at_most = |vars| AtMost::new(vars, 1, 2);
+        nary_propagator_test(
+            1,
+            at_most,
+            vec![one, one, dom0_1, dom1_3],
+            Unknown,
+            True,
+            vec![(2, Assignment), (3, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            at_most,
+            vec![one, one, one, dom1_3],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            3,
+            at_most,
+            vec![one, dom0_1, dom0_1, (2, 3).to_interval()],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        let at_least = |vars| AtLeast::new(vars, 1, 2);
+        nary_propagator_test(
+            4,
+            at_least,
+            vec![dom0_1, dom1_3, (2, 3).to_interval()],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            5,
+            at_least,
+            vec![one, dom0_1, dom1_3],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            6,
+            at_least,
+            vec![one, (2, 3).to_interval(), (2, 3).to_interval()],
+            False,
+            False,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn among_holes_test() {
+        let mut store = VStoreSet::empty();
+        let x = Box::new(store.alloc((0, 5).to_interval_set())) as Var<VStoreSet>;
+        let y = Box::new(store.alloc(vec![(1, 1), (3, 3)].to_interval_set())) as Var<VStoreSet>;
+        let count = Box::new(store.alloc((1, 1).to_interval_set())) as Var<VStoreSet>;
+        // `y` is mandatory, thus the values [1, 3] are removed from `x`.
+        let mut among = Among::new(vec![x, y], vec![1, 3], count);
+        assert_eq!(among.is_subsumed(&store), Unknown);
+        assert!(among.propagate(&mut store));
+        assert_eq!(store[0], vec![(0, 0), (2, 2), (4, 5)].to_interval_set());
+        assert_eq!(among.is_subsumed(&store), True);
+    }
+
+    #[test]
+    fn among_wide_domain_test() {
+        let far = 1isize << 40;
+        let mut store = VStoreSet::empty();
+        let x = Box::new(store.alloc(vec![(0, 0), (far, far)].to_interval_set())) as Var<VStoreSet>;
+        let count = Box::new(store.alloc((0, 0).to_interval_set())) as Var<VStoreSet>;
+        // The domain of `x` is not enumerated since it is larger than the counted values.
+        let mut among = Among::new(vec![x], vec![0], count);
+        assert_eq!(among.is_subsumed(&store), Unknown);
+        assert!(among.propagate(&mut store));
+        assert_eq!(store[0], (far, far).to_interval_set());
+        assert_eq!(among.is_subsumed(&store), True);
+    }
+
+    #[test]
+    fn not_count_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+
+        // not count([x1, x2], 1, count) where `count` is the last variable.
+        let not_count = |mut vars: Vec<FDVar>| {
+            let count = vars.pop().unwrap();
+            Among::make(vars, vec![1], Limit::Different(count))
+        };
+        nary_propagator_test(
+            1,
+            not_count,
+            vec![one, zero, (1, 2).to_interval()],
+            Unknown,
+            True,
+            vec![(2, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            not_count,
+            vec![one, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            not_count,
+            vec![one, one, (2, 2).to_interval()],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            4,
+            not_count,
+            vec![one, zero, zero],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    fn among_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        let make_among = |mut vars: Vec<FDVar>| {
+            let count = vars.pop().unwrap();
+            Among::new(vars, vec![1, 2], count)
+        };
+        nary_propagator_test(
+            test_num,
+            make_among,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
@@ -13,16 +13,28 @@
 // limitations under the License.
 
 use concept::*;
+use gcollections::*;
+use kernel::*;
 use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
 use propagators::*;
 use term::*;
+use trilean::SKleene;
 
+/// `cumulative(starts, durations, resources, capacity)`: at any time, the resources used by the tasks running at that time must not exceed the capacity, where the task `i` runs over `[starts[i], starts[i] + durations[i])`.
+///
+/// It is a global propagator filtering with the compulsory parts of the tasks (time-tabling) and, if enabled with `with_edge_finding`, with edge-finding.
+/// It can also be decomposed into primitive constraints with `join`.
+#[derive(Debug)]
 pub struct Cumulative<VStore> {
     starts: Vec<Var<VStore>>,
     durations: Vec<Var<VStore>>,
     resources: Vec<Var<VStore>>,
     capacity: Var<VStore>,
     intermediate: Vec<Vec<usize>>, // Given intermediate[j][i], if i left-overlap j, then it contains the number of resources used by i.
+    edge_finding: bool,
 }
 
 impl<VStore> Cumulative<VStore> {
@@ -41,8 +53,15 @@ impl<VStore> Cumulative<VStore> {
             resources: resources,
             capacity: capacity,
             intermediate: vec![],
+            edge_finding: false,
         }
     }
+
+    /// Add edge-finding to the filtering of the propagator, it runs in `O(n^3)` where `n` is the number of tasks.
+    pub fn with_edge_finding(mut self) -> Self {
+        self.edge_finding = true;
+        self
+    }
 }
 
 impl<VStore, Domain, Bound> Cumulative<VStore>
@@ -131,14 +150,425 @@ where
     }
 }
 
+impl<VStore> Clone for Cumulative<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Cumulative {
+            starts: self.starts.iter().map(|v| v.bclone()).collect(),
+            durations: self.durations.iter().map(|v| v.bclone()).collect(),
+            resources: self.resources.iter().map(|v| v.bclone()).collect(),
+            capacity: self.capacity.bclone(),
+            intermediate: self.intermediate.clone(),
+            edge_finding: self.edge_finding,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Cumulative<VStore> {
+    fn display(&self, model: &Model) {
+        print!("cumulative(");
+        for vars in &[&self.starts, &self.durations, &self.resources] {
+            print!("[");
+            for (i, v) in vars.iter().enumerate() {
+                if i > 0 {
+                    print!(", ");
+                }
+                v.display(model);
+            }
+            print!("], ");
+        }
+        self.capacity.display(model);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Cumulative<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Cumulative<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn tasks(&self, vstore: &VStore) -> Vec<Task<Bound>> {
+        (0..self.starts.len())
+            .map(|i| {
+                let start = self.starts[i].read(vstore);
+                Task {
+                    est: start.lower(),
+                    lst: start.upper(),
+                    duration: self.durations[i].read(vstore).lower(),
+                    resource: self.resources[i].read(vstore).lower(),
+                }
+            })
+            .collect()
+    }
+
+    fn assigned(&self, vstore: &VStore) -> bool {
+        self.starts
+            .iter()
+            .chain(self.durations.iter())
+            .chain(self.resources.iter())
+            .all(|v| v.read(vstore).is_singleton())
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Cumulative<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        use trilean::SKleene::*;
+        let capacity = self.capacity.read(vstore);
+        let height = max_height(&profile(&self.tasks(vstore)));
+        let max_usage = self
+            .resources
+            .iter()
+            .fold(Bound::zero(), |sum, r| sum + r.read(vstore).upper());
+        if height > capacity.upper() {
+            False
+        } else if max_usage <= capacity.lower() {
+            True
+        } else if self.assigned(vstore) {
+            // The profile is exact once the tasks are assigned.
+            if height <= capacity.lower() {
+                True
+            } else {
+                Unknown
+            }
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Cumulative<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        let capacity = self.capacity.read(vstore);
+        let tasks = self.tasks(vstore);
+        let profile = profile(&tasks);
+        let height = max_height(&profile);
+        if height > capacity.upper() {
+            return false;
+        }
+        // The capacity must cover the compulsory parts and the resources of a task cannot exceed the capacity left by the other tasks while it is surely running.
+        let c_max = capacity.upper();
+        if !self.capacity.update(
+            vstore,
+            capacity.intersection(&Domain::new(height, c_max.clone())),
+        ) {
+            return false;
+        }
+        for (i, task) in tasks.iter().enumerate() {
+            if let Some((start, end)) = task.running() {
+                let others = profile
+                    .iter()
+                    .filter(|s| s.start < end && start < s.end)
+                    .map(|s| s.height.clone() - task.resource.clone())
+                    .max()
+                    .unwrap_or_else(Bound::zero);
+                let resource = self.resources[i].read(vstore);
+                let r_max = c_max.clone() - others;
+                if r_max < resource.lower() {
+                    return false;
+                }
+                let r_min = resource.lower();
+                if !self.resources[i]
+                    .update(vstore, resource.intersection(&Domain::new(r_min, r_max)))
+                {
+                    return false;
+                }
+            }
+        }
+
+        // The latest start dates are obtained by filtering the earliest start dates of the mirrored tasks.
+        let ests = match filter_est(&tasks, &c_max, self.edge_finding) {
+            Some(ests) => ests,
+            None => return false,
+        };
+        let mirrored: Vec<Task<Bound>> = tasks.iter().map(|t| t.mirror()).collect();
+        let mirrored_ests = match filter_est(&mirrored, &c_max, self.edge_finding) {
+            Some(ests) => ests,
+            None => return false,
+        };
+        for (i, (est, mirrored_est)) in ests.into_iter().zip(mirrored_ests).enumerate() {
+            let lst = -mirrored_est - tasks[i].duration.clone();
+            if est > lst {
+                return false;
+            }
+            let start = self.starts[i].read(vstore);
+            if !self.starts[i].update(vstore, start.intersection(&Domain::new(est, lst))) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Cumulative<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.starts
+            .iter()
+            .chain(self.durations.iter())
+            .chain(self.resources.iter())
+            .chain(Some(&self.capacity))
+            .flat_map(|v| v.dependencies(FDEvent::Bound))
+            .collect()
+    }
+}
+
+/// Task with a minimal duration and resource usage, the start date ranges over `[est, lst]`.
+/// A task with a variable duration or resource usage is relaxed into this task which is always "included" into it.
+#[derive(Clone, Debug)]
+struct Task<Bound> {
+    est: Bound,
+    lst: Bound,
+    duration: Bound,
+    resource: Bound,
+}
+
+impl<Bound: IntBound> Task<Bound> {
+    fn ect(&self) -> Bound {
+        self.est.clone() + self.duration.clone()
+    }
+
+    fn lct(&self) -> Bound {
+        self.lst.clone() + self.duration.clone()
+    }
+
+    fn energy(&self) -> Bound {
+        self.duration.clone() * self.resource.clone()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.duration <= Bound::zero() || self.resource <= Bound::zero()
+    }
+
+    /// Interval `[lst, ect)` during which the task is running whatever its start date.
+    fn running(&self) -> Option<(Bound, Bound)> {
+        if self.duration > Bound::zero() && self.lst < self.ect() {
+            Some((self.lst.clone(), self.ect()))
+        } else {
+            None
+        }
+    }
+
+    /// Same as `running` for the tasks using resources.
+    fn compulsory_part(&self) -> Option<(Bound, Bound)> {
+        if self.resource > Bound::zero() {
+            self.running()
+        } else {
+            None
+        }
+    }
+
+    /// Symmetric task over the reversed time line: `[s, s + d)` becomes `[-s - d, -s)`.
+    fn mirror(&self) -> Self {
+        Task {
+            est: -self.lct(),
+            lst: -self.ect(),
+            duration: self.duration.clone(),
+            resource: self.resource.clone(),
+        }
+    }
+}
+
+/// Segment `[start, end)` of the resource profile.
+struct Segment<Bound> {
+    start: Bound,
+    end: Bound,
+    height: Bound,
+}
+
+/// Resources used by the compulsory parts of `tasks`, the segments are sorted and of non-zero height.
+fn profile<Bound: IntBound>(tasks: &[Task<Bound>]) -> Vec<Segment<Bound>> {
+    let mut events = vec![];
+    for task in tasks {
+        if let Some((start, end)) = task.compulsory_part() {
+            events.push((start, task.resource.clone()));
+            events.push((end, -task.resource.clone()));
+        }
+    }
+    events.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut profile = vec![];
+    let mut height = Bound::zero();
+    let mut i = 0;
+    while i < events.len() {
+        let time = events[i].0.clone();
+        while i < events.len() && events[i].0 == time {
+            height = height + events[i].1.clone();
+            i += 1;
+        }
+        if i < events.len() && height > Bound::zero() {
+            profile.push(Segment {
+                start: time,
+                end: events[i].0.clone(),
+                height: height.clone(),
+            });
+        }
+    }
+    profile
+}
+
+fn max_height<Bound: IntBound>(profile: &[Segment<Bound>]) -> Bound {
+    profile
+        .iter()
+        .map(|s| s.height.clone())
+        .max()
+        .unwrap_or_else(Bound::zero)
+}
+
+/// New earliest start dates of `tasks`, or `None` if the tasks cannot be scheduled.
+fn filter_est<Bound: IntBound>(
+    tasks: &[Task<Bound>],
+    capacity: &Bound,
+    edge_finding: bool,
+) -> Option<Vec<Bound>> {
+    let mut ests = time_tabling(tasks, capacity);
+    if edge_finding {
+        for (est, ef_est) in ests.iter_mut().zip(self::edge_finding(tasks, capacity)?) {
+            if ef_est > *est {
+                *est = ef_est;
+            }
+        }
+    }
+    Some(ests)
+}
+
+/// A task cannot start if it overlaps a segment of the profile where the resources left are not sufficient.
+fn time_tabling<Bound: IntBound>(tasks: &[Task<Bound>], capacity: &Bound) -> Vec<Bound> {
+    let profile = profile(tasks);
+    tasks
+        .iter()
+        .map(|task| {
+            let mut est = task.est.clone();
+            if task.is_empty() {
+                return est;
+            }
+            let compulsory_part = task.compulsory_part();
+            for segment in &profile {
+                if segment.start >= est.clone() + task.duration.clone() {
+                    break;
+                }
+                if segment.end <= est {
+                    continue;
+                }
+                let own = match compulsory_part {
+                    Some((ref start, ref end))
+                        if *start <= segment.start && segment.end <= *end =>
+                    {
+                        task.resource.clone()
+                    }
+                    _ => Bound::zero(),
+                };
+                if segment.height.clone() - own + task.resource.clone() > *capacity {
+                    est = segment.end.clone();
+                }
+            }
+            est
+        })
+        .collect()
+}
+
+/// Edge-finding over the task intervals `Ω(a, b)`, the set of tasks `j` with `a <= est(j)` and `lct(j) <= b`.
+/// If the energy of `Ω ∪ {i}` does not fit in `[min(a, est(i)), b)`, then `i` ends after all the tasks of `Ω` and starts after `a' + ceil(rest(Ω', i) / r(i))` for every `Ω' = Ω(a', b') ⊆ Ω` where `rest(Ω', i) = e(Ω') - (capacity - r(i)) * (b' - a') > 0` (Mercier and Van Hentenryck, "Edge finding for cumulative scheduling", 2008).
+/// Returns `None` if a task interval is overloaded.
+fn edge_finding<Bound: IntBound>(tasks: &[Task<Bound>], capacity: &Bound) -> Option<Vec<Bound>> {
+    let mut ests: Vec<Bound> = tasks.iter().map(|t| t.est.clone()).collect();
+    ests.sort();
+    ests.dedup();
+    let mut lcts: Vec<Bound> = tasks.iter().map(|t| t.lct()).collect();
+    lcts.sort();
+    lcts.dedup();
+    let contains = |a: usize, b: usize, t: &Task<Bound>| ests[a] <= t.est && t.lct() <= lcts[b];
+    let mut energy = vec![vec![Bound::zero(); lcts.len()]; ests.len()];
+    for a in 0..ests.len() {
+        for b in 0..lcts.len() {
+            energy[a][b] = tasks
+                .iter()
+                .filter(|t| contains(a, b, t))
+                .fold(Bound::zero(), |e, t| e + t.energy());
+            let window = lcts[b].clone() - ests[a].clone();
+            if energy[a][b] > Bound::zero() && energy[a][b] > capacity.clone() * window {
+                return None;
+            }
+        }
+    }
+
+    let mut new_ests: Vec<Bound> = tasks.iter().map(|t| t.est.clone()).collect();
+    for (i, task) in tasks.iter().enumerate() {
+        if task.is_empty() {
+            continue;
+        }
+        // best[a][b]: maximal start date given by the task intervals included in `Ω(a, b)`.
+        let mut best: Vec<Vec<Option<Bound>>> = vec![vec![None; lcts.len()]; ests.len()];
+        for a in (0..ests.len()).rev() {
+            for b in 0..lcts.len() {
+                let window = lcts[b].clone() - ests[a].clone();
+                let rest =
+                    energy[a][b].clone() - (capacity.clone() - task.resource.clone()) * window;
+                let mut bound = if energy[a][b] > Bound::zero() && rest > Bound::zero() {
+                    Some(ests[a].clone() + rest.div_ceil(&task.resource))
+                } else {
+                    None
+                };
+                if a + 1 < ests.len() {
+                    bound = bound.max(best[a + 1][b].clone());
+                }
+                if b > 0 {
+                    bound = bound.max(best[a][b - 1].clone());
+                }
+                best[a][b] = bound;
+            }
+        }
+        for a in 0..ests.len() {
+            for b in 0..lcts.len() {
+                if contains(a, b, task) || energy[a][b] == Bound::zero() {
+                    continue;
+                }
+                let start = if task.est < ests[a] {
+                    task.est.clone()
+                } else {
+                    ests[a].clone()
+                };
+                let window = lcts[b].clone() - start;
+                if energy[a][b].clone() + task.energy() > capacity.clone() * window {
+                    if let Some(ref bound) = best[a][b] {
+                        if *bound > new_ests[i] {
+                            new_ests[i] = bound.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Some(new_ests)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use gcollections::ops::*;
     use interval::interval::*;
     use interval::ops::Range;
-    use kernel::*;
-    use model::*;
     use propagation::ops::Subsumption;
     use propagation::CStoreFD;
     use trilean::SKleene;
@@ -204,6 +634,16 @@ mod test {
             cstore: &mut CStoreFD<VStoreFD>,
             constant: bool,
         ) {
+            let mut cumulative = self.cumulative(model, vstore, constant);
+            cumulative.join(vstore, cstore);
+        }
+
+        fn cumulative(
+            self,
+            model: &mut Model,
+            vstore: &mut VStoreFD,
+            constant: bool,
+        ) -> Cumulative<VStoreFD> {
             model.open_group("s");
             let starts = self
                 .starts
@@ -228,8 +668,31 @@ mod test {
             let capacity = Box::new(vstore.alloc(self.capacity));
             model.register_var(capacity.index(), String::from("c"));
 
-            let mut cumulative = Cumulative::new(starts, durations, resources, capacity);
-            cumulative.join(vstore, cstore);
+            Cumulative::new(starts, durations, resources, capacity)
+        }
+
+        /// Same as `test` with the global propagator instead of the decomposition.
+        fn test_global(
+            self,
+            test_num: usize,
+            before: SKleene,
+            after: SKleene,
+            edge_finding: bool,
+        ) -> VStoreFD {
+            println!("Test number {}", test_num);
+            let mut vstore = VStoreFD::empty();
+            let mut cstore = CStoreFD::empty();
+            let mut model = Model::new();
+            let mut cumulative = self.cumulative(&mut model, &mut vstore, false);
+            if edge_finding {
+                cumulative = cumulative.with_edge_finding();
+            }
+            cstore.alloc(Box::new(cumulative));
+            cstore.display(&(model, vstore.clone()));
+            assert_eq!(cstore.is_subsumed(&vstore), before);
+            assert_eq!(cstore.consistency(&mut vstore), after);
+            assert_eq!(cstore.is_subsumed(&vstore), after);
+            vstore
         }
 
         // The boolean "constant" indicates if we transform the singleton domains into constant terms or not.
@@ -317,4 +780,89 @@ mod test {
         test.starts[2] = Interval::new(4, 5);
         test.test(3, Unknown, Unknown, constant);
     }
+
+    #[test]
+    fn cumulative_global_assignment_test() {
+        for &edge_finding in &[false, true] {
+            let test =
+                CumulativeTest::new_assignment(vec![0, 1, 4], vec![3, 4, 2], vec![1, 2, 2], 3);
+            test.test_global(1, False, False, edge_finding);
+            let test =
+                CumulativeTest::new_assignment(vec![0, 1, 5], vec![3, 4, 2], vec![1, 2, 2], 3);
+            test.test_global(2, True, True, edge_finding);
+            let test = CumulativeTest::new_assignment(vec![0, 0], vec![0, 0], vec![1, 1], 1);
+            test.test_global(3, True, True, edge_finding);
+        }
+    }
+
+    #[test]
+    fn cumulative_global_test() {
+        for &edge_finding in &[false, true] {
+            let mut test =
+                CumulativeTest::new_assignment(vec![0, 1, 4], vec![3, 4, 2], vec![1, 2, 2], 3);
+            test.starts[0] = Interval::new(0, 4);
+            test.test_global(1, False, False, edge_finding);
+
+            let mut test =
+                CumulativeTest::new_assignment(vec![0, 1, 4], vec![3, 4, 2], vec![1, 2, 2], 3);
+            test.starts[2] = Interval::new(4, 5);
+            let vstore = test.test_global(2, Unknown, True, edge_finding);
+            assert_eq!(vstore[2], Interval::singleton(5));
+        }
+    }
+
+    #[test]
+    fn time_tabling_test() {
+        // The compulsory part of the first task is [1, 5) and uses all the capacity.
+        let test = CumulativeTest::new(
+            vec![(0, 1).to_interval(), (0, 10).to_interval()],
+            vec![(5, 5).to_interval(), (2, 2).to_interval()],
+            vec![(2, 2).to_interval(), (1, 1).to_interval()],
+            (1, 2).to_interval(),
+        );
+        let vstore = test.test_global(1, Unknown, Unknown, false);
+        assert_eq!(vstore[1], (5, 10).to_interval());
+        assert_eq!(vstore[6], (2, 2).to_interval());
+
+        // The resource of the second task is bounded by the compulsory part of the first task.
+        let test = CumulativeTest::new(
+            vec![(0, 1).to_interval(), (2, 3).to_interval()],
+            vec![(5, 5).to_interval(), (2, 2).to_interval()],
+            vec![(1, 1).to_interval(), (0, 3).to_interval()],
+            (3, 3).to_interval(),
+        );
+        let vstore = test.test_global(2, Unknown, True, false);
+        assert_eq!(vstore[5], (0, 2).to_interval());
+    }
+
+    #[test]
+    fn edge_finding_test() {
+        // The two first tasks fill [0, 7) except one time unit, so the third task must start after them.
+        // There are no compulsory parts and time-tabling does not prune anything.
+        let test = || {
+            CumulativeTest::new(
+                vec![
+                    (0, 4).to_interval(),
+                    (0, 4).to_interval(),
+                    (0, 10).to_interval(),
+                ],
+                vec![
+                    (3, 3).to_interval(),
+                    (3, 3).to_interval(),
+                    (2, 2).to_interval(),
+                ],
+                vec![
+                    (1, 1).to_interval(),
+                    (1, 1).to_interval(),
+                    (1, 1).to_interval(),
+                ],
+                (1, 1).to_interval(),
+            )
+        };
+        let vstore = test().test_global(1, Unknown, Unknown, false);
+        assert_eq!(vstore[2], (0, 10).to_interval());
+        let vstore = test().test_global(2, Unknown, Unknown, true);
+        assert_eq!(vstore[2], (6, 10).to_interval());
+        assert_eq!(vstore[0], (0, 4).to_interval());
+    }
 }
@@ -0,0 +1,530 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// `diffn(xs, ys, widths, heights)`: the rectangles of origin `(xs[i], ys[i])` and of size `widths[i] * heights[i]` do not overlap.
+/// By default, a rectangle of zero width or height cannot lie inside another rectangle; it can be placed anywhere if the constraint is built with `non_strict`.
+///
+/// The origins are filtered with the sweep algorithm of Beldiceanu and Carlsson (2001): each other rectangle forbids a box of origins given by its compulsory part, and the bounds of each origin are moved to the first position where the boxes do not cover the whole range of the other coordinate.
+/// Variable sizes are not filtered, the forbidden regions are computed with their lower bounds.
+#[derive(Debug)]
+pub struct Diffn<VStore> {
+    xs: Vec<Var<VStore>>,
+    ys: Vec<Var<VStore>>,
+    widths: Vec<Var<VStore>>,
+    heights: Vec<Var<VStore>>,
+    strict: bool,
+}
+
+impl<VStore> Diffn<VStore> {
+    pub fn new(
+        xs: Vec<Var<VStore>>,
+        ys: Vec<Var<VStore>>,
+        widths: Vec<Var<VStore>>,
+        heights: Vec<Var<VStore>>,
+    ) -> Self {
+        let rects = xs.len();
+        assert_eq!(rects, ys.len());
+        assert_eq!(rects, widths.len());
+        assert_eq!(rects, heights.len());
+        Diffn {
+            xs,
+            ys,
+            widths,
+            heights,
+            strict: true,
+        }
+    }
+
+    /// The rectangles of zero width or height do not constrain the other rectangles.
+    pub fn non_strict(mut self) -> Self {
+        self.strict = false;
+        self
+    }
+}
+
+impl<VStore> Clone for Diffn<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        let bclone = |vars: &Vec<Var<VStore>>| vars.iter().map(|v| v.bclone()).collect();
+        Diffn {
+            xs: bclone(&self.xs),
+            ys: bclone(&self.ys),
+            widths: bclone(&self.widths),
+            heights: bclone(&self.heights),
+            strict: self.strict,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Diffn<VStore> {
+    fn display(&self, model: &Model) {
+        if self.strict {
+            print!("diffn(");
+        } else {
+            print!("diffn_nonstrict(");
+        }
+        for (k, vars) in [&self.xs, &self.ys, &self.widths, &self.heights]
+            .iter()
+            .enumerate()
+        {
+            if k > 0 {
+                print!(", ");
+            }
+            model.display_array(vars);
+        }
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Diffn<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+/// Rectangle with the bounds of its origin and of its size, indexed by the dimension (`0` for `x` and `1` for `y`).
+#[derive(Clone, Debug)]
+struct Rect<Bound> {
+    lb: [Bound; 2],
+    ub: [Bound; 2],
+    min_size: [Bound; 2],
+    max_size: [Bound; 2],
+}
+
+/// Box of forbidden origins, `[lo, hi]` in each dimension.
+type Region<Bound> = [(Bound, Bound); 2];
+
+impl<Bound: IntBound> Rect<Bound> {
+    fn may_be_empty(&self) -> bool {
+        self.min_size.iter().any(|s| *s == Bound::zero())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.max_size.iter().any(|s| *s == Bound::zero())
+    }
+
+    /// Rectangle over the reversed axis `d`, the origin `o` becomes `-o` as in `mirror_region`, thus the largest origin is the opposite of the smallest mirrored one.
+    /// Only the bounds of the origins are mirrored since the sweep reads the origins of the rectangle being filtered.
+    fn mirror(&self, d: usize) -> Self {
+        let mut mirrored = self.clone();
+        mirrored.lb[d] = -self.ub[d].clone();
+        mirrored.ub[d] = -self.lb[d].clone();
+        mirrored
+    }
+}
+
+fn mirror_region<Bound: IntBound>(region: &Region<Bound>, d: usize) -> Region<Bound> {
+    let mut mirrored = region.clone();
+    mirrored[d] = (-region[d].1.clone(), -region[d].0.clone());
+    mirrored
+}
+
+/// The origins of `i` overlapping the compulsory part of `j`: `j.ub - i.min_size < o < j.lb + j.min_size` in each dimension.
+fn forbidden_region<Bound: IntBound>(
+    i: &Rect<Bound>,
+    j: &Rect<Bound>,
+    strict: bool,
+) -> Option<Region<Bound>> {
+    if !strict && (i.may_be_empty() || j.may_be_empty()) {
+        return None;
+    }
+    let bounds = |d: usize| {
+        (
+            j.ub[d].clone() - i.min_size[d].clone() + Bound::one(),
+            j.lb[d].clone() + j.min_size[d].clone() - Bound::one(),
+        )
+    };
+    let region = [bounds(0), bounds(1)];
+    if region.iter().all(|(lo, hi)| lo <= hi) {
+        Some(region)
+    } else {
+        None
+    }
+}
+
+/// The smallest origin of `rect` in the dimension `d` that is not forbidden by `regions` for every origin in the other dimension.
+fn sweep_min<Bound: IntBound>(
+    rect: &Rect<Bound>,
+    regions: &[Region<Bound>],
+    d: usize,
+) -> Option<Bound> {
+    let e = 1 - d;
+    let mut o = rect.lb[d].clone();
+    while o <= rect.ub[d] {
+        let containing: Vec<&Region<Bound>> = regions
+            .iter()
+            .filter(|r| r[d].0 <= o && o <= r[d].1)
+            .collect();
+        let mut spans: Vec<(Bound, Bound)> = containing.iter().map(|r| r[e].clone()).collect();
+        spans.sort();
+        let mut free = rect.lb[e].clone();
+        for (lo, hi) in spans {
+            if lo > free {
+                break;
+            } else if hi >= free {
+                free = hi + Bound::one();
+            }
+        }
+        if free <= rect.ub[e] {
+            return Some(o);
+        }
+        // The regions containing `o` cover the other dimension until the first of them ends.
+        o = containing.iter().map(|r| r[d].1.clone()).min().unwrap() + Bound::one();
+    }
+    None
+}
+
+fn read_all<VStore>(vars: &[Var<VStore>], store: &VStore) -> Vec<VStore::Item>
+where
+    VStore: VStoreConcept,
+{
+    vars.iter().map(|v| v.read(store)).collect()
+}
+
+impl<VStore, Domain, Bound> Diffn<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn rects(&self, store: &VStore) -> Vec<Rect<Bound>> {
+        let xs = read_all(&self.xs, store);
+        let ys = read_all(&self.ys, store);
+        let widths = read_all(&self.widths, store);
+        let heights = read_all(&self.heights, store);
+        (0..xs.len())
+            .map(|i| Rect {
+                lb: [xs[i].lower(), ys[i].lower()],
+                ub: [xs[i].upper(), ys[i].upper()],
+                min_size: [widths[i].lower(), heights[i].lower()],
+                max_size: [widths[i].upper(), heights[i].upper()],
+            })
+            .collect()
+    }
+
+    fn origin(&mut self, i: usize, d: usize) -> &mut Var<VStore> {
+        if d == 0 {
+            &mut self.xs[i]
+        } else {
+            &mut self.ys[i]
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Diffn<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let rects = self.rects(store);
+        let mut subsumed = True;
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                let (a, b) = (&rects[i], &rects[j]);
+                // The compulsory parts overlap if the origin of `a` is surely in the forbidden region of `b`.
+                let surely_overlap = match forbidden_region(a, b, self.strict) {
+                    Some(region) => {
+                        (0..2).all(|d| region[d].0 <= a.lb[d] && a.ub[d] <= region[d].1)
+                    }
+                    None => false,
+                };
+                if surely_overlap {
+                    return False;
+                }
+                let separated = |d: usize, a: &Rect<Bound>, b: &Rect<Bound>| {
+                    a.ub[d].clone() + a.max_size[d].clone() <= b.lb[d]
+                };
+                let never_overlap = (!self.strict && (a.is_empty() || b.is_empty()))
+                    || (0..2).any(|d| separated(d, a, b) || separated(d, b, a));
+                if !never_overlap {
+                    subsumed = Unknown;
+                }
+            }
+        }
+        subsumed
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Diffn<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let mut rects = self.rects(store);
+        for i in 0..rects.len() {
+            let regions: Vec<Region<Bound>> = (0..rects.len())
+                .filter(|&j| j != i)
+                .filter_map(|j| forbidden_region(&rects[i], &rects[j], self.strict))
+                .collect();
+            if regions.is_empty() {
+                continue;
+            }
+            for d in 0..2 {
+                let mirrored: Vec<Region<Bound>> =
+                    regions.iter().map(|r| mirror_region(r, d)).collect();
+                let (lb, ub) = match (
+                    sweep_min(&rects[i], &regions, d),
+                    sweep_min(&rects[i].mirror(d), &mirrored, d),
+                ) {
+                    (Some(lb), Some(ub)) => (lb, -ub),
+                    _ => return false,
+                };
+                let origin = self.origin(i, d);
+                let dom = origin.read(store);
+                if !origin.update(
+                    store,
+                    dom.intersection(&Domain::new(lb.clone(), ub.clone())),
+                ) {
+                    return false;
+                }
+                rects[i].lb[d] = lb;
+                rects[i].ub[d] = ub;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Diffn<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .xs
+            .iter()
+            .chain(self.ys.iter())
+            .chain(self.widths.iter())
+            .chain(self.heights.iter())
+            .flat_map(|v| v.dependencies(FDEvent::Bound))
+            .collect();
+        // The same variable can be shared, for instance by the width and the height of a square.
+        deps.sort();
+        deps.dedup();
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+
+    fn rect(lb: [isize; 2], ub: [isize; 2], size: [isize; 2]) -> Rect<isize> {
+        Rect {
+            lb,
+            ub,
+            min_size: size,
+            max_size: size,
+        }
+    }
+
+    #[test]
+    fn sweep_test() {
+        // A 2x2 square fixed at (1, 1) forbids the origins [0, 2] x [0, 2] of another 2x2 square.
+        let square = rect([0, 0], [4, 4], [2, 2]);
+        let fixed = rect([1, 1], [1, 1], [2, 2]);
+        let region = forbidden_region(&square, &fixed, true).unwrap();
+        assert_eq!(region, [(0, 2), (0, 2)]);
+        // The square can be placed at x = 0 above the fixed square.
+        assert_eq!(sweep_min(&square, &[region], 0), Some(0));
+        // Restricted to y in [0, 2], it must be on the right.
+        let low = rect([0, 0], [4, 2], [2, 2]);
+        assert_eq!(sweep_min(&low, &[region], 0), Some(3));
+        assert_eq!(
+            sweep_min(&low.mirror(0), &[mirror_region(&region, 0)], 0),
+            Some(-4)
+        );
+        // Zero width rectangles only forbid the interior of the other rectangles in the strict mode.
+        let point = rect([0, 0], [4, 4], [0, 0]);
+        assert_eq!(
+            forbidden_region(&point, &fixed, true),
+            Some([(2, 2), (2, 2)])
+        );
+        assert_eq!(forbidden_region(&point, &fixed, false), None);
+    }
+
+    #[test]
+    fn diffn_test() {
+        let d = |v: isize| (v, v).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+        let dom1_3 = (1, 3).to_interval();
+
+        // The second square must be on the right of the first one.
+        diffn_test_one(
+            1,
+            true,
+            vec![d(0), dom0_3, d(0), d(0), d(2), d(2), d(2), d(2)],
+            Unknown,
+            True,
+            vec![(1, Bound)],
+            true,
+        );
+        diffn_test_one(
+            2,
+            true,
+            vec![
+                d(0),
+                dom0_3,
+                d(0),
+                (0, 2).to_interval(),
+                d(2),
+                d(2),
+                d(2),
+                d(2),
+            ],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        diffn_test_one(
+            3,
+            true,
+            vec![
+                d(0),
+                (0, 1).to_interval(),
+                d(0),
+                d(0),
+                d(2),
+                d(2),
+                d(2),
+                d(2),
+            ],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        // The second square must be on the left of the first one.
+        diffn_test_one(
+            4,
+            true,
+            vec![d(2), dom0_3, d(0), d(0), d(2), d(2), d(2), d(2)],
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        // A segment of zero width in the middle of the first square.
+        diffn_test_one(
+            5,
+            true,
+            vec![d(0), dom1_3, d(0), d(1), d(2), d(0), d(2), d(1)],
+            Unknown,
+            True,
+            vec![(1, Bound)],
+            true,
+        );
+        diffn_test_one(
+            6,
+            false,
+            vec![d(0), dom1_3, d(0), d(1), d(2), d(0), d(2), d(1)],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn diffn_search_test() {
+        // Four unit squares fill a 2x2 square in 4! ways.
+        let mut space = FDSpace::empty();
+        let mut alloc =
+            |lb, ub| Box::new(space.vstore.alloc((lb, ub).to_interval_set())) as Var<VStore>;
+        let xs: Vec<_> = (0..4).map(|_| alloc(0, 1)).collect();
+        let ys: Vec<_> = (0..4).map(|_| alloc(0, 1)).collect();
+        let sizes: Vec<_> = (0..4).map(|_| alloc(1, 1)).collect();
+        let heights = sizes.iter().map(|v| v.bclone()).collect();
+        space
+            .cstore
+            .alloc(Box::new(Diffn::new(xs, ys, sizes, heights)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 24);
+    }
+
+    fn diffn_test_one(
+        test_num: u32,
+        strict: bool,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        // The domains are the ones of `xs`, `ys`, `widths` and `heights` in this order.
+        let make_diffn = |mut xs: Vec<FDVar>| {
+            let n = xs.len() / 4;
+            let mut ys = xs.split_off(n);
+            let mut widths = ys.split_off(n);
+            let heights = widths.split_off(n);
+            let diffn = Diffn::new(xs, ys, widths, heights);
+            if strict {
+                diffn
+            } else {
+                diffn.non_strict()
+            }
+        };
+        nary_propagator_test(
+            test_num,
+            make_diffn,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
@@ -0,0 +1,668 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unary resource constraint filtered with the Θ-Λ tree of Vilím, "Global constraints in scheduling" (PhD thesis, 2007).
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use trilean::SKleene;
+
+/// `disjunctive(starts, durations)`: the tasks `[starts[i], starts[i] + durations[i])` do not overlap.
+/// It is the constraint `cumulative` where the resources and the capacity are equal to 1.
+///
+/// The earliest and latest start dates are filtered with overload checking, detectable precedences, not-first/not-last and edge-finding, each running in `O(n log n)`.
+/// Variable durations are filtered with their lower bounds.
+#[derive(Debug)]
+pub struct Disjunctive<VStore> {
+    starts: Vec<Var<VStore>>,
+    durations: Vec<Var<VStore>>,
+}
+
+impl<VStore> Disjunctive<VStore> {
+    pub fn new(starts: Vec<Var<VStore>>, durations: Vec<Var<VStore>>) -> Self {
+        assert_eq!(starts.len(), durations.len());
+        Disjunctive { starts, durations }
+    }
+}
+
+impl<VStore> Clone for Disjunctive<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Disjunctive::new(
+            self.starts.iter().map(|v| v.bclone()).collect(),
+            self.durations.iter().map(|v| v.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Disjunctive<VStore> {
+    fn display(&self, model: &Model) {
+        print!("disjunctive(");
+        for (k, vars) in [&self.starts, &self.durations].iter().enumerate() {
+            if k > 0 {
+                print!(", ");
+            }
+            print!("[");
+            for (i, v) in vars.iter().enumerate() {
+                if i > 0 {
+                    print!(", ");
+                }
+                v.display(model);
+            }
+            print!("]");
+        }
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Disjunctive<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Disjunctive<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// The tasks with a non-zero duration together with their positions in `starts`.
+    /// Tasks of zero duration do not overlap any other task.
+    fn tasks(&self, vstore: &VStore) -> (Vec<usize>, Vec<Task<Bound>>) {
+        let mut indices = vec![];
+        let mut tasks = vec![];
+        for i in 0..self.starts.len() {
+            let duration = self.durations[i].read(vstore).lower();
+            if duration > Bound::zero() {
+                let start = self.starts[i].read(vstore);
+                indices.push(i);
+                tasks.push(Task {
+                    est: start.lower(),
+                    lst: start.upper(),
+                    duration,
+                });
+            }
+        }
+        (indices, tasks)
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Disjunctive<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        use trilean::SKleene::*;
+        let starts: Vec<Domain> = self.starts.iter().map(|v| v.read(vstore)).collect();
+        let durations: Vec<Domain> = self.durations.iter().map(|v| v.read(vstore)).collect();
+        let mut subsumed = True;
+        for i in 0..starts.len() {
+            for j in i + 1..starts.len() {
+                // Compulsory parts `[lst, ect)` of the tasks must not overlap.
+                let min_end = |k: usize| starts[k].lower() + durations[k].lower();
+                let surely_overlap = durations[i].lower() > Bound::zero()
+                    && durations[j].lower() > Bound::zero()
+                    && starts[i].upper() < min_end(j)
+                    && starts[j].upper() < min_end(i);
+                if surely_overlap {
+                    return False;
+                }
+                let max_end = |k: usize| starts[k].upper() + durations[k].upper();
+                let never_overlap = durations[i].upper() == Bound::zero()
+                    || durations[j].upper() == Bound::zero()
+                    || max_end(i) <= starts[j].lower()
+                    || max_end(j) <= starts[i].lower();
+                if !never_overlap {
+                    subsumed = Unknown;
+                }
+            }
+        }
+        subsumed
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Disjunctive<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        let (indices, tasks) = self.tasks(vstore);
+        let mirrored: Vec<Task<Bound>> = tasks.iter().map(|t| t.mirror()).collect();
+        // The rules filtering the latest completion times are the rules filtering the earliest start times of the mirrored tasks, and conversely.
+        let (ests, mirrored_ests) = match (edge_finding(&tasks), edge_finding(&mirrored)) {
+            (Some(ests), Some(mirrored_ests)) => (ests, mirrored_ests),
+            _ => return false,
+        };
+        let ests = [
+            ests,
+            detectable_precedences(&tasks),
+            not_last(&mirrored).into_iter().map(|lct| -lct).collect(),
+        ];
+        let lcts = [
+            mirrored_ests.into_iter().map(|est| -est).collect(),
+            detectable_precedences(&mirrored)
+                .into_iter()
+                .map(|est| -est)
+                .collect(),
+            not_last(&tasks),
+        ];
+        for (k, task) in tasks.iter().enumerate() {
+            let est = ests
+                .iter()
+                .map(|e: &Vec<Bound>| e[k].clone())
+                .max()
+                .unwrap();
+            let lct = lcts
+                .iter()
+                .map(|l: &Vec<Bound>| l[k].clone())
+                .min()
+                .unwrap();
+            let lst = lct - task.duration.clone();
+            if est > lst {
+                return false;
+            }
+            let start = &mut self.starts[indices[k]];
+            let dom = start.read(vstore);
+            if !start.update(vstore, dom.intersection(&Domain::new(est, lst))) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Disjunctive<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.starts
+            .iter()
+            .chain(self.durations.iter())
+            .flat_map(|v| v.dependencies(FDEvent::Bound))
+            .collect()
+    }
+}
+
+/// Task with a fixed duration starting in `[est, lst]`.
+#[derive(Clone, Debug)]
+struct Task<Bound> {
+    est: Bound,
+    lst: Bound,
+    duration: Bound,
+}
+
+impl<Bound: IntBound> Task<Bound> {
+    fn ect(&self) -> Bound {
+        self.est.clone() + self.duration.clone()
+    }
+
+    fn lct(&self) -> Bound {
+        self.lst.clone() + self.duration.clone()
+    }
+
+    /// Symmetric task over the reversed time line: `[s, s + d)` becomes `[-s - d, -s)`.
+    fn mirror(&self) -> Self {
+        Task {
+            est: -self.lct(),
+            lst: -self.ect(),
+            duration: self.duration.clone(),
+        }
+    }
+}
+
+fn sorted_by<Bound, F>(tasks: &[Task<Bound>], key: F) -> Vec<usize>
+where
+    Bound: IntBound,
+    F: Fn(&Task<Bound>) -> Bound,
+{
+    let mut order: Vec<usize> = (0..tasks.len()).collect();
+    order.sort_by_key(|&i| key(&tasks[i]));
+    order
+}
+
+/// Edge-finding: if the tasks of `Θ ∪ {i}` cannot be completed before `lct(Θ)`, then `i` starts after the completion of `Θ`.
+/// Returns the new earliest start times, or `None` if a set of tasks cannot be completed before its latest completion time (overload checking).
+fn edge_finding<Bound: IntBound>(tasks: &[Task<Bound>]) -> Option<Vec<Bound>> {
+    let mut ests: Vec<Bound> = tasks.iter().map(|t| t.est.clone()).collect();
+    if tasks.is_empty() {
+        return Some(ests);
+    }
+    let mut tree = ThetaLambdaTree::new(tasks);
+    for (i, task) in tasks.iter().enumerate() {
+        tree.insert(i, task);
+    }
+    let mut queue = sorted_by(tasks, |t| t.lct());
+    let mut j = queue.pop().unwrap();
+    if tree.ect() > Some(tasks[j].lct()) {
+        return None;
+    }
+    while let Some(next) = queue.pop() {
+        tree.gray(j, &tasks[j]);
+        j = next;
+        let lct = Some(tasks[j].lct());
+        if tree.ect() > lct {
+            return None;
+        }
+        while tree.gray_ect() > lct {
+            let i = match tree.gray_responsible() {
+                Some(i) => i,
+                None => break,
+            };
+            let ect = tree.ect().unwrap();
+            if ect > ests[i] {
+                ests[i] = ect;
+            }
+            tree.remove(i);
+        }
+    }
+    Some(ests)
+}
+
+/// Detectable precedences: if `ect(i) > lst(j)` then `j` precedes `i`, and `i` starts after the completion of all the tasks it detectably succeeds.
+fn detectable_precedences<Bound: IntBound>(tasks: &[Task<Bound>]) -> Vec<Bound> {
+    let mut ests: Vec<Bound> = tasks.iter().map(|t| t.est.clone()).collect();
+    let mut tree = ThetaLambdaTree::new(tasks);
+    let mut in_theta = vec![false; tasks.len()];
+    let by_lst = sorted_by(tasks, |t| t.lst.clone());
+    let mut q = 0;
+    for i in sorted_by(tasks, |t| t.ect()) {
+        while q < tasks.len() && tasks[i].ect() > tasks[by_lst[q]].lst {
+            tree.insert(by_lst[q], &tasks[by_lst[q]]);
+            in_theta[by_lst[q]] = true;
+            q += 1;
+        }
+        if let Some(ect) = tree.ect_without(i, &tasks[i], in_theta[i]) {
+            if ect > ests[i] {
+                ests[i] = ect;
+            }
+        }
+    }
+    ests
+}
+
+/// Not-last: if the tasks of `Θ`, the tasks that must start before `lct(i)`, cannot be completed before `lst(i)` then `i` is not the last of `Θ ∪ {i}` and completes before the latest start time of the tasks of `Θ`.
+/// Returns the new latest completion times.
+fn not_last<Bound: IntBound>(tasks: &[Task<Bound>]) -> Vec<Bound> {
+    let mut lcts: Vec<Bound> = tasks.iter().map(|t| t.lct()).collect();
+    let mut tree = ThetaLambdaTree::new(tasks);
+    let mut in_theta = vec![false; tasks.len()];
+    let by_lst = sorted_by(tasks, |t| t.lst.clone());
+    let mut q = 0;
+    let mut last = None;
+    for i in sorted_by(tasks, |t| t.lct()) {
+        while q < tasks.len() && tasks[i].lct() > tasks[by_lst[q]].lst {
+            tree.insert(by_lst[q], &tasks[by_lst[q]]);
+            in_theta[by_lst[q]] = true;
+            last = Some(by_lst[q]);
+            q += 1;
+        }
+        if let (Some(ect), Some(j)) = (tree.ect_without(i, &tasks[i], in_theta[i]), last) {
+            if ect > tasks[i].lst && tasks[j].lst < lcts[i] {
+                lcts[i] = tasks[j].lst.clone();
+            }
+        }
+    }
+    lcts
+}
+
+/// Node of the Θ-Λ tree summarizing the tasks of its leaves: `Θ` contains the white tasks and `Λ` the gray tasks.
+/// `None` completion times stand for `-∞`.
+#[derive(Clone)]
+struct Node<Bound> {
+    /// Sum of the durations of `Θ`.
+    sum: Bound,
+    /// Earliest completion time of `Θ`.
+    ect: Option<Bound>,
+    /// Sum of the durations of `Θ` with at most one task of `Λ`.
+    gray_sum: Bound,
+    /// Earliest completion time of `Θ` with at most one task of `Λ`.
+    gray_ect: Option<Bound>,
+    /// Gray task responsible for `gray_sum`.
+    gray_sum_resp: Option<usize>,
+    /// Gray task responsible for `gray_ect`.
+    gray_ect_resp: Option<usize>,
+}
+
+impl<Bound: IntBound> Node<Bound> {
+    fn empty() -> Self {
+        Node {
+            sum: Bound::zero(),
+            ect: None,
+            gray_sum: Bound::zero(),
+            gray_ect: None,
+            gray_sum_resp: None,
+            gray_ect_resp: None,
+        }
+    }
+
+    fn white(task: &Task<Bound>) -> Self {
+        Node {
+            sum: task.duration.clone(),
+            ect: Some(task.ect()),
+            gray_sum: task.duration.clone(),
+            gray_ect: Some(task.ect()),
+            gray_sum_resp: None,
+            gray_ect_resp: None,
+        }
+    }
+
+    fn gray(i: usize, task: &Task<Bound>) -> Self {
+        Node {
+            sum: Bound::zero(),
+            ect: None,
+            gray_sum: task.duration.clone(),
+            gray_ect: Some(task.ect()),
+            gray_sum_resp: Some(i),
+            gray_ect_resp: Some(i),
+        }
+    }
+
+    fn combine(left: &Self, right: &Self) -> Self {
+        let shift = |ect: &Option<Bound>, sum: &Bound| ect.clone().map(|e| e + sum.clone());
+        let ect = right.ect.clone().max(shift(&left.ect, &right.sum));
+        let (gray_sum, gray_sum_resp) = max_resp(vec![
+            (
+                left.gray_sum.clone() + right.sum.clone(),
+                left.gray_sum_resp,
+            ),
+            (
+                left.sum.clone() + right.gray_sum.clone(),
+                right.gray_sum_resp,
+            ),
+        ]);
+        let (gray_ect, gray_ect_resp) = max_resp(vec![
+            (right.gray_ect.clone(), right.gray_ect_resp),
+            (shift(&left.ect, &right.gray_sum), right.gray_sum_resp),
+            (shift(&left.gray_ect, &right.sum), left.gray_ect_resp),
+        ]);
+        Node {
+            sum: left.sum.clone() + right.sum.clone(),
+            ect,
+            gray_sum,
+            gray_ect,
+            gray_sum_resp,
+            gray_ect_resp,
+        }
+    }
+}
+
+/// Maximal value together with its responsible gray task, the values with a responsible are preferred in case of ties.
+fn max_resp<T: Ord>(candidates: Vec<(T, Option<usize>)>) -> (T, Option<usize>) {
+    candidates
+        .into_iter()
+        .max_by(|a, b| a.0.cmp(&b.0).then(a.1.is_some().cmp(&b.1.is_some())))
+        .unwrap()
+}
+
+/// Balanced binary tree where the leaves are the tasks ordered by earliest start times.
+struct ThetaLambdaTree<Bound> {
+    nodes: Vec<Node<Bound>>,
+    leaf_of: Vec<usize>,
+}
+
+impl<Bound: IntBound> ThetaLambdaTree<Bound> {
+    /// Empty tree for `tasks`.
+    fn new(tasks: &[Task<Bound>]) -> Self {
+        let mut leaves = 1;
+        while leaves < tasks.len() {
+            leaves *= 2;
+        }
+        let mut leaf_of = vec![0; tasks.len()];
+        for (pos, i) in sorted_by(tasks, |t| t.est.clone()).into_iter().enumerate() {
+            leaf_of[i] = leaves + pos;
+        }
+        ThetaLambdaTree {
+            nodes: vec![Node::empty(); 2 * leaves],
+            leaf_of,
+        }
+    }
+
+    fn insert(&mut self, i: usize, task: &Task<Bound>) {
+        self.set(i, Node::white(task));
+    }
+
+    fn gray(&mut self, i: usize, task: &Task<Bound>) {
+        self.set(i, Node::gray(i, task));
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.set(i, Node::empty());
+    }
+
+    fn set(&mut self, i: usize, node: Node<Bound>) {
+        let mut k = self.leaf_of[i];
+        self.nodes[k] = node;
+        while k > 1 {
+            k /= 2;
+            self.nodes[k] = Node::combine(&self.nodes[2 * k], &self.nodes[2 * k + 1]);
+        }
+    }
+
+    fn ect(&self) -> Option<Bound> {
+        self.nodes[1].ect.clone()
+    }
+
+    /// Earliest completion time of `Θ \ {i}` where `i` is in `Θ` if `in_theta` is true.
+    fn ect_without(&mut self, i: usize, task: &Task<Bound>, in_theta: bool) -> Option<Bound> {
+        if in_theta {
+            self.remove(i);
+            let ect = self.ect();
+            self.insert(i, task);
+            ect
+        } else {
+            self.ect()
+        }
+    }
+
+    fn gray_ect(&self) -> Option<Bound> {
+        self.nodes[1].gray_ect.clone()
+    }
+
+    fn gray_responsible(&self) -> Option<usize> {
+        self.nodes[1].gray_ect_resp
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::cmp::*;
+    use propagators::test::*;
+    use search::*;
+    use term::*;
+    use trilean::SKleene::*;
+    use variable::{VStoreFD, VStoreSet};
+
+    /// The variables are the starts followed by the durations.
+    fn disjunctive_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        let make_disjunctive = |mut starts: Vec<FDVar>| {
+            let n = starts.len() / 2;
+            let durations = starts.split_off(n);
+            Disjunctive::new(starts, durations)
+        };
+        nary_propagator_test(
+            test_num,
+            make_disjunctive,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+
+    #[test]
+    fn disjunctive_test() {
+        let d = |v: isize| (v, v).to_interval();
+        // Assigned tasks.
+        disjunctive_test_one(1, vec![d(0), d(3), d(3), d(2)], True, True, vec![], true);
+        disjunctive_test_one(2, vec![d(0), d(2), d(3), d(2)], False, False, vec![], false);
+        // Zero duration tasks do not overlap.
+        disjunctive_test_one(3, vec![d(1), d(0), d(0), d(3)], True, True, vec![], true);
+        // Detectable precedence: the second task must be after the first one.
+        disjunctive_test_one(
+            4,
+            vec![d(0), (1, 10).to_interval(), d(3), d(2)],
+            Unknown,
+            True,
+            vec![(1, Bound)],
+            true,
+        );
+        // Overload: three tasks of duration 2 in [0, 5).
+        disjunctive_test_one(
+            5,
+            vec![
+                (0, 3).to_interval(),
+                (0, 3).to_interval(),
+                (0, 3).to_interval(),
+                d(2),
+                d(2),
+                d(2),
+            ],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+        // Variable durations are filtered with their lower bounds.
+        disjunctive_test_one(
+            6,
+            vec![d(0), (0, 10).to_interval(), (2, 4).to_interval(), d(2)],
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+    }
+
+    fn task(est: isize, lst: isize, duration: isize) -> Task<isize> {
+        Task { est, lst, duration }
+    }
+
+    #[test]
+    fn edge_finding_test() {
+        // The two first tasks fill [0, 7) except one time unit, so the third task must start after them.
+        let tasks = vec![task(0, 4, 3), task(0, 4, 3), task(0, 10, 2)];
+        assert_eq!(edge_finding(&tasks), Some(vec![0, 0, 6]));
+        // No precedence is detectable yet.
+        assert_eq!(detectable_precedences(&tasks), vec![0, 0, 0]);
+        let overloaded = vec![task(0, 2, 3), task(0, 2, 3)];
+        assert_eq!(edge_finding(&overloaded), None);
+    }
+
+    #[test]
+    fn detectable_precedences_test() {
+        // ect(1) = 5 > lst(0) = 4 so the task 0 precedes the task 1.
+        let tasks = vec![task(0, 4, 3), task(2, 10, 3), task(0, 20, 1)];
+        assert_eq!(detectable_precedences(&tasks), vec![0, 3, 0]);
+    }
+
+    #[test]
+    fn not_last_test() {
+        // The task 2 cannot be last since the tasks 0 and 1 cannot both be completed before its latest start time 4, so it completes before one of them starts.
+        let tasks = vec![task(0, 5, 3), task(0, 5, 3), task(0, 4, 2)];
+        assert_eq!(not_last(&tasks), vec![8, 8, 5]);
+    }
+
+    #[test]
+    fn not_first_test() {
+        // Mirror of `not_last_test`: the task 2 cannot be first and starts after the completion of one of the tasks 0 and 1.
+        let mut store = VStoreFD::empty();
+        let starts: Vec<FDVar> = vec![(0, 5), (0, 5), (2, 6)]
+            .into_iter()
+            .map(|s| Box::new(store.alloc(s.to_interval())) as FDVar)
+            .collect();
+        let durations: Vec<FDVar> = vec![3, 3, 2]
+            .into_iter()
+            .map(|d| Box::new(store.alloc((d, d).to_interval())) as FDVar)
+            .collect();
+        test_propagation(
+            1,
+            Disjunctive::new(starts, durations),
+            &mut store,
+            Unknown,
+            Unknown,
+            vec![(2, Bound)],
+            true,
+        );
+        assert_eq!(store[2], (3, 6).to_interval());
+    }
+
+    #[test]
+    fn disjunctive_search() {
+        // Four tasks of variable durations sharing a unary resource on a tight horizon.
+        let mut space = FDSpace::empty();
+        let starts: Vec<Var<VStoreSet>> = (0..4)
+            .map(|_| Box::new(space.vstore.alloc((0, 8).to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let durations: Vec<Var<VStoreSet>> = vec![(2, 3), (3, 4), (1, 2), (2, 2)]
+            .into_iter()
+            .map(|d| Box::new(space.vstore.alloc(d.to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        for (s, d) in starts.iter().zip(durations.iter()) {
+            // s + d <= 10
+            space.cstore.alloc(Box::new(x_leq_y(
+                Box::new(Sum::new(vec![s.bclone(), d.bclone()])),
+                Box::new(Constant::new(10)),
+            )));
+        }
+        space.cstore.alloc(Box::new(Disjunctive::new(
+            starts.iter().map(|v| v.bclone()).collect(),
+            durations.iter().map(|v| v.bclone()).collect(),
+        )));
+        let mut search = one_solution_engine();
+        search.start(&space);
+        let (frozen_space, status) = search.enter(space);
+        assert_eq!(status, Status::Satisfiable);
+        let space = frozen_space.unfreeze();
+        let tasks: Vec<(isize, isize)> = starts
+            .iter()
+            .zip(durations.iter())
+            .map(|(s, d)| (s.read(&space.vstore).lower(), d.read(&space.vstore).lower()))
+            .collect();
+        for i in 0..tasks.len() {
+            for j in i + 1..tasks.len() {
+                assert!(
+                    tasks[i].0 + tasks[i].1 <= tasks[j].0 || tasks[j].0 + tasks[j].1 <= tasks[i].0
+                );
+            }
+        }
+    }
+}
@@ -20,6 +20,8 @@ use model::*;
 use propagation::events::*;
 use propagation::*;
 use propagators::cmp::x_neq_y::*;
+use propagators::graph::*;
+use propagators::values::*;
 use trilean::SKleene;
 
 /// Precondition: `vars.len() > 1`.
@@ -44,10 +46,23 @@ pub fn join_distinct<VStore, CStore, Domain, Bound>(
     }
 }
 
+/// Filtering achieved by `Distinct`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistinctStrength {
+    /// Conjunction of `x != y` for every pair of variables: a value is only removed once a variable is assigned to it.
+    Decomposition,
+    /// Bounds consistency with the Hall intervals algorithm of Lopez-Ortiz, Quimper, Tromp and van Beek (2003), together with the decomposition to remove the assigned values.
+    Bounds,
+    /// Domain consistency with the matching algorithm of Régin (1994).
+    /// The values of the domains are enumerated so it is meant for variables with domains of reasonable width.
+    Domain,
+}
+
 #[derive(Debug)]
 pub struct Distinct<VStore> {
     conj: Conjunction<VStore>,
     vars: Vec<Var<VStore>>,
+    strength: DistinctStrength,
 }
 
 impl<VStore> NotFormula<VStore> for Distinct<VStore>
@@ -67,6 +82,11 @@ where
 {
     /// Precondition: `vars.len() > 1`.
     pub fn new(vars: Vec<Var<VStore>>) -> Self {
+        Distinct::with_strength(vars, DistinctStrength::Decomposition)
+    }
+
+    /// Precondition: `vars.len() > 1`.
+    pub fn with_strength(vars: Vec<Var<VStore>>, strength: DistinctStrength) -> Self {
         assert!(
             vars.len() > 0,
             "Variable array in `Distinct` must be non-empty."
@@ -82,6 +102,7 @@ where
         Distinct {
             conj: Conjunction::new(props),
             vars: vars,
+            strength,
         }
     }
 }
@@ -94,13 +115,17 @@ where
         Distinct {
             conj: self.conj.clone(),
             vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            strength: self.strength,
         }
     }
 }
 
 impl<VStore> DisplayStateful<Model> for Distinct<VStore> {
     fn display(&self, model: &Model) {
-        model.display_global("distinct", &self.vars);
+        match self.strength {
+            DistinctStrength::Decomposition => model.display_global("distinct", &self.vars),
+            _ => model.display_call("distinct", &self.vars),
+        }
     }
 }
 
@@ -110,9 +135,93 @@ impl<VStore> Subsumption<VStore> for Distinct<VStore> {
     }
 }
 
-impl<VStore> Propagator<VStore> for Distinct<VStore> {
+impl<VStore, Domain, Bound> Propagator<VStore> for Distinct<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
     fn propagate(&mut self, vstore: &mut VStore) -> bool {
-        self.conj.propagate(vstore)
+        match self.strength {
+            DistinctStrength::Decomposition => self.conj.propagate(vstore),
+            DistinctStrength::Bounds => {
+                self.conj.propagate(vstore) && self.propagate_bounds(vstore)
+            }
+            DistinctStrength::Domain => self.propagate_domain(vstore),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Distinct<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate_bounds(&mut self, vstore: &mut VStore) -> bool {
+        let mut intervals: Vec<HallInterval<Bound>> = self
+            .vars
+            .iter()
+            .map(|v| {
+                let dom = v.read(vstore);
+                HallInterval::new(dom.lower(), dom.upper())
+            })
+            .collect();
+        if !filter_hall_intervals(&mut intervals) {
+            return false;
+        }
+        for (v, interval) in self.vars.iter_mut().zip(intervals) {
+            if interval.min > interval.max {
+                return false;
+            }
+            let dom = v.read(vstore);
+            if !v.update(
+                vstore,
+                dom.intersection(&Domain::new(interval.min, interval.max)),
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn propagate_domain(&mut self, vstore: &mut VStore) -> bool {
+        let doms: Vec<Vec<Bound>> = self
+            .vars
+            .iter()
+            .map(|v| domain_values(&v.read(vstore)))
+            .collect();
+        let mut values: Vec<Bound> = doms.concat();
+        values.sort();
+        values.dedup();
+        let edges: Vec<Vec<usize>> = doms
+            .iter()
+            .map(|dom| {
+                dom.iter()
+                    .map(|v| values.binary_search(v).unwrap())
+                    .collect()
+            })
+            .collect();
+        let matching = match maximum_matching(&edges, values.len()) {
+            Some(matching) => matching,
+            None => return false,
+        };
+        let supported = supported_edges(&edges, &matching, values.len());
+        for (i, v) in self.vars.iter_mut().enumerate() {
+            let support: Vec<Bound> = doms[i]
+                .iter()
+                .zip(supported[i].iter())
+                .filter(|&(_, s)| *s)
+                .map(|(value, _)| value.clone())
+                .collect();
+            if support.len() < doms[i].len() {
+                let dom = v.read(vstore);
+                if !v.update(vstore, restrict_to_values(dom, &support)) {
+                    return false;
+                }
+            }
+        }
+        true
     }
 }
 
@@ -125,13 +234,196 @@ impl<VStore> PropagatorDependencies<FDEvent> for Distinct<VStore> {
     }
 }
 
+/// Domain `[min..max]` of a variable and the ranks of `min` and `max + 1` in the sorted array of bounds.
+struct HallInterval<Bound> {
+    min: Bound,
+    max: Bound,
+    minrank: usize,
+    maxrank: usize,
+}
+
+impl<Bound> HallInterval<Bound> {
+    fn new(min: Bound, max: Bound) -> Self {
+        HallInterval {
+            min,
+            max,
+            minrank: 0,
+            maxrank: 0,
+        }
+    }
+}
+
+/// Shrink the intervals to their bounds consistent values with respect to `distinct`, returns `false` if a Hall interval contains more intervals than values.
+/// It is the algorithm of Lopez-Ortiz, Quimper, Tromp and van Beek, "A fast and simple algorithm for bounds consistency of the alldifferent constraint" (2003).
+fn filter_hall_intervals<Bound: IntBound>(intervals: &mut [HallInterval<Bound>]) -> bool {
+    if intervals.is_empty() {
+        return true;
+    }
+    let n = intervals.len();
+    let mut minsorted: Vec<usize> = (0..n).collect();
+    minsorted.sort_by(|&i, &j| intervals[i].min.cmp(&intervals[j].min));
+    let mut maxsorted: Vec<usize> = (0..n).collect();
+    maxsorted.sort_by(|&i, &j| intervals[i].max.cmp(&intervals[j].max));
+
+    // Merge the lower bounds and the upper bounds (+1) into `bounds` surrounded by two sentinels.
+    let two = Bound::one() + Bound::one();
+    let mut min = intervals[minsorted[0]].min.clone();
+    let mut max = intervals[maxsorted[0]].max.clone() + Bound::one();
+    let mut last = min.clone() - two.clone();
+    let mut bounds = vec![last.clone()];
+    let (mut i, mut j) = (0, 0);
+    loop {
+        if i < n && min <= max {
+            if min != last {
+                last = min.clone();
+                bounds.push(last.clone());
+            }
+            intervals[minsorted[i]].minrank = bounds.len() - 1;
+            i += 1;
+            if i < n {
+                min = intervals[minsorted[i]].min.clone();
+            }
+        } else {
+            if max != last {
+                last = max.clone();
+                bounds.push(last.clone());
+            }
+            intervals[maxsorted[j]].maxrank = bounds.len() - 1;
+            j += 1;
+            if j == n {
+                break;
+            }
+            max = intervals[maxsorted[j]].max.clone() + Bound::one();
+        }
+    }
+    let last = bounds[bounds.len() - 1].clone() + two;
+    bounds.push(last);
+
+    filter_lower(intervals, &maxsorted, &bounds) && filter_upper(intervals, &minsorted, &bounds)
+}
+
+fn filter_lower<Bound: IntBound>(
+    intervals: &mut [HallInterval<Bound>],
+    maxsorted: &[usize],
+    bounds: &[Bound],
+) -> bool {
+    let nb = bounds.len() - 2;
+    // `t`: critical capacity pointers, `d`: differences between critical capacities, `h`: Hall interval pointers.
+    let mut t = vec![0; nb + 2];
+    let mut h = vec![0; nb + 2];
+    let mut d = vec![Bound::zero(); nb + 2];
+    for i in 1..nb + 2 {
+        t[i] = i - 1;
+        h[i] = i - 1;
+        d[i] = bounds[i].clone() - bounds[i - 1].clone();
+    }
+    for &k in maxsorted {
+        let x = intervals[k].minrank;
+        let y = intervals[k].maxrank;
+        let mut z = path_max(&t, x + 1);
+        let j = t[z];
+        d[z] = d[z].clone() - Bound::one();
+        if d[z].is_zero() {
+            t[z] = z + 1;
+            z = path_max(&t, t[z]);
+            t[z] = j;
+        }
+        path_set(&mut t, x + 1, z, z);
+        let capacity = bounds[z].clone() - bounds[y].clone();
+        if d[z] < capacity {
+            return false;
+        }
+        if h[x] > x {
+            let w = path_max(&h, h[x]);
+            intervals[k].min = bounds[w].clone();
+            path_set(&mut h, x, w, w);
+        }
+        if d[z] == capacity {
+            let hy = h[y];
+            path_set(&mut h, hy, j - 1, y);
+            h[y] = j - 1;
+        }
+    }
+    true
+}
+
+fn filter_upper<Bound: IntBound>(
+    intervals: &mut [HallInterval<Bound>],
+    minsorted: &[usize],
+    bounds: &[Bound],
+) -> bool {
+    let nb = bounds.len() - 2;
+    let mut t = vec![0; nb + 2];
+    let mut h = vec![0; nb + 2];
+    let mut d = vec![Bound::zero(); nb + 2];
+    for i in 0..nb + 1 {
+        t[i] = i + 1;
+        h[i] = i + 1;
+        d[i] = bounds[i + 1].clone() - bounds[i].clone();
+    }
+    for &k in minsorted.iter().rev() {
+        let x = intervals[k].maxrank;
+        let y = intervals[k].minrank;
+        let mut z = path_min(&t, x - 1);
+        let j = t[z];
+        d[z] = d[z].clone() - Bound::one();
+        if d[z].is_zero() {
+            t[z] = z - 1;
+            z = path_min(&t, t[z]);
+            t[z] = j;
+        }
+        path_set(&mut t, x - 1, z, z);
+        let capacity = bounds[y].clone() - bounds[z].clone();
+        if d[z] < capacity {
+            return false;
+        }
+        if h[x] < x {
+            let w = path_min(&h, h[x]);
+            intervals[k].max = bounds[w].clone() - Bound::one();
+            path_set(&mut h, x, w, w);
+        }
+        if d[z] == capacity {
+            let hy = h[y];
+            path_set(&mut h, hy, j + 1, y);
+            h[y] = j + 1;
+        }
+    }
+    true
+}
+
+fn path_set(t: &mut [usize], start: usize, end: usize, to: usize) {
+    let mut l = start;
+    while l != end {
+        let k = l;
+        l = t[k];
+        t[k] = to;
+    }
+}
+
+fn path_min(t: &[usize], mut i: usize) -> usize {
+    while t[i] < i {
+        i = t[i];
+    }
+    i
+}
+
+fn path_max(t: &[usize], mut i: usize) -> usize {
+    while t[i] > i {
+        i = t[i];
+    }
+    i
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use gcollections::ops::*;
     use interval::interval::*;
+    use interval::interval_set::*;
     use propagation::events::FDEvent::*;
     use propagators::test::*;
     use trilean::SKleene::*;
+    use variable::VStoreSet;
 
     #[test]
     fn distinct_test() {
@@ -190,4 +482,102 @@ mod test {
             propagate_success,
         );
     }
+
+    #[test]
+    fn distinct_bounds_test() {
+        let zero = (0, 0).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+        let dom1_2 = (1, 2).to_interval();
+        let dom1_3 = (1, 3).to_interval();
+        let dom1_5 = (1, 5).to_interval();
+
+        for &strength in &[DistinctStrength::Bounds, DistinctStrength::Domain] {
+            let test = |test_num, doms, before, after, delta_expected, propagate_success| {
+                nary_propagator_test(
+                    test_num,
+                    |vars| Distinct::with_strength(vars, strength),
+                    doms,
+                    before,
+                    after,
+                    delta_expected,
+                    propagate_success,
+                )
+            };
+            test(
+                1,
+                vec![dom1_2, dom1_2, dom1_3],
+                Unknown,
+                Unknown,
+                vec![(2, Assignment)],
+                true,
+            );
+            test(
+                2,
+                vec![dom1_2, dom1_2, dom1_2],
+                Unknown,
+                Unknown,
+                vec![],
+                false,
+            );
+            test(
+                3,
+                vec![dom1_3, dom1_3, dom1_3, dom1_5],
+                Unknown,
+                Unknown,
+                vec![(3, Bound)],
+                true,
+            );
+            test(
+                4,
+                vec![zero, dom0_3, dom0_3],
+                Unknown,
+                Unknown,
+                vec![(1, Bound), (2, Bound)],
+                true,
+            );
+            test(
+                5,
+                vec![dom1_2, dom1_3, dom1_2, dom1_5, dom1_5],
+                Unknown,
+                Unknown,
+                vec![(1, Assignment), (3, Bound), (4, Bound)],
+                true,
+            );
+        }
+    }
+
+    #[test]
+    fn distinct_domain_test() {
+        let mut store = VStoreSet::empty();
+        let doms = vec![
+            vec![(1, 1), (3, 3)],
+            vec![(1, 1), (3, 3)],
+            vec![(1, 3)],
+            vec![(2, 4)],
+        ];
+        let vars: Vec<Var<VStoreSet>> = doms
+            .into_iter()
+            .map(|d| Box::new(store.alloc(d.to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let vars_clone = || vars.iter().map(|v| v.bclone()).collect();
+
+        // Bounds consistency does not see the holes.
+        let mut bounds = Distinct::with_strength(vars_clone(), DistinctStrength::Bounds);
+        assert!(bounds.propagate(&mut store));
+        assert_eq!(vars[2].read(&store), (1, 3).to_interval_set());
+
+        let mut domain = Distinct::with_strength(vars_clone(), DistinctStrength::Domain);
+        assert_eq!(domain.is_subsumed(&store), Unknown);
+        assert!(domain.propagate(&mut store));
+        assert_eq!(vars[2].read(&store), (2, 2).to_interval_set());
+        assert_eq!(vars[3].read(&store), (4, 4).to_interval_set());
+        assert_eq!(vars[0].read(&store), vec![(1, 1), (3, 3)].to_interval_set());
+
+        assert!(vars[0]
+            .bclone()
+            .update(&mut store, (3, 3).to_interval_set()));
+        assert!(domain.propagate(&mut store));
+        assert_eq!(vars[1].read(&store), (1, 1).to_interval_set());
+        assert_eq!(domain.is_subsumed(&store), True);
+    }
 }
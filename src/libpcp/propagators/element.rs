@@ -0,0 +1,229 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::cmp::x_eq_y::*;
+use term;
+use trilean::SKleene;
+
+/// `x = array[index]` where the positions of `array` start at 0.
+/// It is the constraint `x = y` where `y` is the view `term::Element`: `index` is domain consistent, and `x` is domain consistent if the possible elements are assigned (e.g. with `XEqElement::constant`) and bounds consistent otherwise.
+#[derive(Debug)]
+pub struct XEqElement<VStore> {
+    eq: XEqY<VStore>,
+}
+
+impl<VStore, Domain, Bound> XEqElement<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// Precondition: `array.len() > 0`.
+    pub fn new(x: Var<VStore>, index: Var<VStore>, array: Vec<Var<VStore>>) -> Self {
+        XEqElement {
+            eq: XEqY::new(x, Box::new(term::Element::new(index, array))),
+        }
+    }
+
+    /// `x = values[index]`.
+    /// Precondition: `values.len() > 0`.
+    pub fn constant(x: Var<VStore>, index: Var<VStore>, values: Vec<Bound>) -> Self {
+        XEqElement {
+            eq: XEqY::new(x, Box::new(term::Element::constant(index, values))),
+        }
+    }
+}
+
+impl<VStore> Clone for XEqElement<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        XEqElement {
+            eq: self.eq.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for XEqElement<VStore> {
+    fn display(&self, model: &Model) {
+        self.eq.display(model);
+    }
+}
+
+impl<VStore> NotFormula<VStore> for XEqElement<VStore>
+where
+    XEqY<VStore>: NotFormula<VStore>,
+{
+    fn not(&self) -> Formula<VStore> {
+        self.eq.not()
+    }
+}
+
+impl<VStore> Subsumption<VStore> for XEqElement<VStore>
+where
+    XEqY<VStore>: Subsumption<VStore>,
+{
+    fn is_subsumed(&self, vstore: &VStore) -> SKleene {
+        self.eq.is_subsumed(vstore)
+    }
+}
+
+impl<VStore> Propagator<VStore> for XEqElement<VStore>
+where
+    XEqY<VStore>: Propagator<VStore>,
+{
+    fn propagate(&mut self, vstore: &mut VStore) -> bool {
+        self.eq.propagate(vstore)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for XEqElement<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.eq.dependencies()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use trilean::SKleene::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn element_test() {
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+        let dom0_9 = (0, 9).to_interval();
+        let dom3_5 = (3, 5).to_interval();
+        let dom2_3 = (2, 3).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+        let four = (4, 4).to_interval();
+
+        // x, index, array...
+        element_test_one(
+            1,
+            vec![dom0_9, dom0_2, four, dom3_5, dom2_3],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        element_test_one(
+            2,
+            vec![one, dom0_2, four, dom3_5, dom2_3],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        element_test_one(
+            3,
+            vec![dom0_1, dom0_2, four, dom0_1, dom2_3],
+            Unknown,
+            Unknown,
+            vec![(1, Assignment)],
+            true,
+        );
+        element_test_one(
+            4,
+            vec![dom0_9, two, four, dom3_5, dom2_3],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        element_test_one(
+            5,
+            vec![two, dom0_2, four, four, dom2_3],
+            Unknown,
+            True,
+            vec![(1, Assignment), (4, Assignment)],
+            true,
+        );
+        element_test_one(
+            6,
+            vec![four, dom0_2, four, four, four],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    fn element_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        let make_element = |mut vars: Vec<FDVar>| {
+            let array = vars.split_off(2);
+            let index = vars.pop().unwrap();
+            let x = vars.pop().unwrap();
+            XEqElement::new(x, index, array)
+        };
+        nary_propagator_test(
+            test_num,
+            make_element,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+
+    #[test]
+    fn element_constant_test() {
+        let mut store = VStoreSet::empty();
+        let x = Box::new(store.alloc((0, 10).to_interval_set())) as Var<VStoreSet>;
+        let i = Box::new(store.alloc((0, 10).to_interval_set())) as Var<VStoreSet>;
+        let mut element = XEqElement::constant(x.bclone(), i.bclone(), vec![7, 2, 2, 9, 4]);
+        assert_eq!(element.is_subsumed(&store), Unknown);
+        assert!(element.propagate(&mut store));
+        assert_eq!(
+            x.read(&store),
+            vec![(2, 2), (4, 4), (7, 7), (9, 9)].to_interval_set()
+        );
+        assert_eq!(i.read(&store), (0, 4).to_interval_set());
+
+        assert!(x
+            .bclone()
+            .update(&mut store, vec![(2, 2), (4, 4)].to_interval_set()));
+        assert!(element.propagate(&mut store));
+        assert_eq!(i.read(&store), vec![(1, 2), (4, 4)].to_interval_set());
+
+        assert!(x.bclone().update(&mut store, (2, 2).to_interval_set()));
+        assert!(element.propagate(&mut store));
+        assert_eq!(i.read(&store), (1, 2).to_interval_set());
+        assert_eq!(element.is_subsumed(&store), True);
+        assert_eq!(element.not().is_subsumed(&store), False);
+    }
+}
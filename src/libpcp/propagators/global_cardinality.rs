@@ -0,0 +1,478 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::graph::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// For each `i`, the number of variables in `vars` equal to `values[i]` is `counts[i]`. The values not in `values` are not constrained.
+///
+/// The variables are domain consistent with the flow-based algorithm of Régin (1996): the bounds of `counts` are the capacities of the edges between the values and the sink, an edge between a variable and a value belongs to a feasible flow if it is in the current flow or if both ends are in the same strongly connected component of the residual graph.
+/// The cardinality variables are restricted to the number of variables assigned to and possibly equal to their value.
+pub struct GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    vars: Vec<Var<VStore>>,
+    values: Vec<<VStore::Item as Collection>::Item>,
+    counts: Vec<Var<VStore>>,
+}
+
+impl<VStore, Domain, Bound> GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Precondition: `values` are distinct and `values.len() == counts.len()`.
+    pub fn new(vars: Vec<Var<VStore>>, values: Vec<Bound>, counts: Vec<Var<VStore>>) -> Self {
+        assert_eq!(
+            values.len(),
+            counts.len(),
+            "`GlobalCardinality` must have one cardinality variable per value."
+        );
+        let mut cards: Vec<(Bound, Var<VStore>)> = values.into_iter().zip(counts).collect();
+        cards.sort_by(|a, b| a.0.cmp(&b.0));
+        assert!(
+            cards.windows(2).all(|w| w[0].0 != w[1].0),
+            "Values in `GlobalCardinality` must be distinct."
+        );
+        let (values, counts) = cards.into_iter().unzip();
+        GlobalCardinality {
+            vars,
+            values,
+            counts,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("GlobalCardinality")
+            .field("vars", &self.vars)
+            .field("values", &self.values)
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        GlobalCardinality {
+            vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            values: self.values.clone(),
+            counts: self.counts.iter().map(|v| v.bclone()).collect(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        model.display_call("global_cardinality", &self.vars);
+        print!(" of {:?} = ", self.values);
+        model.display_array(&self.counts);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+/// Flow in the bipartite graph between the variables and the values where the value `v` is used between `lower[v]` and `upper[v]` times.
+struct Flow<'a> {
+    edges: &'a [Vec<usize>],
+    var_to_val: Vec<Option<usize>>,
+    load: Vec<usize>,
+}
+
+impl<'a> Flow<'a> {
+    /// Returns `None` if there is no flow satisfying the capacities.
+    fn feasible(edges: &'a [Vec<usize>], lower: &[usize], upper: &[usize]) -> Option<Flow<'a>> {
+        let mut flow = Flow {
+            edges,
+            var_to_val: vec![None; edges.len()],
+            load: vec![0; lower.len()],
+        };
+        // The lower capacities are satisfied first, the next augmenting paths do not decrease the load of any value.
+        for x in 0..edges.len() {
+            let mut visited = vec![false; lower.len()];
+            flow.augment(x, lower, &mut visited);
+        }
+        if flow.load.iter().sum::<usize>() < lower.iter().sum() {
+            return None;
+        }
+        for x in 0..edges.len() {
+            if flow.var_to_val[x].is_none() {
+                let mut visited = vec![false; upper.len()];
+                if !flow.augment(x, upper, &mut visited) {
+                    return None;
+                }
+            }
+        }
+        Some(flow)
+    }
+
+    fn assign(&mut self, x: usize, v: usize) {
+        if let Some(old) = self.var_to_val[x] {
+            self.load[old] -= 1;
+        }
+        self.var_to_val[x] = Some(v);
+        self.load[v] += 1;
+    }
+
+    /// Moves `x` to another value, the value previously taken by `x` (if any) has one less unit.
+    fn augment(&mut self, x: usize, capacity: &[usize], visited: &mut [bool]) -> bool {
+        let edges = self.edges;
+        for &v in &edges[x] {
+            if visited[v] || self.var_to_val[x] == Some(v) {
+                continue;
+            }
+            visited[v] = true;
+            if self.load[v] < capacity[v] {
+                self.assign(x, v);
+                return true;
+            }
+            for y in 0..edges.len() {
+                if self.var_to_val[y] == Some(v) && self.augment(y, capacity, visited) {
+                    self.assign(x, v);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+impl<VStore, Domain, Bound> GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read_all(&self, store: &VStore) -> Vec<Domain> {
+        self.vars.iter().map(|v| v.read(store)).collect()
+    }
+
+    /// For each value, the number of variables assigned to it and the number of variables that can take it.
+    fn occurrences(&self, doms: &[Domain]) -> Vec<(usize, usize)> {
+        self.values
+            .iter()
+            .map(|v| {
+                let possible: Vec<&Domain> = doms.iter().filter(|d| d.contains(v)).collect();
+                let assigned = possible.iter().filter(|d| d.is_singleton()).count();
+                (assigned, possible.len())
+            })
+            .collect()
+    }
+
+    fn filter_vars(&mut self, store: &mut VStore) -> bool {
+        let n = self.vars.len();
+        let doms = self.read_all(store);
+        let mut universe: Vec<Bound> = doms.iter().flat_map(domain_values).collect();
+        universe.extend(self.values.iter().cloned());
+        universe.sort();
+        universe.dedup();
+        let index = |v: &Bound| universe.binary_search(v).unwrap();
+        let edges: Vec<Vec<usize>> = doms
+            .iter()
+            .map(|d| domain_values(d).iter().map(index).collect())
+            .collect();
+        let mut lower = vec![0; universe.len()];
+        let mut upper = vec![n; universe.len()];
+        let sizes: Vec<Bound> = (0..n + 1).map(bound_of_usize).collect();
+        for (v, count) in self.values.iter().zip(self.counts.iter()) {
+            let count = count.read(store);
+            let v = index(v);
+            lower[v] = sizes.iter().filter(|&s| *s < count.lower()).count();
+            upper[v] = match sizes.iter().rposition(|s| *s <= count.upper()) {
+                Some(u) => u,
+                None => return false,
+            };
+        }
+        let flow = match Flow::feasible(&edges, &lower, &upper) {
+            Some(flow) => flow,
+            None => return false,
+        };
+
+        // Residual graph: the variables are the nodes `0..n`, the values `n..n+k` and the sink is `n+k`.
+        let k = universe.len();
+        let sink = n + k;
+        let mut succ = vec![vec![]; n + k + 1];
+        for (x, values) in edges.iter().enumerate() {
+            for &v in values {
+                if flow.var_to_val[x] == Some(v) {
+                    succ[n + v].push(x);
+                } else {
+                    succ[x].push(n + v);
+                }
+            }
+        }
+        for v in 0..k {
+            if flow.load[v] < upper[v] {
+                succ[n + v].push(sink);
+            }
+            if flow.load[v] > lower[v] {
+                succ[sink].push(n + v);
+            }
+        }
+        let component = strongly_connected_components(&succ);
+        for (x, values) in edges.iter().enumerate() {
+            let supported: Vec<Bound> = values
+                .iter()
+                .filter(|&&v| flow.var_to_val[x] == Some(v) || component[x] == component[n + v])
+                .map(|&v| universe[v].clone())
+                .collect();
+            // Read again in case the same variable occurs several times.
+            let dom = self.vars[x].read(store);
+            if !self.vars[x].update(store, restrict_to_values(dom, &supported)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn filter_counts(&mut self, store: &mut VStore) -> bool {
+        let doms = self.read_all(store);
+        let occurrences = self.occurrences(&doms);
+        for (count, (assigned, possible)) in self.counts.iter_mut().zip(occurrences) {
+            let range = Domain::new(bound_of_usize(assigned), bound_of_usize(possible));
+            let dom = count.read(store).intersection(&range);
+            if !count.update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let doms = self.read_all(store);
+        let all_assigned = doms.iter().all(|d| d.is_singleton());
+        let mut status = if all_assigned { True } else { Unknown };
+        for (count, (assigned, possible)) in self.counts.iter().zip(self.occurrences(&doms)) {
+            let count = count.read(store);
+            if bound_of_usize::<Bound>(assigned) > count.upper()
+                || bound_of_usize::<Bound>(possible) < count.lower()
+            {
+                return False;
+            } else if !count.is_singleton() {
+                status = Unknown;
+            }
+        }
+        status
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.filter_vars(store) && self.filter_counts(store)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for GlobalCardinality<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .vars
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect();
+        for count in &self.counts {
+            deps.append(&mut count.dependencies(FDEvent::Bound));
+        }
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn global_cardinality_test() {
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+        let dom1_2 = (1, 2).to_interval();
+
+        // The values 1 and 2 can only be taken once.
+        gcc_test_one(
+            1,
+            vec![dom1_2, dom1_2, dom1_2, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![],
+            false,
+        );
+        gcc_test_one(
+            2,
+            vec![dom1_2, dom1_2, (1, 3).to_interval(), dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(2, Assignment)],
+            true,
+        );
+        gcc_test_one(3, vec![one, two, one, two, one], True, True, vec![], true);
+        gcc_test_one(
+            4,
+            vec![one, one, two, dom0_3, dom0_3],
+            Unknown,
+            True,
+            vec![(3, Assignment), (4, Assignment)],
+            true,
+        );
+        gcc_test_one(
+            5,
+            vec![one, one, one, (0, 2).to_interval(), dom0_3],
+            False,
+            False,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn global_cardinality_lower_bound_test() {
+        let mut store = VStoreSet::empty();
+        let mut alloc = |dom: Vec<(isize, isize)>| {
+            Box::new(store.alloc(dom.to_interval_set())) as Var<VStoreSet>
+        };
+        // The value 3 must be taken once and only `x` can take it.
+        let x = alloc(vec![(1, 1), (3, 3)]);
+        let y = alloc(vec![(1, 2)]);
+        let z = alloc(vec![(1, 2)]);
+        let c1 = alloc(vec![(0, 1)]);
+        let c2 = alloc(vec![(0, 3)]);
+        let c3 = alloc(vec![(1, 1)]);
+        let mut gcc = GlobalCardinality::new(vec![x, y, z], vec![3, 1, 2], vec![c3, c1, c2]);
+        assert!(gcc.propagate(&mut store));
+        assert_eq!(store[0], (3, 3).to_interval_set());
+        assert_eq!(store[1], (1, 2).to_interval_set());
+        assert_eq!(store[4], (0, 2).to_interval_set());
+    }
+
+    #[test]
+    fn global_cardinality_search_test() {
+        // Each of the values 0, 1 and 2 is taken once or twice by 4 variables: 3 * 4!/2! solutions.
+        let mut space = FDSpace::empty();
+        let vars: Vec<Var<VStore>> = (0..4)
+            .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+            .collect();
+        let counts: Vec<Var<VStore>> = (0..3)
+            .map(|_| Box::new(space.vstore.alloc((1, 2).to_interval_set())) as Var<VStore>)
+            .collect();
+        space.cstore.alloc(Box::new(GlobalCardinality::new(
+            vars,
+            vec![0, 1, 2],
+            counts,
+        )));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 36);
+    }
+
+    fn gcc_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        // global_cardinality([x1, x2, x3], [1, 2], [c1, c2])
+        let make_gcc = |mut vars: Vec<FDVar>| {
+            let counts = vars.split_off(3);
+            GlobalCardinality::new(vars, vec![1, 2], counts)
+        };
+        nary_propagator_test(
+            test_num,
+            make_gcc,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Graph algorithms shared by the propagators reasoning on a graph of variables and values, or of successors.
+
+use std::cmp::min;
+
+/// Tarjan's algorithm (without recursion), returns the component of each node.
+pub fn strongly_connected_components(succ: &[Vec<usize>]) -> Vec<usize> {
+    let none = usize::MAX;
+    let num_nodes = succ.len();
+    let mut index = vec![none; num_nodes];
+    let mut lowlink = vec![0; num_nodes];
+    let mut on_stack = vec![false; num_nodes];
+    let mut component = vec![none; num_nodes];
+    let mut stack = vec![];
+    let mut calls: Vec<(usize, usize)> = vec![];
+    let mut next_index = 0;
+    let mut num_components = 0;
+    for root in 0..num_nodes {
+        if index[root] != none {
+            continue;
+        }
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        calls.push((root, 0));
+        while let Some((u, i)) = calls.pop() {
+            if i < succ[u].len() {
+                calls.push((u, i + 1));
+                let w = succ[u][i];
+                if index[w] == none {
+                    index[w] = next_index;
+                    lowlink[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    calls.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[u] = min(lowlink[u], index[w]);
+                }
+            } else {
+                if let Some(&(parent, _)) = calls.last() {
+                    lowlink[parent] = min(lowlink[parent], lowlink[u]);
+                }
+                if lowlink[u] == index[u] {
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component[w] = num_components;
+                        if w == u {
+                            break;
+                        }
+                    }
+                    num_components += 1;
+                }
+            }
+        }
+    }
+    component
+}
+
+/// Maximum matching in the bipartite graph where `edges[x]` are the values adjacent to the variable `x`.
+/// Returns the value matched to each variable, or `None` if some variable cannot be matched.
+pub fn maximum_matching(edges: &[Vec<usize>], num_values: usize) -> Option<Vec<usize>> {
+    let mut val_to_var = vec![None; num_values];
+    let mut unmatched = vec![];
+    // Greedy initial matching, completed with augmenting paths.
+    for (x, values) in edges.iter().enumerate() {
+        match values.iter().find(|&&v| val_to_var[v].is_none()) {
+            Some(&v) => val_to_var[v] = Some(x),
+            None => unmatched.push(x),
+        }
+    }
+    for x in unmatched {
+        let mut visited = vec![false; num_values];
+        if !augment(x, edges, &mut val_to_var, &mut visited) {
+            return None;
+        }
+    }
+    let mut var_to_val = vec![0; edges.len()];
+    for (v, x) in val_to_var.into_iter().enumerate() {
+        if let Some(x) = x {
+            var_to_val[x] = v;
+        }
+    }
+    Some(var_to_val)
+}
+
+fn augment(
+    x: usize,
+    edges: &[Vec<usize>],
+    val_to_var: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &v in &edges[x] {
+        if !visited[v] {
+            visited[v] = true;
+            let free = match val_to_var[v] {
+                None => true,
+                Some(y) => augment(y, edges, val_to_var, visited),
+            };
+            if free {
+                val_to_var[v] = Some(x);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// For each edge `(x, edges[x][i])`, whether it belongs to a maximum matching.
+/// Matched edges are oriented from the variable to the value and the others from the value to the variable: an edge belongs to a maximum matching if it is matched, if its value is reachable from a free value, or if both ends are in the same strongly connected component.
+pub fn supported_edges(edges: &[Vec<usize>], matching: &[usize], num_values: usize) -> Vec<Vec<bool>> {
+    let n = edges.len();
+    let mut succ = vec![vec![]; n + num_values];
+    let mut free = vec![true; num_values];
+    for (x, values) in edges.iter().enumerate() {
+        free[matching[x]] = false;
+        for &v in values {
+            if v == matching[x] {
+                succ[x].push(n + v);
+            } else {
+                succ[n + v].push(x);
+            }
+        }
+    }
+    let mut reached = vec![false; n + num_values];
+    let mut stack: Vec<usize> = (0..num_values)
+        .filter(|&v| free[v])
+        .map(|v| n + v)
+        .collect();
+    for &u in &stack {
+        reached[u] = true;
+    }
+    while let Some(u) = stack.pop() {
+        for &w in &succ[u] {
+            if !reached[w] {
+                reached[w] = true;
+                stack.push(w);
+            }
+        }
+    }
+    let component = strongly_connected_components(&succ);
+    edges
+        .iter()
+        .enumerate()
+        .map(|(x, values)| {
+            values
+                .iter()
+                .map(|&v| v == matching[x] || reached[n + v] || component[x] == component[n + v])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strongly_connected_components_test() {
+        // 0 <-> 1 -> 2 -> 3 -> 2, 4 alone.
+        let succ = vec![vec![1], vec![0, 2], vec![3], vec![2], vec![]];
+        let component = strongly_connected_components(&succ);
+        assert_eq!(component[0], component[1]);
+        assert_eq!(component[2], component[3]);
+        assert!(component[0] != component[2]);
+        assert!(component[4] != component[0] && component[4] != component[2]);
+        // Tarjan's algorithm numbers the components in reverse topological order.
+        assert!(component[2] < component[0]);
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::cmp::{x_geq_y, x_greater_y};
+use std::cmp::max;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// `xs[0] <= xs[1] <= ... <= xs[n-1]`, or `<` between each pair for the strict version.
+/// It is equivalent to a chain of `XLessY` but the filtering is a single forward pass on the lower bounds and a backward pass on the upper bounds, in `O(n)`, which is bounds consistent.
+#[derive(Debug)]
+pub struct Increasing<VStore> {
+    xs: Vec<Var<VStore>>,
+    strict: bool,
+}
+
+impl<VStore> Increasing<VStore> {
+    pub fn new(xs: Vec<Var<VStore>>) -> Self {
+        Increasing { xs, strict: false }
+    }
+}
+
+pub fn strictly_increasing<VStore>(xs: Vec<Var<VStore>>) -> Increasing<VStore> {
+    Increasing { xs, strict: true }
+}
+
+/// `xs[0] >= xs[1] >= ... >= xs[n-1]` is `Increasing` on the reversed `xs`.
+pub fn decreasing<VStore>(xs: Vec<Var<VStore>>) -> Increasing<VStore> {
+    Increasing::new(xs.into_iter().rev().collect())
+}
+
+pub fn strictly_decreasing<VStore>(xs: Vec<Var<VStore>>) -> Increasing<VStore> {
+    strictly_increasing(xs.into_iter().rev().collect())
+}
+
+impl<VStore> Clone for Increasing<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Increasing {
+            xs: self.xs.iter().map(|x| x.bclone()).collect(),
+            strict: self.strict,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Increasing<VStore> {
+    fn display(&self, model: &Model) {
+        if self.strict {
+            print!("strictly_increasing(");
+        } else {
+            print!("increasing(");
+        }
+        model.display_array(&self.xs);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Increasing<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// One of the consecutive pairs is decreasing: `xs[i] > xs[i+1]`, or `xs[i] >= xs[i+1]` for the strict version.
+    /// With less than two variables, the constraint is always satisfied and its negation always fails.
+    fn not(&self) -> Formula<VStore> {
+        let mut fs: Vec<Formula<VStore>> = self
+            .xs
+            .windows(2)
+            .map(|w| {
+                let (x, y) = (w[0].bclone(), w[1].bclone());
+                if self.strict {
+                    Box::new(x_geq_y(x, y)) as Formula<VStore>
+                } else {
+                    Box::new(x_greater_y(x, y))
+                }
+            })
+            .collect();
+        match fs.len() {
+            0 => Box::new(Negation::new(self.clone())),
+            1 => fs.pop().unwrap(),
+            _ => Box::new(Disjunction::new(fs)),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Increasing<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Difference required between two consecutive variables.
+    fn gap(&self) -> Bound {
+        if self.strict {
+            Bound::one()
+        } else {
+            Bound::zero()
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Increasing<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let doms: Vec<Domain> = self.xs.iter().map(|x| x.read(store)).collect();
+        if doms.is_empty() {
+            return True;
+        }
+        let gap = self.gap();
+        // The smallest value each variable can take if the constraint holds.
+        let mut lower = doms[0].lower();
+        for dom in &doms[1..] {
+            lower = max(lower + gap.clone(), dom.lower());
+            if lower > dom.upper() {
+                return False;
+            }
+        }
+        if doms
+            .windows(2)
+            .all(|w| w[0].upper() + gap.clone() <= w[1].lower())
+        {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Increasing<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let gap = self.gap();
+        let n = self.xs.len();
+        for i in 1..n {
+            let lower = self.xs[i - 1].read(store).lower() + gap.clone();
+            let dom = self.xs[i].read(store).shrink_left(lower);
+            if !self.xs[i].update(store, dom) {
+                return false;
+            }
+        }
+        for i in (1..n).rev() {
+            let upper = self.xs[i].read(store).upper() - gap.clone();
+            let dom = self.xs[i - 1].read(store).shrink_right(upper);
+            if !self.xs[i - 1].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Increasing<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .flat_map(|x| x.dependencies(FDEvent::Bound))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use variable::VStoreFD;
+
+    #[test]
+    fn increasing_test() {
+        let dom0_10 = (0, 10).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let five = (5, 5).to_interval();
+
+        nary_propagator_test(
+            1,
+            Increasing::new,
+            vec![dom0_10, five, dom0_10],
+            Unknown,
+            True,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            strictly_increasing,
+            vec![dom0_10, five, dom0_10],
+            Unknown,
+            True,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+        // The lower bound of `xs[0]` is pushed to `xs[3]` through the chain.
+        nary_propagator_test(
+            3,
+            strictly_increasing,
+            vec![(3, 4).to_interval(), dom0_10, dom0_10, (0, 7).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(1, Bound), (2, Bound), (3, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            strictly_increasing,
+            vec![dom0_1, dom0_1, dom0_1],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            5,
+            Increasing::new,
+            vec![dom0_1, five, five, (5, 10).to_interval()],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            6,
+            decreasing,
+            vec![dom0_10, five, (7, 9).to_interval()],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            7,
+            strictly_decreasing,
+            vec![dom0_10, (2, 8).to_interval(), dom0_10],
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn not_increasing_test() {
+        let mut vstore = VStoreFD::empty();
+        let mut alloc = |lb, ub| Box::new(vstore.alloc((lb, ub).to_interval())) as FDVar;
+        let xs = vec![alloc(0, 10), alloc(5, 5), alloc(5, 9)];
+        let ys = vec![alloc(0, 10), alloc(5, 5), alloc(6, 9)];
+        let single = vec![alloc(0, 10)];
+
+        // `5 > xs[2]` is disentailed, so `xs[0] > 5`.
+        let mut not_increasing = Increasing::new(xs).not();
+        assert_eq!(not_increasing.is_subsumed(&vstore), Unknown);
+        assert!(not_increasing.propagate(&mut vstore));
+        assert_eq!(vstore[0], (6, 10).to_interval());
+
+        // `5 >= ys[2]` is disentailed, so `ys[0] >= 5`.
+        let mut not_strictly_increasing = strictly_increasing(ys).not();
+        assert!(not_strictly_increasing.propagate(&mut vstore));
+        assert_eq!(vstore[3], (5, 10).to_interval());
+
+        let mut not_single = Increasing::new(single).not();
+        assert_eq!(not_single.is_subsumed(&vstore), False);
+        assert!(!not_single.propagate(&mut vstore));
+    }
+}
@@ -0,0 +1,639 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// `xs` is lexicographically smaller than `ys`.
+///
+/// The filtering is the two-pointer algorithm of Frisch, Hnich, Kiziltan, Miguel and Walsh (2002): `alpha` is the first position where `xs` and `ys` are not assigned to the same value, and only `xs[alpha] <= ys[alpha]` is enforced, strictly if the suffixes after `alpha` cannot satisfy the ordering anymore.
+/// It achieves domain consistency when the variables are distinct.
+#[derive(Debug)]
+pub struct LexLess<VStore> {
+    xs: Vec<Var<VStore>>,
+    ys: Vec<Var<VStore>>,
+    strict: bool,
+}
+
+impl<VStore> LexLess<VStore> {
+    /// Precondition: `xs.len() == ys.len()`.
+    pub fn new(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>) -> Self {
+        LexLess::make(xs, ys, true)
+    }
+
+    fn make(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>, strict: bool) -> Self {
+        assert_eq!(
+            xs.len(),
+            ys.len(),
+            "Vectors compared lexicographically must have the same length."
+        );
+        LexLess { xs, ys, strict }
+    }
+}
+
+/// `xs` is lexicographically smaller than or equal to `ys`, filtered as `LexLess`.
+#[derive(Debug)]
+pub struct LexLessEq<VStore> {
+    lex: LexLess<VStore>,
+}
+
+impl<VStore> LexLessEq<VStore> {
+    /// Precondition: `xs.len() == ys.len()`.
+    pub fn new(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>) -> Self {
+        LexLessEq {
+            lex: LexLess::make(xs, ys, false),
+        }
+    }
+}
+
+/// Precondition: `xs.len() == ys.len()`.
+pub fn lex_leq<VStore>(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>) -> LexLessEq<VStore> {
+    LexLessEq::new(xs, ys)
+}
+
+impl<VStore> Clone for LexLess<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        LexLess::make(
+            self.xs.iter().map(|v| v.bclone()).collect(),
+            self.ys.iter().map(|v| v.bclone()).collect(),
+            self.strict,
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for LexLess<VStore> {
+    fn display(&self, model: &Model) {
+        if self.strict {
+            print!("lex_less(");
+        } else {
+            print!("lex_less_eq(");
+        }
+        model.display_array(&self.xs);
+        print!(", ");
+        model.display_array(&self.ys);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for LexLess<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// `not (xs < ys)` is `ys <= xs`.
+    fn not(&self) -> Formula<VStore> {
+        let not_self = self.clone();
+        Box::new(LexLess::make(not_self.ys, not_self.xs, !self.strict))
+    }
+}
+
+fn read_all<VStore>(vars: &[Var<VStore>], store: &VStore) -> Vec<VStore::Item>
+where
+    VStore: VStoreConcept,
+{
+    vars.iter().map(|v| v.read(store)).collect()
+}
+
+fn assigned_equal<Domain, Bound>(x: &Domain, y: &Domain) -> bool
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    x.is_singleton() && y.is_singleton() && x.lower() == y.lower()
+}
+
+impl<VStore, Domain, Bound> LexLess<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// `feasible[i]` is true if the ordering can still hold between `xs[i..]` and `ys[i..]`.
+    fn feasible(&self, xs: &[Domain], ys: &[Domain]) -> Vec<bool> {
+        let n = xs.len();
+        let mut feasible = vec![!self.strict; n + 1];
+        for i in (0..n).rev() {
+            feasible[i] =
+                xs[i].lower() < ys[i].upper() || (xs[i].overlap(&ys[i]) && feasible[i + 1]);
+        }
+        feasible
+    }
+
+    fn entailed(&self, xs: &[Domain], ys: &[Domain]) -> bool {
+        for (x, y) in xs.iter().zip(ys) {
+            if x.upper() < y.lower() {
+                return true;
+            } else if !assigned_equal(x, y) {
+                return false;
+            }
+        }
+        !self.strict
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for LexLess<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let xs = read_all(&self.xs, store);
+        let ys = read_all(&self.ys, store);
+        if !self.feasible(&xs, &ys)[0] {
+            False
+        } else if self.entailed(&xs, &ys) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for LexLess<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        loop {
+            let xs = read_all(&self.xs, store);
+            let ys = read_all(&self.ys, store);
+            let alpha = match (0..xs.len()).find(|&i| !assigned_equal(&xs[i], &ys[i])) {
+                Some(alpha) => alpha,
+                None => return !self.strict,
+            };
+            let feasible = self.feasible(&xs, &ys);
+            if !feasible[alpha] {
+                return false;
+            }
+            // If the suffixes cannot satisfy the ordering, `xs[alpha] = ys[alpha]` is not a support.
+            let strict = !feasible[alpha + 1];
+            let x = self.xs[alpha].read(store);
+            let y_ub = ys[alpha].upper();
+            let x = if strict {
+                x.strict_shrink_right(y_ub)
+            } else {
+                x.shrink_right(y_ub)
+            };
+            if !self.xs[alpha].update(store, x) {
+                return false;
+            }
+            let x_lb = self.xs[alpha].read(store).lower();
+            let y = self.ys[alpha].read(store);
+            let y = if strict {
+                y.strict_shrink_left(x_lb)
+            } else {
+                y.shrink_left(x_lb)
+            };
+            if !self.ys[alpha].update(store, y) {
+                return false;
+            }
+            // `alpha` moves forward only if the pruning assigned both variables to the same value.
+            let x = self.xs[alpha].read(store);
+            let y = self.ys[alpha].read(store);
+            if !assigned_equal(&x, &y) {
+                return true;
+            }
+        }
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for LexLess<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .chain(self.ys.iter())
+            .flat_map(|v| v.dependencies(FDEvent::Bound))
+            .collect()
+    }
+}
+
+impl<VStore> Clone for LexLessEq<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        LexLessEq {
+            lex: self.lex.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for LexLessEq<VStore> {
+    fn display(&self, model: &Model) {
+        self.lex.display(model);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for LexLessEq<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// `not (xs <= ys)` is `ys < xs`.
+    fn not(&self) -> Formula<VStore> {
+        self.lex.not()
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for LexLessEq<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        self.lex.is_subsumed(store)
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for LexLessEq<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        self.lex.propagate(store)
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for LexLessEq<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.lex.dependencies()
+    }
+}
+
+/// Each row of `rows` is lexicographically smaller than or equal to the next one (strictly smaller with `LexChain::strict`).
+/// It is typically used to break the symmetry between interchangeable rows of a matrix of variables.
+///
+/// The ordering is enforced by a `LexLess` between every pair of consecutive rows, propagated forward then backward along the chain.
+#[derive(Debug)]
+pub struct LexChain<VStore> {
+    lexes: Vec<LexLess<VStore>>,
+}
+
+impl<VStore> LexChain<VStore>
+where
+    VStore: Collection,
+{
+    /// Precondition: the rows have the same length.
+    pub fn new(rows: Vec<Vec<Var<VStore>>>) -> Self {
+        LexChain::make(rows, false)
+    }
+
+    /// Precondition: the rows have the same length.
+    pub fn strict(rows: Vec<Vec<Var<VStore>>>) -> Self {
+        LexChain::make(rows, true)
+    }
+
+    fn make(rows: Vec<Vec<Var<VStore>>>, strict: bool) -> Self {
+        let mut lexes = vec![];
+        let mut rows = rows.into_iter();
+        if let Some(mut previous) = rows.next() {
+            for row in rows {
+                let next = row.iter().map(|v| v.bclone()).collect();
+                lexes.push(LexLess::make(previous, row, strict));
+                previous = next;
+            }
+        }
+        LexChain { lexes }
+    }
+}
+
+impl<VStore> Clone for LexChain<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        LexChain {
+            lexes: self.lexes.clone(),
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for LexChain<VStore> {
+    fn display(&self, model: &Model) {
+        print!("lex_chain([");
+        for (i, lex) in self.lexes.iter().enumerate() {
+            model.display_array(&lex.xs);
+            print!(", ");
+            if i + 1 == self.lexes.len() {
+                model.display_array(&lex.ys);
+            }
+        }
+        print!("])");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for LexChain<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// One of the consecutive pairs of rows is not ordered.
+    /// A chain with less than two rows is always satisfied, so its negation always fails.
+    fn not(&self) -> Formula<VStore> {
+        match self.lexes.len() {
+            0 => Box::new(Negation::new(self.clone())),
+            1 => self.lexes[0].not(),
+            _ => Box::new(Disjunction::new(
+                self.lexes.iter().map(|lex| lex.not()).collect(),
+            )),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for LexChain<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let mut status = True;
+        for lex in &self.lexes {
+            match lex.is_subsumed(store) {
+                False => return False,
+                Unknown => status = Unknown,
+                True => (),
+            }
+        }
+        status
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for LexChain<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        // The backward pass transmits the upper bounds of the last rows to the first ones.
+        self.lexes.iter_mut().all(|lex| lex.propagate(store))
+            && self.lexes.iter_mut().rev().all(|lex| lex.propagate(store))
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for LexChain<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .lexes
+            .iter()
+            .flat_map(|lex| lex.dependencies())
+            .collect();
+        deps.sort();
+        deps.dedup();
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use propagators::XNeqY;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreFD;
+
+    #[test]
+    fn lex_less_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let three = (3, 3).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+        let dom0_5 = (0, 5).to_interval();
+        let dom0_9 = (0, 9).to_interval();
+
+        lex_test_one(
+            1,
+            true,
+            vec![dom0_1, dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        lex_test_one(
+            2,
+            true,
+            vec![one, dom0_2, one, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(1, Bound), (3, Bound)],
+            true,
+        );
+        lex_test_one(
+            3,
+            true,
+            vec![one, one, one, one],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        lex_test_one(4, false, vec![one, one, one, one], True, True, vec![], true);
+        lex_test_one(
+            5,
+            true,
+            vec![(2, 3).to_interval(), dom0_5, dom0_2, dom0_5],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment), (1, Bound), (2, Assignment), (3, Bound)],
+            true,
+        );
+        lex_test_one(
+            6,
+            true,
+            vec![zero, (5, 9).to_interval(), (1, 3).to_interval(), dom0_1],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        lex_test_one(
+            7,
+            true,
+            vec![three, dom0_9, dom0_2, dom0_9],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        lex_test_one(
+            8,
+            false,
+            vec![one, dom0_9, one, (0, 4).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn lex_not_test() {
+        let mut vstore = VStoreFD::empty();
+        let mut alloc = |lb, ub| Box::new(vstore.alloc((lb, ub).to_interval())) as FDVar;
+        let xs = vec![alloc(1, 1), alloc(0, 5)];
+        let ys = vec![alloc(1, 1), alloc(2, 3)];
+        // not ([1, x] < [1, y]) is [1, y] <= [1, x].
+        let mut not_lex = LexLess::new(xs, ys).not();
+        assert_eq!(not_lex.is_subsumed(&vstore), Unknown);
+        assert!(not_lex.propagate(&mut vstore));
+        assert_eq!(vstore[1], (2, 5).to_interval());
+    }
+
+    #[test]
+    fn lex_less_eq_test() {
+        let one = (1, 1).to_interval();
+        let dom0_5 = (0, 5).to_interval();
+        let make_leq = |mut xs: Vec<FDVar>| {
+            let ys = xs.split_off(xs.len() / 2);
+            LexLessEq::new(xs, ys)
+        };
+        nary_propagator_test(1, make_leq, vec![one, one], True, True, vec![], true);
+        nary_propagator_test(
+            2,
+            make_leq,
+            vec![one, dom0_5, one, (0, 2).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+
+        let mut vstore = VStoreFD::empty();
+        let mut alloc = |lb, ub| Box::new(vstore.alloc((lb, ub).to_interval())) as FDVar;
+        let xs = vec![alloc(1, 1), alloc(0, 5)];
+        let ys = vec![alloc(1, 1), alloc(2, 3)];
+        // not ([1, x] <= [1, y]) is [1, y] < [1, x].
+        let mut not_leq = LexLessEq::new(xs, ys).not();
+        assert!(not_leq.propagate(&mut vstore));
+        assert_eq!(vstore[1], (3, 5).to_interval());
+    }
+
+    #[test]
+    fn lex_chain_not_test() {
+        let mut vstore = VStoreFD::empty();
+        let mut alloc = |lb, ub| vec![Box::new(vstore.alloc((lb, ub).to_interval())) as FDVar];
+        let rows = vec![alloc(0, 0), alloc(0, 2), alloc(1, 1)];
+        let single_row = vec![alloc(0, 2)];
+        // not ([0] <= [x] <= [1]) is [x] < [0] or [1] < [x].
+        let mut not_chain = LexChain::new(rows).not();
+        assert_eq!(not_chain.is_subsumed(&vstore), Unknown);
+        assert!(not_chain.propagate(&mut vstore));
+        assert_eq!(vstore[1], (2, 2).to_interval());
+
+        let mut not_empty = LexChain::new(single_row).not();
+        assert_eq!(not_empty.is_subsumed(&vstore), False);
+        assert!(!not_empty.propagate(&mut vstore));
+    }
+
+    fn count_rows(
+        num_rows: usize,
+        make_chain: fn(Vec<Vec<Var<VStore>>>) -> LexChain<VStore>,
+    ) -> usize {
+        // Each row is either [0, 1] or [1, 0].
+        let mut space = FDSpace::empty();
+        let rows: Vec<Vec<Var<VStore>>> = (0..num_rows)
+            .map(|_| {
+                (0..2)
+                    .map(|_| Box::new(space.vstore.alloc((0, 1).to_interval_set())) as Var<VStore>)
+                    .collect()
+            })
+            .collect();
+        for row in &rows {
+            space
+                .cstore
+                .alloc(Box::new(XNeqY::new(row[0].bclone(), row[1].bclone())));
+        }
+        space.cstore.alloc(Box::new(make_chain(rows)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        statistics.num_solution
+    }
+
+    #[test]
+    fn lex_chain_test() {
+        // Without symmetry breaking, the 3 rows can be taken in any order: 2^3 solutions.
+        assert_eq!(count_rows(3, |_| LexChain::new(vec![])), 8);
+        // The rows are sorted: 0, 1, 2 or 3 rows are [0, 1] and the others [1, 0].
+        assert_eq!(count_rows(3, LexChain::new), 4);
+        assert_eq!(count_rows(2, LexChain::strict), 1);
+        assert_eq!(count_rows(3, LexChain::strict), 0);
+    }
+
+    fn lex_test_one(
+        test_num: u32,
+        strict: bool,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        // The first half of `doms` is `xs` and the second half is `ys`.
+        let make_lex = |mut xs: Vec<FDVar>| {
+            let ys = xs.split_off(xs.len() / 2);
+            LexLess::make(xs, ys, strict)
+        };
+        nary_propagator_test(
+            test_num,
+            make_lex,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
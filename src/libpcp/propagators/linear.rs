@@ -0,0 +1,368 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use std::fmt::{Debug, Formatter, Result};
+use term::ops::*;
+use term::LinearExpr;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearRel {
+    Eq,
+    Leq,
+    Geq,
+    Neq,
+}
+
+/// `coefs[0] * vars[0] + ... + coefs[n-1] * vars[n-1] <rel> rhs`.
+/// The relations `=`, `<=` and `>=` are bounds consistent and run in `O(n)`, `!=` is only propagated when a single variable remains unassigned.
+pub struct Linear<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    expr: LinearExpr<VStore>,
+    rel: LinearRel,
+    rhs: <VStore::Item as Collection>::Item,
+}
+
+impl<VStore, Domain, Bound> Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+{
+    /// Precondition: `coefs.len() == vars.len()`.
+    pub fn new(coefs: Vec<Bound>, vars: Vec<Var<VStore>>, rel: LinearRel, rhs: Bound) -> Self {
+        Linear::from_expr(LinearExpr::new(coefs, vars), rel, rhs)
+    }
+
+    pub fn from_expr(expr: LinearExpr<VStore>, rel: LinearRel, rhs: Bound) -> Self {
+        Linear { expr, rel, rhs }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("Linear")
+            .field("expr", &self.expr)
+            .field("rel", &self.rel)
+            .field("rhs", &self.rhs)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Linear::from_expr(self.expr.clone(), self.rel, self.rhs.clone())
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        self.expr.display(model);
+        let rel = match self.rel {
+            LinearRel::Eq => "=",
+            LinearRel::Leq => "<=",
+            LinearRel::Geq => ">=",
+            LinearRel::Neq => "!=",
+        };
+        print!(" {} {:?}", rel, self.rhs);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        let (rel, rhs) = match self.rel {
+            LinearRel::Eq => (LinearRel::Neq, self.rhs.clone()),
+            LinearRel::Neq => (LinearRel::Eq, self.rhs.clone()),
+            LinearRel::Leq => (LinearRel::Geq, self.rhs.clone() + Bound::one()),
+            LinearRel::Geq => (LinearRel::Leq, self.rhs.clone() - Bound::one()),
+        };
+        Box::new(Linear::from_expr(self.expr.clone(), rel, rhs))
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let sum = self.expr.read(store);
+        let (lb, ub) = (sum.lower(), sum.upper());
+        let rhs = self.rhs.clone();
+        match self.rel {
+            LinearRel::Leq if ub <= rhs => True,
+            LinearRel::Leq if lb > rhs => False,
+            LinearRel::Geq if lb >= rhs => True,
+            LinearRel::Geq if ub < rhs => False,
+            LinearRel::Eq if lb > rhs || ub < rhs => False,
+            LinearRel::Eq if lb == ub => True,
+            LinearRel::Neq if lb > rhs || ub < rhs => True,
+            LinearRel::Neq if lb == ub => False,
+            _ => Unknown,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Linear<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let sum = self.expr.read(store);
+        let rhs = self.rhs.clone();
+        match self.rel {
+            LinearRel::Eq => self.expr.update(store, Domain::singleton(rhs)),
+            LinearRel::Leq => {
+                rhs >= sum.lower() && self.expr.update(store, Domain::new(sum.lower(), rhs))
+            }
+            LinearRel::Geq => {
+                rhs <= sum.upper() && self.expr.update(store, Domain::new(rhs, sum.upper()))
+            }
+            LinearRel::Neq => self.expr.exclude(store, rhs),
+        }
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Linear<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let event = match self.rel {
+            LinearRel::Neq => FDEvent::Assignment,
+            _ => FDEvent::Bound,
+        };
+        self.expr.dependencies(event)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+
+    #[test]
+    fn linear_test() {
+        let dom0_10 = (0, 10).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+        let dom1_2 = (1, 2).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+
+        // 2x + 3y = 12
+        linear_test_one(
+            1,
+            LinearRel::Eq,
+            vec![dom0_10, dom0_3],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        linear_test_one(
+            2,
+            LinearRel::Eq,
+            vec![dom0_10, two],
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        linear_test_one(
+            3,
+            LinearRel::Eq,
+            vec![dom0_10, one],
+            Unknown,
+            False,
+            vec![],
+            false,
+        );
+        // 2x + 3y <= 12
+        linear_test_one(
+            4,
+            LinearRel::Leq,
+            vec![dom0_10, dom0_3],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        linear_test_one(
+            5,
+            LinearRel::Leq,
+            vec![(3, 4).to_interval(), dom0_3],
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+        linear_test_one(
+            6,
+            LinearRel::Leq,
+            vec![dom1_2, dom1_2],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        // 2x + 3y >= 12
+        linear_test_one(
+            7,
+            LinearRel::Geq,
+            vec![dom0_10, dom0_3],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        linear_test_one(
+            8,
+            LinearRel::Geq,
+            vec![dom1_2, (0, 2).to_interval()],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        linear_test_one(
+            9,
+            LinearRel::Geq,
+            vec![dom1_2, (2, 3).to_interval()],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Assignment)],
+            true,
+        );
+        // 2x + 3y != 12
+        linear_test_one(
+            10,
+            LinearRel::Neq,
+            vec![dom0_10, dom0_3],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        linear_test_one(
+            11,
+            LinearRel::Neq,
+            vec![(1, 3).to_interval(), two],
+            Unknown,
+            True,
+            vec![(0, Bound)],
+            true,
+        );
+        linear_test_one(
+            12,
+            LinearRel::Neq,
+            vec![(3, 3).to_interval(), two],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        linear_test_one(
+            13,
+            LinearRel::Neq,
+            vec![dom1_2, one],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn linear_negative_coefs_test() {
+        // x - 2y <= -3 with x in [0, 10] and y in [0, 4]: x <= 5 and y >= 2.
+        let make_linear = |vars: Vec<FDVar>| Linear::new(vec![1, -2], vars, LinearRel::Leq, -3);
+        nary_propagator_test(
+            1,
+            make_linear,
+            vec![(0, 10).to_interval(), (0, 4).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        // -3x - y = -7 with x in [0, 3] and y in [0, 3]: x = 2 and y <= 1.
+        let make_linear = |vars: Vec<FDVar>| Linear::new(vec![-3, -1], vars, LinearRel::Eq, -7);
+        nary_propagator_test(
+            2,
+            make_linear,
+            vec![(0, 3).to_interval(), (0, 3).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment), (1, Bound)],
+            true,
+        );
+    }
+
+    fn linear_test_one(
+        test_num: u32,
+        rel: LinearRel,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        let make_linear = |vars: Vec<FDVar>| Linear::new(vec![2, 3], vars, rel, 12);
+        nary_propagator_test(
+            test_num,
+            make_linear,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
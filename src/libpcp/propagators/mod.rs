@@ -17,13 +17,51 @@
 //! We keep the propagator implementations generic over domains implementing specific operations (e.g. intersection or union). Propagators are also implemented to work on variable views, you can always obtain a view from a variable by using the `Identity` view.
 
 pub mod all_equal;
+pub mod automaton;
+pub mod bin_packing;
+pub mod channel;
+pub mod circuit;
 pub mod cmp;
+pub mod count;
 pub mod cumulative;
+pub mod diffn;
+pub mod disjunctive;
 pub mod distinct;
+pub mod element;
+pub mod global_cardinality;
+pub mod graph;
+pub mod increasing;
+pub mod lex;
+pub mod linear;
+pub mod nvalue;
+pub mod regular;
+pub mod sequence;
+pub mod sort;
+pub mod table;
+pub mod value_precede;
+pub mod values;
 
 pub use propagators::all_equal::*;
+pub use propagators::automaton::*;
+pub use propagators::bin_packing::*;
+pub use propagators::channel::*;
+pub use propagators::circuit::*;
 pub use propagators::cmp::*;
+pub use propagators::count::*;
+pub use propagators::diffn::*;
+pub use propagators::disjunctive::*;
 pub use propagators::distinct::*;
+pub use propagators::element::*;
+pub use propagators::global_cardinality::*;
+pub use propagators::increasing::*;
+pub use propagators::lex::*;
+pub use propagators::linear::*;
+pub use propagators::nvalue::*;
+pub use propagators::regular::*;
+pub use propagators::sequence::*;
+pub use propagators::sort::*;
+pub use propagators::table::*;
+pub use propagators::value_precede::*;
 
 #[cfg(test)]
 pub mod test {
@@ -0,0 +1,516 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constraints on the number of distinct values taken by an array of variables: `NValue`, and its one-sided versions `at_most_nvalue` and `at_least_nvalue`.
+//!
+//! The reasoning is on the bounds of the variables, seen as intervals, so the holes in the domains are ignored.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::collections::BTreeSet;
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// Minimal set of points hitting every interval, computed greedily from the left: each point is the smallest upper bound of the intervals not hit yet.
+/// It is a lower bound on the number of distinct values.
+fn hitting_points_left<Bound: IntBound>(intervals: &[(Bound, Bound)]) -> Vec<Bound> {
+    let mut sorted = intervals.to_vec();
+    sorted.sort_by(|a, b| a.1.cmp(&b.1));
+    let mut points: Vec<Bound> = vec![];
+    for (lower, upper) in sorted {
+        let hit = match points.last() {
+            Some(p) => lower <= *p,
+            None => false,
+        };
+        if !hit {
+            points.push(upper);
+        }
+    }
+    points
+}
+
+/// Same as `hitting_points_left` from the right: each point is the largest lower bound of the intervals not hit yet.
+/// The points are returned in increasing order.
+fn hitting_points_right<Bound: IntBound>(intervals: &[(Bound, Bound)]) -> Vec<Bound> {
+    let mut sorted = intervals.to_vec();
+    sorted.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut points: Vec<Bound> = vec![];
+    for (lower, upper) in sorted {
+        let hit = match points.last() {
+            Some(p) => *p <= upper,
+            None => false,
+        };
+        if !hit {
+            points.push(lower);
+        }
+    }
+    points.reverse();
+    points
+}
+
+/// Maximal number of distinct values, that is the size of a maximum matching between the intervals and the values.
+/// The intervals are sorted by upper bound and each one takes the smallest free value it contains (Glover's rule).
+fn max_distinct<Bound: IntBound>(intervals: &[(Bound, Bound)]) -> usize {
+    let mut sorted = intervals.to_vec();
+    sorted.sort_by(|a, b| a.1.cmp(&b.1));
+    let mut used = BTreeSet::new();
+    for (lower, upper) in sorted {
+        let mut v = lower;
+        while v <= upper && used.contains(&v) {
+            v = v + Bound::one();
+        }
+        if v <= upper {
+            used.insert(v);
+        }
+    }
+    used.len()
+}
+
+/// The number of distinct values taken by the variables of `xs` is `n`.
+///
+/// `at_most_nvalue` only bounds this number from above: `n` is bounded from below by the size of a minimal set of values hitting the bounds of every variable, computed greedily (Beldiceanu, 2001).
+/// When this size reaches the upper bound of `n`, the `i`-th value of any such set lies between the `i`-th points of the greedy sets computed from the right and from the left, and the variables are restricted to these kernels.
+/// `at_least_nvalue` only bounds this number from below: `n` is bounded from above by the size of a maximum matching between the variables and their values, and the variables are all different once `n` reaches their number.
+///
+/// Since the bounds of `n` are read at each propagation, `n` can be the objective of `BranchAndBound`.
+#[derive(Debug)]
+pub struct NValue<VStore> {
+    n: Var<VStore>,
+    xs: Vec<Var<VStore>>,
+    at_most: bool,
+    at_least: bool,
+}
+
+impl<VStore> NValue<VStore> {
+    pub fn new(n: Var<VStore>, xs: Vec<Var<VStore>>) -> Self {
+        NValue {
+            n,
+            xs,
+            at_most: true,
+            at_least: true,
+        }
+    }
+}
+
+/// The number of distinct values taken by the variables of `xs` is at most `n`.
+pub fn at_most_nvalue<VStore>(n: Var<VStore>, xs: Vec<Var<VStore>>) -> NValue<VStore> {
+    NValue {
+        n,
+        xs,
+        at_most: true,
+        at_least: false,
+    }
+}
+
+/// The number of distinct values taken by the variables of `xs` is at least `n`.
+pub fn at_least_nvalue<VStore>(n: Var<VStore>, xs: Vec<Var<VStore>>) -> NValue<VStore> {
+    NValue {
+        n,
+        xs,
+        at_most: false,
+        at_least: true,
+    }
+}
+
+impl<VStore> Clone for NValue<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        NValue {
+            n: self.n.bclone(),
+            xs: self.xs.iter().map(|x| x.bclone()).collect(),
+            at_most: self.at_most,
+            at_least: self.at_least,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for NValue<VStore> {
+    fn display(&self, model: &Model) {
+        match (self.at_most, self.at_least) {
+            (true, false) => print!("at_most_nvalue("),
+            (false, true) => print!("at_least_nvalue("),
+            _ => print!("nvalue("),
+        }
+        self.n.display(model);
+        print!(", ");
+        model.display_array(&self.xs);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for NValue<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> NValue<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn intervals(&self, store: &VStore) -> Vec<(Bound, Bound)> {
+        self.xs
+            .iter()
+            .map(|x| {
+                let dom = x.read(store);
+                (dom.lower(), dom.upper())
+            })
+            .collect()
+    }
+
+    /// Number of distinct values if all the variables are assigned.
+    fn assigned_values(&self, store: &VStore) -> Option<usize> {
+        let mut values = BTreeSet::new();
+        for x in &self.xs {
+            let dom = x.read(store);
+            if !dom.is_singleton() {
+                return None;
+            }
+            values.insert(dom.lower());
+        }
+        Some(values.len())
+    }
+
+    /// Restricts the variables to the union of the kernels `[right[i], left[i]]`.
+    fn restrict_to_kernels(&mut self, store: &mut VStore, left: &[Bound], right: &[Bound]) -> bool {
+        let k = left.len();
+        for x in &mut self.xs {
+            let mut dom = x
+                .read(store)
+                .intersection(&Domain::new(right[0].clone(), left[k - 1].clone()));
+            for i in 1..k {
+                if left[i - 1].clone() + Bound::one() < right[i] {
+                    let gap = Domain::new(
+                        left[i - 1].clone() + Bound::one(),
+                        right[i].clone() - Bound::one(),
+                    );
+                    dom = dom.difference(&gap);
+                }
+            }
+            if !x.update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Removes the value of each assigned variable from the other variables.
+    fn all_different(&mut self, store: &mut VStore) -> bool {
+        for i in 0..self.xs.len() {
+            let dom = self.xs[i].read(store);
+            if dom.is_singleton() {
+                for j in 0..self.xs.len() {
+                    if i != j {
+                        let other = self.xs[j].read(store).difference(&dom.lower());
+                        if !self.xs[j].update(store, other) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for NValue<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let n = self.n.read(store);
+        let intervals = self.intervals(store);
+        let lower: Bound = bound_of_usize(hitting_points_left(&intervals).len());
+        let upper: Bound = bound_of_usize(max_distinct(&intervals));
+        if (self.at_most && lower > n.upper()) || (self.at_least && upper < n.lower()) {
+            return False;
+        }
+        let at_most_entailed = !self.at_most || upper <= n.lower();
+        let at_least_entailed = !self.at_least || lower >= n.upper();
+        if at_most_entailed && at_least_entailed {
+            return True;
+        }
+        match self.assigned_values(store) {
+            Some(count) => {
+                let count: Bound = bound_of_usize(count);
+                if (!self.at_most || count <= n.lower()) && (!self.at_least || count >= n.upper()) {
+                    True
+                } else {
+                    Unknown
+                }
+            }
+            None => Unknown,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for NValue<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        if self.at_most {
+            let intervals = self.intervals(store);
+            let left = hitting_points_left(&intervals);
+            let n = self.n.read(store).shrink_left(bound_of_usize(left.len()));
+            if !self.n.update(store, n) {
+                return false;
+            }
+            if !left.is_empty() && self.n.read(store).upper() == bound_of_usize(left.len()) {
+                let right = hitting_points_right(&intervals);
+                if !self.restrict_to_kernels(store, &left, &right) {
+                    return false;
+                }
+            }
+        }
+        if self.at_least {
+            let intervals = self.intervals(store);
+            let n = self
+                .n
+                .read(store)
+                .shrink_right(bound_of_usize(max_distinct(&intervals)));
+            if !self.n.update(store, n) {
+                return false;
+            }
+            if self.n.read(store).lower() >= bound_of_usize(self.xs.len())
+                && !self.all_different(store)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for NValue<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps = self.n.dependencies(FDEvent::Bound);
+        for x in &self.xs {
+            deps.append(&mut x.dependencies(FDEvent::Bound));
+        }
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::cmp::*;
+    use propagators::test::*;
+    use search::branch_and_bound::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::propagation::*;
+    use search::*;
+
+    #[test]
+    fn hitting_points_test() {
+        let intervals = vec![(0, 2), (4, 6), (3, 9), (1, 5)];
+        assert_eq!(hitting_points_left(&intervals), vec![2, 6]);
+        assert_eq!(hitting_points_right(&intervals), vec![0, 4]);
+        assert_eq!(max_distinct(&intervals), 4);
+        assert_eq!(max_distinct(&[(2, 2), (5, 5), (0, 5)]), 3);
+        assert_eq!(max_distinct(&[(0, 1), (0, 1), (1, 1)]), 2);
+    }
+
+    /// The first variable is `n` and the others are `xs`.
+    fn split<P, F>(make: F) -> impl Fn(Vec<FDVar>) -> P
+    where
+        F: Fn(FDVar, Vec<FDVar>) -> P,
+    {
+        move |mut vars: Vec<FDVar>| {
+            let xs = vars.split_off(1);
+            make(vars.pop().unwrap(), xs)
+        }
+    }
+
+    #[test]
+    fn at_most_nvalue_test() {
+        let xs = vec![
+            (0, 2).to_interval(),
+            (4, 6).to_interval(),
+            (3, 9).to_interval(),
+        ];
+        let with_n = |n: Interval<isize>| {
+            let mut doms = vec![n];
+            doms.extend(xs.clone());
+            doms
+        };
+
+        // The two values are taken in `[0, 2]` and `[4, 6]`.
+        nary_propagator_test(
+            1,
+            split(at_most_nvalue),
+            with_n((0, 2).to_interval()),
+            Unknown,
+            Unknown,
+            vec![(0, Assignment), (3, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            split(at_most_nvalue),
+            with_n((0, 1).to_interval()),
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            3,
+            split(at_most_nvalue),
+            vec![
+                (0, 5).to_interval(),
+                (1, 1).to_interval(),
+                (1, 1).to_interval(),
+                (2, 2).to_interval(),
+            ],
+            Unknown,
+            True,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            split(at_most_nvalue),
+            with_n((3, 5).to_interval()),
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn at_least_nvalue_test() {
+        let dom0_1 = (0, 1).to_interval();
+
+        nary_propagator_test(
+            1,
+            split(at_least_nvalue),
+            vec![(0, 5).to_interval(), dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            split(at_least_nvalue),
+            vec![(3, 3).to_interval(), dom0_1, dom0_1, dom0_1],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        // Three distinct values among three variables.
+        nary_propagator_test(
+            3,
+            split(at_least_nvalue),
+            vec![
+                (3, 5).to_interval(),
+                (0, 0).to_interval(),
+                (0, 2).to_interval(),
+                (0, 2).to_interval(),
+            ],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment), (2, Bound), (3, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn nvalue_test() {
+        nary_propagator_test(
+            1,
+            split(NValue::new),
+            vec![
+                (0, 10).to_interval(),
+                (1, 1).to_interval(),
+                (3, 3).to_interval(),
+                (3, 3).to_interval(),
+            ],
+            Unknown,
+            True,
+            vec![(0, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            split(NValue::new),
+            vec![
+                (0, 10).to_interval(),
+                (0, 1).to_interval(),
+                (1, 2).to_interval(),
+                (0, 2).to_interval(),
+            ],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn minimize_nvalue_test() {
+        // Four tasks choose a resource among their allowed ones, the first two cannot share it.
+        let mut space = FDSpace::empty();
+        let xs: Vec<_> = vec![(0, 1), (1, 2), (2, 3), (0, 3)]
+            .into_iter()
+            .map(|d| Box::new(space.vstore.alloc(d.to_interval_set())) as Var<VStore>)
+            .collect();
+        let n = Box::new(space.vstore.alloc((0, 4).to_interval_set())) as Var<VStore>;
+        space
+            .cstore
+            .alloc(Box::new(XNeqY::new(xs[0].bclone(), xs[1].bclone())));
+        space.cstore.alloc(Box::new(NValue::new(n.bclone(), xs)));
+
+        let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+            AllSolution::new(OneSolution::new(BranchAndBound::new(
+                Mode::Minimize,
+                n,
+                Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+            )));
+        search.start(&space);
+        let (_, status) = search.enter(space);
+        assert_eq!(status, Status::EndOfSearch);
+        assert_eq!(search.child.child.value, Some(2));
+    }
+}
@@ -0,0 +1,417 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::automaton::*;
+use propagators::values::*;
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// The sequence `vars` must be a word accepted by `dfa`.
+///
+/// The filtering achieves domain consistency with the layered graph of Pesant (2004): the layer `i` contains the states of `dfa` reachable after reading `vars[0..i]`, and a value of `vars[i]` is supported if it labels an edge between layers `i` and `i+1` lying on a path from the initial state to an accepting state.
+/// The graph is rebuilt at each call in `O(n * |transitions|)`.
+///
+/// The negation accepts the words rejected by `dfa`: at each call, `dfa` is completed over the values of the domains of `vars` and its accepting states are swapped (see `Dfa::complement`).
+pub struct Regular<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    vars: Vec<Var<VStore>>,
+    dfa: Dfa<<VStore::Item as Collection>::Item>,
+    negated: bool,
+}
+
+impl<VStore, Domain, Bound> Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+{
+    pub fn new(vars: Vec<Var<VStore>>, dfa: Dfa<Bound>) -> Self {
+        Regular {
+            vars,
+            dfa,
+            negated: false,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("Regular")
+            .field("vars", &self.vars)
+            .field("dfa", &self.dfa)
+            .field("negated", &self.negated)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Regular {
+            vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            dfa: self.dfa.clone(),
+            negated: self.negated,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Ord + Clone,
+{
+    fn display(&self, model: &Model) {
+        if self.negated {
+            print!("not ");
+        }
+        model.display_call("regular", &self.vars);
+        print!(" over a DFA with {} states", self.dfa.num_states());
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        let mut not_regular = self.clone();
+        not_regular.negated = !self.negated;
+        Box::new(not_regular)
+    }
+}
+
+impl<VStore, Domain, Bound> Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// The automaton of the constraint, completed over the values of `doms` if it is negated.
+    fn automaton(&self, doms: &[Domain]) -> Cow<'_, Dfa<Bound>> {
+        if self.negated {
+            let mut alphabet: Vec<Bound> = doms.iter().flat_map(domain_values).collect();
+            alphabet.sort();
+            alphabet.dedup();
+            Cow::Owned(self.dfa.complement(&alphabet))
+        } else {
+            Cow::Borrowed(&self.dfa)
+        }
+    }
+
+    /// `layers[i][q]` is true if `q` is reachable from the initial state of `dfa` by reading `vars[0..i]` and if an accepting state is reachable from `q` by reading `vars[i..]`.
+    fn layers(dfa: &Dfa<Bound>, doms: &[Domain]) -> Vec<Vec<bool>> {
+        let n = doms.len();
+        let num_states = dfa.num_states();
+        let mut layers = vec![vec![false; num_states]; n + 1];
+        layers[0][dfa.initial()] = true;
+        for (i, dom) in doms.iter().enumerate() {
+            for q in 0..num_states {
+                if layers[i][q] {
+                    for (v, to) in dfa.transitions(q) {
+                        if dom.contains(v) {
+                            layers[i + 1][*to] = true;
+                        }
+                    }
+                }
+            }
+        }
+        for (q, reached) in layers[n].iter_mut().enumerate() {
+            *reached = *reached && dfa.is_accepting(q);
+        }
+        for (i, dom) in doms.iter().enumerate().rev() {
+            for q in 0..num_states {
+                if layers[i][q] {
+                    layers[i][q] = dfa
+                        .transitions(q)
+                        .iter()
+                        .any(|(v, to)| layers[i + 1][*to] && dom.contains(v));
+                }
+            }
+        }
+        layers
+    }
+
+    fn read_all(&self, store: &VStore) -> Vec<Domain> {
+        self.vars.iter().map(|v| v.read(store)).collect()
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let doms = self.read_all(store);
+        let dfa = self.automaton(&doms);
+        if !Self::layers(&dfa, &doms)[0][dfa.initial()] {
+            False
+        } else if doms.iter().all(|d| d.is_singleton()) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Regular<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let doms = self.read_all(store);
+        let supports = {
+            let dfa = self.automaton(&doms);
+            let layers = Self::layers(&dfa, &doms);
+            if !layers[0][dfa.initial()] {
+                return false;
+            }
+            (0..self.vars.len())
+                .map(|i| {
+                    let mut supported: Vec<Bound> = (0..dfa.num_states())
+                        .filter(|&q| layers[i][q])
+                        .flat_map(|q| dfa.transitions(q).iter())
+                        .filter(|(v, to)| layers[i + 1][*to] && doms[i].contains(v))
+                        .map(|(v, _)| v.clone())
+                        .collect();
+                    supported.sort();
+                    supported.dedup();
+                    supported
+                })
+                .collect::<Vec<_>>()
+        };
+        for (i, supported) in supports.iter().enumerate() {
+            // Read again in case the same variable occurs several times in the sequence.
+            let dom = self.vars[i].read(store);
+            if !self.vars[i].update(store, restrict_to_values(dom, supported)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Regular<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.vars
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreSet;
+
+    // Words over {0, 1} without two consecutive `1`.
+    fn no_consecutive_ones() -> Dfa<isize> {
+        Dfa::new(2, 0, vec![(0, 0, 0), (0, 1, 1), (1, 0, 0)], vec![0, 1])
+    }
+
+    #[test]
+    fn regular_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+
+        regular_test_one(
+            1,
+            vec![dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        regular_test_one(
+            2,
+            vec![dom0_3, one, dom0_3],
+            Unknown,
+            True,
+            vec![(0, Assignment), (2, Assignment)],
+            true,
+        );
+        regular_test_one(3, vec![one, one, dom0_1], False, False, vec![], false);
+        regular_test_one(4, vec![zero, one, zero], True, True, vec![], true);
+    }
+
+    #[test]
+    fn not_regular_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        // Words over {0, 1} with two consecutive `1`: in words of length 3, the second symbol is `1`.
+        let not_regular = |vars| {
+            let mut regular = Regular::new(vars, no_consecutive_ones());
+            regular.negated = true;
+            regular
+        };
+
+        nary_propagator_test(
+            1,
+            not_regular,
+            vec![dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(1, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            not_regular,
+            vec![one, dom0_1, zero],
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            not_regular,
+            vec![zero, one, zero],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        // The symbol `2` is not in `dfa`, so the word is rejected.
+        nary_propagator_test(
+            4,
+            not_regular,
+            vec![zero, (2, 2).to_interval(), zero],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn regular_domain_test() {
+        // Words of {0, 1, 2} of which the first and last symbols are equal: (0 [0-2]* 0) | (1 [0-2]* 1) | (2 [0-2]* 2).
+        let dfa = Regex::Union(
+            (0..3)
+                .map(|v| {
+                    Regex::symbol(v)
+                        .concat(Regex::range(0, 2).star())
+                        .concat(Regex::symbol(v))
+                })
+                .collect(),
+        )
+        .to_dfa();
+        let mut store = VStoreSet::empty();
+        let first = Box::new(store.alloc(vec![(0, 0), (2, 2)].to_interval_set())) as Var<VStoreSet>;
+        let middle = Box::new(store.alloc((0, 5).to_interval_set())) as Var<VStoreSet>;
+        let last = Box::new(store.alloc((1, 2).to_interval_set())) as Var<VStoreSet>;
+        let mut regular = Regular::new(vec![first, middle, last], dfa);
+        assert_eq!(regular.is_subsumed(&store), Unknown);
+        assert!(regular.propagate(&mut store));
+        assert_eq!(store[0], (2, 2).to_interval_set());
+        assert_eq!(store[1], (0, 2).to_interval_set());
+        assert_eq!(store[2], (2, 2).to_interval_set());
+    }
+
+    #[test]
+    fn regular_search_test() {
+        // The number of words of length `n` without two consecutive `1` is the Fibonacci number F(n+2), and each is found without failure.
+        let mut fibonacci = (1, 2);
+        for n in 1..10 {
+            let mut space = FDSpace::empty();
+            let vars: Vec<Var<VStore>> = (0..n)
+                .map(|_| Box::new(space.vstore.alloc((0, 1).to_interval_set())) as Var<VStore>)
+                .collect();
+            space
+                .cstore
+                .alloc(Box::new(Regular::new(vars, no_consecutive_ones())));
+            let mut statistics = Statistics::new();
+            {
+                let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                    AllSolution::new(OneSolution::new(Monitor::new(
+                        &mut statistics,
+                        Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                    )));
+                search.start(&space);
+                let (_, status) = search.enter(space);
+                assert_eq!(status, Status::EndOfSearch);
+            }
+            fibonacci = (fibonacci.1, fibonacci.0 + fibonacci.1);
+            assert_eq!(statistics.num_solution, fibonacci.0);
+            assert_eq!(statistics.num_failed_node, 0);
+        }
+    }
+
+    fn regular_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        nary_propagator_test(
+            test_num,
+            |vars| Regular::new(vars, no_consecutive_ones()),
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+}
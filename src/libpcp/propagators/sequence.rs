@@ -0,0 +1,673 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constraints over the windows of `k` consecutive variables of a sequence.
+//!
+//! Both propagators rely on the cumulative sums encoding: with `S[0] = 0` and `S[i+1] = S[i] + x[i]`, the sum of a window is `S[i+k] - S[i]`.
+//! The bounds of the elements and of the windows are difference constraints over `S`, which are all solved together by shortest paths (Bellman-Ford).
+//! Since the system is totally unimodular, the bounds of `S[i+1] - S[i]` computed this way are exactly the bounds of `x[i]` over the solutions of the constraint, across all the windows at once.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// An edge `(i, j, w)` stands for `S[j] - S[i] <= w`.
+type Edge<Bound> = (usize, usize, Bound);
+
+fn prefix_sum_edges<Bound>(
+    steps: &[(Bound, Bound)],
+    l: &Bound,
+    u: &Bound,
+    k: usize,
+) -> Vec<Edge<Bound>>
+where
+    Bound: IntBound,
+{
+    let n = steps.len();
+    let mut edges = vec![];
+    for (i, (lo, hi)) in steps.iter().enumerate() {
+        edges.push((i, i + 1, hi.clone()));
+        edges.push((i + 1, i, -lo.clone()));
+    }
+    for i in 0..(n + 1).saturating_sub(k) {
+        edges.push((i, i + k, u.clone()));
+        edges.push((i + k, i, -l.clone()));
+    }
+    edges
+}
+
+/// Relax the edges at most `passes` times and return `true` if the distances converged.
+fn relax<Bound>(dist: &mut [Option<Bound>], edges: &[Edge<Bound>], passes: usize) -> bool
+where
+    Bound: IntBound,
+{
+    for _ in 0..passes {
+        let mut changed = false;
+        for (i, j, w) in edges {
+            if let Some(d) = dist[*i].clone() {
+                let d = d + w.clone();
+                let shorter = match dist[*j] {
+                    Some(ref dj) => d < *dj,
+                    None => true,
+                };
+                if shorter {
+                    dist[*j] = Some(d);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether the difference constraints have a solution, that is, whether there is no cycle of negative weight.
+fn consistent<Bound>(num_nodes: usize, edges: &[Edge<Bound>]) -> bool
+where
+    Bound: IntBound,
+{
+    // Same as a virtual source connected to every node with a zero weight.
+    let mut dist = vec![Some(Bound::zero()); num_nodes];
+    relax(&mut dist, edges, num_nodes + 1)
+}
+
+/// Bounds of `x[i]` over the sequences whose `i`-th element is in `steps[i]` and whose windows of `k` elements sum in `[l, u]`, or `None` if there is no such sequence.
+/// It runs in `O(n^3)` in the worst case, but the relaxation usually converges after a few passes.
+fn prefix_steps<Bound>(
+    steps: &[(Bound, Bound)],
+    l: &Bound,
+    u: &Bound,
+    k: usize,
+) -> Option<Vec<(Bound, Bound)>>
+where
+    Bound: IntBound,
+{
+    let n = steps.len();
+    let edges = prefix_sum_edges(steps, l, u, k);
+    if !consistent(n + 1, &edges) {
+        return None;
+    }
+    // `dist[s][j]` is the largest value of `S[j] - S[s]`.
+    let dist: Vec<Vec<Option<Bound>>> = (0..n + 1)
+        .map(|s| {
+            let mut dist = vec![None; n + 1];
+            dist[s] = Some(Bound::zero());
+            relax(&mut dist, &edges, n + 1);
+            dist
+        })
+        .collect();
+    Some(
+        (0..n)
+            .map(|i| {
+                let lower = -dist[i + 1][i].clone().unwrap();
+                let upper = dist[i][i + 1].clone().unwrap();
+                (lower, upper)
+            })
+            .collect(),
+    )
+}
+
+/// Whether every window of `k` elements sums in `[l, u]` whatever the values of the elements in `steps`.
+fn windows_entailed<Bound>(steps: &[(Bound, Bound)], l: &Bound, u: &Bound, k: usize) -> bool
+where
+    Bound: IntBound,
+{
+    steps.windows(k).all(|w| {
+        let lower = w.iter().fold(Bound::zero(), |s, b| s + b.0.clone());
+        let upper = w.iter().fold(Bound::zero(), |s, b| s + b.1.clone());
+        *l <= lower && upper <= *u
+    })
+}
+
+/// Between `l` and `u` variables of each window of `k` consecutive variables of `xs` take a value in `values`.
+/// For instance, `Sequence::new(2, 4, 7, xs, vec![1])` with `xs[d] = 1` if a nurse works on day `d` requires between 2 and 4 working days of any 7 consecutive days.
+/// The filtering is domain consistent, whereas a decomposition in one `Sum` per window only reasons on each window separately.
+pub struct Sequence<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    l: <VStore::Item as Collection>::Item,
+    u: <VStore::Item as Collection>::Item,
+    k: usize,
+    xs: Vec<Var<VStore>>,
+    values: Vec<<VStore::Item as Collection>::Item>,
+}
+
+impl<VStore, Domain, Bound> Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Precondition: `k > 0`.
+    pub fn new(l: usize, u: usize, k: usize, xs: Vec<Var<VStore>>, mut values: Vec<Bound>) -> Self {
+        assert!(k > 0, "The windows of `Sequence` must not be empty.");
+        values.sort();
+        values.dedup();
+        Sequence {
+            l: bound_of_usize(l),
+            u: bound_of_usize(u),
+            k,
+            xs,
+            values,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("Sequence")
+            .field("l", &self.l)
+            .field("u", &self.u)
+            .field("k", &self.k)
+            .field("xs", &self.xs)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Sequence {
+            l: self.l.clone(),
+            u: self.u.clone(),
+            k: self.k,
+            xs: self.xs.iter().map(|x| x.bclone()).collect(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        print!("sequence({:?}, {:?}, {}, ", self.l, self.u, self.k);
+        model.display_array(&self.xs);
+        print!(", {:?})", self.values);
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// For each variable, `(1, 1)` if it must take a value in `values`, `(0, 0)` if it cannot, and `(0, 1)` otherwise.
+    fn steps(&self, store: &VStore) -> Vec<(Bound, Bound)> {
+        let (zero, one) = (Bound::zero(), Bound::one());
+        self.xs
+            .iter()
+            .map(|x| {
+                let dom = x.read(store);
+                if !self.values.iter().any(|v| dom.contains(v)) {
+                    (zero.clone(), zero.clone())
+                } else if is_subset_of_values(&dom, &self.values) {
+                    (one.clone(), one.clone())
+                } else {
+                    (zero.clone(), one.clone())
+                }
+            })
+            .collect()
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let steps = self.steps(store);
+        let edges = prefix_sum_edges(&steps, &self.l, &self.u, self.k);
+        if !consistent(steps.len() + 1, &edges) {
+            False
+        } else if windows_entailed(&steps, &self.l, &self.u, self.k) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Sequence<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let steps = self.steps(store);
+        let bounds = match prefix_steps(&steps, &self.l, &self.u, self.k) {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+        for (i, (lower, upper)) in bounds.into_iter().enumerate() {
+            let dom = self.xs[i].read(store);
+            let dom = if upper.is_zero() {
+                remove_values(dom, &self.values)
+            } else if lower == Bound::one() {
+                restrict_to_values(dom, &self.values)
+            } else {
+                continue;
+            };
+            if !self.xs[i].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Sequence<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .flat_map(|x| x.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+/// The sum of each window of `k` consecutive variables of `xs` is between `l` and `u`.
+/// The filtering is bounds consistent, whereas a decomposition in one `Sum` per window only reasons on each window separately.
+pub struct SlidingSum<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    l: <VStore::Item as Collection>::Item,
+    u: <VStore::Item as Collection>::Item,
+    k: usize,
+    xs: Vec<Var<VStore>>,
+}
+
+impl<VStore, Domain, Bound> SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Precondition: `k > 0`.
+    pub fn new(l: Bound, u: Bound, k: usize, xs: Vec<Var<VStore>>) -> Self {
+        assert!(k > 0, "The windows of `SlidingSum` must not be empty.");
+        SlidingSum { l, u, k, xs }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("SlidingSum")
+            .field("l", &self.l)
+            .field("u", &self.u)
+            .field("k", &self.k)
+            .field("xs", &self.xs)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        SlidingSum {
+            l: self.l.clone(),
+            u: self.u.clone(),
+            k: self.k,
+            xs: self.xs.iter().map(|x| x.bclone()).collect(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        print!("sliding_sum({:?}, {:?}, {}, ", self.l, self.u, self.k);
+        model.display_array(&self.xs);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn steps(&self, store: &VStore) -> Vec<(Bound, Bound)> {
+        self.xs
+            .iter()
+            .map(|x| {
+                let dom = x.read(store);
+                (dom.lower(), dom.upper())
+            })
+            .collect()
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let steps = self.steps(store);
+        let edges = prefix_sum_edges(&steps, &self.l, &self.u, self.k);
+        if !consistent(steps.len() + 1, &edges) {
+            False
+        } else if windows_entailed(&steps, &self.l, &self.u, self.k) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for SlidingSum<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let steps = self.steps(store);
+        let bounds = match prefix_steps(&steps, &self.l, &self.u, self.k) {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+        for (i, (lower, upper)) in bounds.into_iter().enumerate() {
+            let dom = self.xs[i]
+                .read(store)
+                .shrink_left(lower)
+                .shrink_right(upper);
+            if !self.xs[i].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for SlidingSum<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .flat_map(|x| x.dependencies(FDEvent::Bound))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // Also the name of a collection kind of `gcollections`.
+    use super::Sequence;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn sequence_test() {
+        let one_or_two = |xs: Vec<FDVar>| Sequence::new(1, 2, 3, xs, vec![1]);
+        let exactly_two = |xs: Vec<FDVar>| Sequence::new(2, 2, 3, xs, vec![1]);
+        let dom0_1 = (0, 1).to_interval();
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+
+        nary_propagator_test(
+            1,
+            one_or_two,
+            vec![dom0_1; 5],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            one_or_two,
+            vec![one, one, dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(2, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            one_or_two,
+            vec![zero, zero, dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(2, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            one_or_two,
+            vec![zero, zero, zero, dom0_1, dom0_1],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            5,
+            one_or_two,
+            vec![one, zero, dom0_1, one],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        // The windows `xs[0..3]` and `xs[1..4]` share `xs[1..3]`, so `xs[3] = xs[0]`.
+        nary_propagator_test(
+            6,
+            exactly_two,
+            vec![one, dom0_1, dom0_1, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(3, Assignment)],
+            true,
+        );
+        // The window `xs[1..4]` contains a single one.
+        nary_propagator_test(
+            7,
+            exactly_two,
+            vec![one, one, zero, zero, dom0_1],
+            False,
+            False,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn sequence_holes_test() {
+        let mut store = VStoreSet::empty();
+        let xs: Vec<Var<VStoreSet>> = (0..3)
+            .map(|_| Box::new(store.alloc((0, 5).to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let mut sequence =
+            Sequence::new(1, 1, 2, xs.iter().map(|x| x.bclone()).collect(), vec![4, 2]);
+        assert!(sequence.propagate(&mut store));
+        assert_eq!(store[1], (0, 5).to_interval_set());
+        assert!(xs[0].bclone().update(&mut store, (2, 2).to_interval_set()));
+        assert!(sequence.propagate(&mut store));
+        assert_eq!(store[1], vec![(0, 1), (3, 3), (5, 5)].to_interval_set());
+        assert_eq!(store[2], vec![(2, 2), (4, 4)].to_interval_set());
+        assert_eq!(sequence.is_subsumed(&store), True);
+    }
+
+    #[test]
+    fn sliding_sum_test() {
+        let make_sum = |l, u| move |xs: Vec<FDVar>| SlidingSum::new(l, u, 2, xs);
+        let dom0_5 = (0, 5).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_sum(5, 7),
+            vec![dom0_5, dom0_5, dom0_5],
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_sum(5, 7),
+            vec![dom0_5, dom0_1, dom0_5],
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+        // `x0 + x1 = 3` and `x1 + x2 = 3` so `x2 = x0`.
+        nary_propagator_test(
+            3,
+            make_sum(3, 3),
+            vec![dom0_1, (0, 3).to_interval(), (0, 3).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(1, Bound), (2, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            make_sum(5, 7),
+            vec![dom0_1, dom0_1, dom0_5],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            5,
+            make_sum(5, 7),
+            vec![
+                (1, 2).to_interval(),
+                (4, 5).to_interval(),
+                (1, 2).to_interval(),
+            ],
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn sequence_search_test() {
+        // Every window of 3 consecutive days has exactly 2 working days, so the schedule repeats every 3 days.
+        let mut space = FDSpace::empty();
+        let xs: Vec<_> = (0..5)
+            .map(|_| Box::new(space.vstore.alloc((0, 1).to_interval_set())) as Var<VStore>)
+            .collect();
+        space
+            .cstore
+            .alloc(Box::new(Sequence::new(2, 2, 3, xs, vec![1])));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 3);
+        assert_eq!(statistics.num_failed_node, 0);
+    }
+}
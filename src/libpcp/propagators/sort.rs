@@ -0,0 +1,370 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::graph::*;
+use std::cmp::{max, min};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// Bounds `(lower, upper)` of each variable.
+type Bounds<Bound> = Vec<(Bound, Bound)>;
+
+/// Intersection of two intervals `(lower, upper)`, or `None` if they are disjoint.
+fn meet<Bound: IntBound>(a: &(Bound, Bound), b: &(Bound, Bound)) -> Option<(Bound, Bound)> {
+    let lower = max(a.0.clone(), b.0.clone());
+    let upper = min(a.1.clone(), b.1.clone());
+    if lower <= upper {
+        Some((lower, upper))
+    } else {
+        None
+    }
+}
+
+/// Smallest interval containing `a` and `b`.
+fn join<Bound: IntBound>(a: Option<(Bound, Bound)>, b: (Bound, Bound)) -> Option<(Bound, Bound)> {
+    Some(match a {
+        None => b,
+        Some(a) => (min(a.0, b.0), max(a.1, b.1)),
+    })
+}
+
+/// Bounds of `xs` and `ys` narrowed until a fixpoint, or `None` if `ys` cannot be the sorted version of `xs`.
+fn sort_bounds<Bound>(
+    mut xs: Bounds<Bound>,
+    mut ys: Bounds<Bound>,
+) -> Option<(Bounds<Bound>, Bounds<Bound>)>
+where
+    Bound: IntBound,
+{
+    let n = xs.len();
+    loop {
+        // `ys[j]` is between the `j`-th smallest lower bound and the `j`-th smallest upper bound of `xs`.
+        let mut lowers: Vec<Bound> = xs.iter().map(|b| b.0.clone()).collect();
+        let mut uppers: Vec<Bound> = xs.iter().map(|b| b.1.clone()).collect();
+        lowers.sort();
+        uppers.sort();
+        for j in 0..n {
+            ys[j].0 = max(ys[j].0.clone(), lowers[j].clone());
+            ys[j].1 = min(ys[j].1.clone(), uppers[j].clone());
+        }
+        for j in 1..n {
+            ys[j].0 = max(ys[j].0.clone(), ys[j - 1].0.clone());
+        }
+        for j in (1..n).rev() {
+            ys[j - 1].1 = min(ys[j - 1].1.clone(), ys[j].1.clone());
+        }
+        if ys.iter().any(|y| y.0 > y.1) {
+            return None;
+        }
+        // Each variable of `xs` is equal to a distinct position of `ys`.
+        let edges: Vec<Vec<usize>> = xs
+            .iter()
+            .map(|x| (0..n).filter(|&j| meet(x, &ys[j]).is_some()).collect())
+            .collect();
+        let matching = maximum_matching(&edges, n)?;
+        let supported = supported_edges(&edges, &matching, n);
+        let mut new_xs = vec![None; n];
+        let mut new_ys = vec![None; n];
+        for (i, positions) in edges.iter().enumerate() {
+            for (k, &j) in positions.iter().enumerate() {
+                if supported[i][k] {
+                    let b = meet(&xs[i], &ys[j]).unwrap();
+                    new_xs[i] = join(new_xs[i].take(), b.clone());
+                    new_ys[j] = join(new_ys[j].take(), b);
+                }
+            }
+        }
+        let new_xs: Vec<_> = new_xs.into_iter().map(Option::unwrap).collect();
+        let new_ys: Vec<_> = new_ys.into_iter().map(Option::unwrap).collect();
+        if new_xs == xs && new_ys == ys {
+            return Some((xs, ys));
+        }
+        xs = new_xs;
+        ys = new_ys;
+    }
+}
+
+/// `ys` is `xs` sorted in increasing order.
+///
+/// The filtering follows Bleuzen-Guernalec and Colmerauer (1997): the bounds of `ys[j]` are narrowed to the `j`-th smallest bounds of `xs`, and each variable of `xs` is matched to a position of `ys` whose bounds intersect its own.
+/// The bounds of a variable of `xs` are restricted to the positions that belong to a perfect matching, which is bounds consistent on `xs`.
+/// Each pass runs in `O(n^2)` and is repeated until a fixpoint.
+#[derive(Debug)]
+pub struct Sort<VStore> {
+    xs: Vec<Var<VStore>>,
+    ys: Vec<Var<VStore>>,
+}
+
+impl<VStore> Sort<VStore> {
+    /// Precondition: `xs.len() == ys.len()`.
+    pub fn new(xs: Vec<Var<VStore>>, ys: Vec<Var<VStore>>) -> Self {
+        assert_eq!(
+            xs.len(),
+            ys.len(),
+            "`Sort` requires as many sorted variables as variables."
+        );
+        Sort { xs, ys }
+    }
+}
+
+impl<VStore> Clone for Sort<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Sort::new(
+            self.xs.iter().map(|x| x.bclone()).collect(),
+            self.ys.iter().map(|y| y.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Sort<VStore> {
+    fn display(&self, model: &Model) {
+        print!("sort(");
+        model.display_array(&self.xs);
+        print!(", ");
+        model.display_array(&self.ys);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Sort<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> Sort<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read_bounds(vars: &[Var<VStore>], store: &VStore) -> Bounds<Bound> {
+        vars.iter()
+            .map(|v| {
+                let dom = v.read(store);
+                (dom.lower(), dom.upper())
+            })
+            .collect()
+    }
+
+    fn bounds(&self, store: &VStore) -> Option<(Bounds<Bound>, Bounds<Bound>)> {
+        sort_bounds(
+            Self::read_bounds(&self.xs, store),
+            Self::read_bounds(&self.ys, store),
+        )
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Sort<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        if self.bounds(store).is_none() {
+            False
+        } else if self
+            .xs
+            .iter()
+            .chain(self.ys.iter())
+            .all(|v| v.read(store).is_singleton())
+        {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Sort<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let (xs, ys) = match self.bounds(store) {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+        let vars = self.xs.iter_mut().chain(self.ys.iter_mut());
+        for (v, (lower, upper)) in vars.zip(xs.into_iter().chain(ys)) {
+            let dom = v.read(store).shrink_left(lower).shrink_right(upper);
+            if !v.update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Sort<VStore> {
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        let mut deps: Vec<_> = self
+            .xs
+            .iter()
+            .chain(self.ys.iter())
+            .flat_map(|v| v.dependencies(FDEvent::Bound))
+            .collect();
+        // A variable can appear in both `xs` and `ys`.
+        deps.sort();
+        deps.dedup();
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+
+    #[test]
+    fn sort_test() {
+        // The first half of the variables are `xs` and the second half `ys`.
+        let make_sort = |mut vars: Vec<FDVar>| {
+            let ys = vars.split_off(vars.len() / 2);
+            Sort::new(vars, ys)
+        };
+        let dom0_10 = (0, 10).to_interval();
+        let dom0_5 = (0, 5).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+        let three = (3, 3).to_interval();
+        let four = (4, 4).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_sort,
+            vec![
+                (1, 5).to_interval(),
+                three,
+                (0, 2).to_interval(),
+                dom0_10,
+                dom0_10,
+                dom0_10,
+            ],
+            Unknown,
+            Unknown,
+            vec![(3, Bound), (4, Bound), (5, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_sort,
+            vec![
+                dom0_1,
+                dom0_1,
+                (5, 5).to_interval(),
+                dom0_10,
+                dom0_10,
+                (0, 4).to_interval(),
+            ],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        // `xs[2]` is the only variable that can be at position 2, so `xs[0]` and `xs[1]` are in `{1, 3}`.
+        nary_propagator_test(
+            3,
+            make_sort,
+            vec![dom0_10, dom0_10, four, one, three, (4, 10).to_interval()],
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound), (5, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            make_sort,
+            vec![three, one, two, one, two, three],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            5,
+            make_sort,
+            vec![two, two, dom0_5, dom0_5, dom0_5, dom0_5],
+            Unknown,
+            Unknown,
+            vec![(3, Bound), (4, Assignment), (5, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            6,
+            make_sort,
+            vec![three, one, two, one, three, two],
+            False,
+            False,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn sort_search_test() {
+        // The sorted variables are assigned by propagation once `xs` is assigned.
+        let mut space = FDSpace::empty();
+        let vars: Vec<_> = (0..6)
+            .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+            .collect();
+        let xs = vars[..3].iter().map(|v| v.bclone()).collect();
+        let ys = vars[3..].iter().map(|v| v.bclone()).collect();
+        space.cstore.alloc(Box::new(Sort::new(xs, ys)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 27);
+        assert_eq!(statistics.num_failed_node, 0);
+    }
+}
@@ -0,0 +1,460 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bit_set::BitSet;
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// Extensional constraint: the tuple formed by `vars` must belong to `tuples` (positive table) or must not belong to `tuples` (negative table).
+///
+/// The filtering follows the compact-table algorithm (Demeulenaere and al., 2016): for each variable and value, we precompute the bitset of the tuples supporting this value.
+/// The valid tuples are obtained by intersecting, for each variable, the union of the supports of the values in its domain.
+/// Propagators are not restored on backtrack, thus the current table is recomputed at each call instead of being maintained incrementally.
+pub struct Table<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    vars: Vec<Var<VStore>>,
+    tuples: Vec<Vec<<VStore::Item as Collection>::Item>>,
+    // `supports[i]` contains the pairs `(v, s)` sorted by `v` where `s` is the set of tuples `t` such that `tuples[t][i] == v`.
+    supports: Vec<Vec<(<VStore::Item as Collection>::Item, BitSet)>>,
+    positive: bool,
+}
+
+impl<VStore, Domain, Bound> Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// The tuple formed by `vars` must be one of `tuples`.
+    /// Precondition: `vars.len() > 0` and each tuple has one value per variable.
+    pub fn new(vars: Vec<Var<VStore>>, tuples: Vec<Vec<Bound>>) -> Self {
+        Table::make(vars, tuples, true)
+    }
+
+    /// The tuple formed by `vars` must not be one of `tuples`.
+    /// Precondition: `vars.len() > 0` and each tuple has one value per variable.
+    pub fn negative(vars: Vec<Var<VStore>>, tuples: Vec<Vec<Bound>>) -> Self {
+        Table::make(vars, tuples, false)
+    }
+
+    fn make(vars: Vec<Var<VStore>>, mut tuples: Vec<Vec<Bound>>, positive: bool) -> Self {
+        assert!(
+            !vars.is_empty(),
+            "Variable array in `Table` must be non-empty."
+        );
+        for tuple in &tuples {
+            assert_eq!(
+                tuple.len(),
+                vars.len(),
+                "Each tuple in `Table` must have one value per variable."
+            );
+        }
+        // Duplicated tuples would be counted twice when checking subsumption.
+        tuples.sort();
+        tuples.dedup();
+        let supports = (0..vars.len())
+            .map(|i| Self::supports_of(&tuples, i))
+            .collect();
+        Table {
+            vars,
+            tuples,
+            supports,
+            positive,
+        }
+    }
+
+    fn supports_of(tuples: &[Vec<Bound>], i: usize) -> Vec<(Bound, BitSet)> {
+        let mut values: Vec<Bound> = tuples.iter().map(|t| t[i].clone()).collect();
+        values.sort();
+        values.dedup();
+        let mut supports: Vec<(Bound, BitSet)> = values
+            .into_iter()
+            .map(|v| (v, BitSet::with_capacity(tuples.len())))
+            .collect();
+        for (t, tuple) in tuples.iter().enumerate() {
+            let pos = supports
+                .binary_search_by(|s| s.0.cmp(&tuple[i]))
+                .expect("Every value of the table has a support list.");
+            supports[pos].1.insert(t);
+        }
+        supports
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("Table")
+            .field("vars", &self.vars)
+            .field("tuples", &self.tuples)
+            .field("positive", &self.positive)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        Table {
+            vars: self.vars.iter().map(|v| v.bclone()).collect(),
+            tuples: self.tuples.clone(),
+            supports: self.supports.clone(),
+            positive: self.positive,
+        }
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Table<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn display(&self, model: &Model) {
+        let name = if self.positive {
+            "table"
+        } else {
+            "negative_table"
+        };
+        model.display_call(name, &self.vars);
+        print!(" over {} tuples", self.tuples.len());
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        let mut not_table = self.clone();
+        not_table.positive = !self.positive;
+        Box::new(not_table)
+    }
+}
+
+impl<VStore, Domain, Bound> Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read_all(&self, store: &VStore) -> Vec<Domain> {
+        self.vars.iter().map(|v| v.read(store)).collect()
+    }
+
+    /// Tuples of which every value is still in the domain of its variable.
+    fn valid_tuples(&self, doms: &[Domain]) -> BitSet {
+        let mut valid: BitSet = (0..self.tuples.len()).collect();
+        for (supports, dom) in self.supports.iter().zip(doms.iter()) {
+            let mut mask = BitSet::with_capacity(self.tuples.len());
+            for (v, s) in supports {
+                if dom.contains(v) {
+                    mask.union_with(s);
+                }
+            }
+            valid.intersect_with(&mask);
+        }
+        valid
+    }
+
+    /// Size of the Cartesian product of `doms` without the domain at `skip`, or `cap` if it is larger.
+    fn bounded_product(doms: &[Domain], skip: Option<usize>, cap: usize) -> usize {
+        let mut product: usize = 1;
+        for (i, dom) in doms.iter().enumerate() {
+            if Some(i) != skip {
+                product = product.saturating_mul(bounded_size(dom, cap)).min(cap);
+            }
+        }
+        product
+    }
+
+    fn propagate_positive(&mut self, store: &mut VStore) -> bool {
+        let valid = self.valid_tuples(&self.read_all(store));
+        if valid.is_empty() {
+            return false;
+        }
+        for i in 0..self.vars.len() {
+            // Read again in case the same variable occurs several times in the scope.
+            let dom = self.vars[i].read(store);
+            let supported: Vec<Bound> = self.supports[i]
+                .iter()
+                .filter(|(v, s)| dom.contains(v) && !s.is_disjoint(&valid))
+                .map(|(v, _)| v.clone())
+                .collect();
+            if !self.vars[i].update(store, restrict_to_values(dom, &supported)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    // A value `v` of `x_i` is not supported if all the tuples of the Cartesian product with `x_i = v` are forbidden.
+    fn propagate_negative(&mut self, store: &mut VStore) -> bool {
+        let doms = self.read_all(store);
+        let valid = self.valid_tuples(&doms);
+        let cap = valid.len() + 1;
+        for i in 0..self.vars.len() {
+            let others = Self::bounded_product(&doms, Some(i), cap);
+            if others >= cap {
+                continue;
+            }
+            for (v, s) in &self.supports[i] {
+                if doms[i].contains(v) && s.intersection(&valid).count() == others {
+                    let dom = self.vars[i].read(store);
+                    if !self.vars[i].update(store, dom.difference(v)) {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let doms = self.read_all(store);
+        let valid = self.valid_tuples(&doms).len();
+        // The tuples are distinct so they cover the Cartesian product if they are as many.
+        let covered = Self::bounded_product(&doms, None, valid + 1) == valid;
+        match (self.positive, valid, covered) {
+            (true, 0, _) => False,
+            (true, _, true) => True,
+            (false, 0, _) => True,
+            (false, _, true) => False,
+            _ => Unknown,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for Table<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        if self.positive {
+            self.propagate_positive(store)
+        } else {
+            self.propagate_negative(store)
+        }
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for Table<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.vars
+            .iter()
+            .flat_map(|v| v.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn table_test() {
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let dom0_2 = (0, 2).to_interval();
+        let dom0_3 = (0, 3).to_interval();
+
+        let tuples = vec![vec![1, 1], vec![2, 3], vec![3, 2]];
+        table_test_one(
+            1,
+            vec![dom0_3, dom0_3],
+            tuples,
+            true,
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        let tuples = vec![vec![0, 2], vec![1, 1]];
+        table_test_one(
+            2,
+            vec![zero, dom0_3],
+            tuples,
+            true,
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        let tuples = vec![vec![0, 2]];
+        table_test_one(
+            3,
+            vec![zero, one],
+            tuples,
+            true,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        let tuples = vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1], vec![1, 1]];
+        table_test_one(
+            4,
+            vec![dom0_1, dom0_1],
+            tuples,
+            true,
+            True,
+            True,
+            vec![],
+            true,
+        );
+        let tuples = vec![vec![0, 0], vec![0, 2]];
+        table_test_one(
+            5,
+            vec![zero, dom0_2],
+            tuples,
+            false,
+            Unknown,
+            True,
+            vec![(1, Assignment)],
+            true,
+        );
+        let tuples = vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]];
+        table_test_one(
+            6,
+            vec![dom0_1, dom0_1],
+            tuples,
+            false,
+            False,
+            False,
+            vec![],
+            false,
+        );
+        let tuples = vec![vec![1, 1]];
+        table_test_one(
+            7,
+            vec![dom0_3, dom0_3],
+            tuples,
+            false,
+            Unknown,
+            Unknown,
+            vec![],
+            true,
+        );
+        let tuples = vec![vec![1, 1]];
+        table_test_one(
+            8,
+            vec![dom0_3, zero],
+            tuples,
+            false,
+            True,
+            True,
+            vec![],
+            true,
+        );
+    }
+
+    fn table_test_one(
+        test_num: u32,
+        doms: Vec<Interval<isize>>,
+        tuples: Vec<Vec<isize>>,
+        positive: bool,
+        before: SKleene,
+        after: SKleene,
+        delta_expected: Vec<(usize, FDEvent)>,
+        propagate_success: bool,
+    ) {
+        let make_table = |vars| {
+            if positive {
+                Table::new(vars, tuples)
+            } else {
+                Table::negative(vars, tuples)
+            }
+        };
+        nary_propagator_test(
+            test_num,
+            make_table,
+            doms,
+            before,
+            after,
+            delta_expected,
+            propagate_success,
+        );
+    }
+
+    #[test]
+    fn table_gac_test() {
+        let mut store = VStoreSet::empty();
+        let x = Box::new(store.alloc((1, 3).to_interval_set())) as Var<VStoreSet>;
+        let y = Box::new(store.alloc((1, 3).to_interval_set())) as Var<VStoreSet>;
+        let z = Box::new(store.alloc((0, 9).to_interval_set())) as Var<VStoreSet>;
+        let vars = vec![x.bclone(), y.bclone(), z.bclone()];
+        let mut table = Table::new(
+            vars,
+            vec![vec![1, 1, 0], vec![1, 3, 5], vec![3, 1, 9], vec![2, 4, 1]],
+        );
+        assert!(table.propagate(&mut store));
+        assert_eq!(x.read(&store), vec![(1, 1), (3, 3)].to_interval_set());
+        assert_eq!(y.read(&store), vec![(1, 1), (3, 3)].to_interval_set());
+        assert_eq!(
+            z.read(&store),
+            vec![(0, 0), (5, 5), (9, 9)].to_interval_set()
+        );
+
+        // The negation forbids the remaining tuples, which rules out `x = 1` once `y = 1` and `z = 0`.
+        let mut not_table = table.not();
+        assert!(y.bclone().update(&mut store, (1, 1).to_interval_set()));
+        assert!(z.bclone().update(&mut store, (0, 0).to_interval_set()));
+        assert_eq!(not_table.is_subsumed(&store), Unknown);
+        assert!(not_table.propagate(&mut store));
+        assert_eq!(x.read(&store), (3, 3).to_interval_set());
+        assert_eq!(not_table.is_subsumed(&store), True);
+        assert_eq!(table.is_subsumed(&store), False);
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use logic::*;
+use model::*;
+use propagation::events::*;
+use propagation::*;
+use propagators::values::*;
+use std::fmt::{Debug, Formatter, Result};
+use trilean::SKleene;
+use trilean::SKleene::*;
+
+/// For each pair of consecutive values `s = values[i]` and `t = values[i+1]`, if a variable of `xs` takes the value `t`, then a variable before it takes the value `s`.
+/// It breaks the symmetry between interchangeable values, for instance the colors of a graph coloring problem.
+///
+/// The constraint is seen as an automaton whose state `q` is the number of values of the chain already used in `xs[0..i]`: the value `values[j]` is accepted if `j <= q`, and the values outside of the chain are always accepted.
+/// The filtering achieves domain consistency by computing, as for `Regular`, the states reachable from the initial state and from which the end of `xs` can be read, in `O(n * m^2)` with `m = values.len()`.
+pub struct ValuePrecede<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    values: Vec<<VStore::Item as Collection>::Item>,
+    xs: Vec<Var<VStore>>,
+}
+
+impl<VStore, Domain, Bound> ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: IntBound,
+{
+    /// The value `s` precedes the value `t` in `xs`.
+    /// Precondition: `s != t`.
+    pub fn new(s: Bound, t: Bound, xs: Vec<Var<VStore>>) -> Self {
+        ValuePrecede::chain(vec![s, t], xs)
+    }
+
+    /// Each value of `values` precedes the next one in `xs`.
+    /// Precondition: the values are distinct.
+    pub fn chain(values: Vec<Bound>, xs: Vec<Var<VStore>>) -> Self {
+        let mut sorted = values.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(
+            sorted.len(),
+            values.len(),
+            "The values of `ValuePrecede` must be distinct."
+        );
+        ValuePrecede { values, xs }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("ValuePrecede")
+            .field("values", &self.values)
+            .field("xs", &self.xs)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        ValuePrecede {
+            values: self.values.clone(),
+            xs: self.xs.iter().map(|v| v.bclone()).collect(),
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        if self.values.len() == 2 {
+            print!("value_precede({:?}, {:?}, ", self.values[0], self.values[1]);
+        } else {
+            print!("value_precede_chain({:?}, ", self.values);
+        }
+        model.display_array(&self.xs);
+        print!(")");
+    }
+}
+
+impl<VStore, Domain, Bound> NotFormula<VStore> for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    fn not(&self) -> Formula<VStore> {
+        Box::new(Negation::new(self.clone()))
+    }
+}
+
+impl<VStore, Domain, Bound> ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// `layers[i][q]` is true if the state `q` is reachable by reading `xs[0..i]` and if the end of `xs` can be read from `q`.
+    /// All the states are accepting, so the last layer only depends on the forward pass.
+    fn layers(&self, doms: &[Domain], others: &[bool]) -> Vec<Vec<bool>> {
+        let n = doms.len();
+        let m = self.values.len();
+        let mut layers = vec![vec![false; m + 1]; n + 1];
+        layers[0][0] = true;
+        for i in 0..n {
+            for q in 0..m + 1 {
+                if layers[i][q] {
+                    if others[i] {
+                        layers[i + 1][q] = true;
+                    }
+                    for j in 0..(q + 1).min(m) {
+                        if doms[i].contains(&self.values[j]) {
+                            layers[i + 1][q.max(j + 1)] = true;
+                        }
+                    }
+                }
+            }
+        }
+        for i in (0..n).rev() {
+            for q in 0..m + 1 {
+                if layers[i][q] {
+                    layers[i][q] = (others[i] && layers[i + 1][q])
+                        || (0..(q + 1).min(m)).any(|j| {
+                            doms[i].contains(&self.values[j]) && layers[i + 1][q.max(j + 1)]
+                        });
+                }
+            }
+        }
+        layers
+    }
+
+    /// Domains of `xs`, and whether each of them contains a value outside of the chain.
+    fn read_all(&self, store: &VStore) -> (Vec<Domain>, Vec<bool>) {
+        let mut sorted = self.values.clone();
+        sorted.sort();
+        let doms: Vec<Domain> = self.xs.iter().map(|x| x.read(store)).collect();
+        let others = doms
+            .iter()
+            .map(|d| !is_subset_of_values(d, &sorted))
+            .collect();
+        (doms, others)
+    }
+}
+
+impl<VStore, Domain, Bound> Subsumption<VStore> for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn is_subsumed(&self, store: &VStore) -> SKleene {
+        let (doms, others) = self.read_all(store);
+        if !self.layers(&doms, &others)[0][0] {
+            False
+        } else if doms.iter().all(|d| d.is_singleton()) {
+            True
+        } else {
+            Unknown
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Propagator<VStore> for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn propagate(&mut self, store: &mut VStore) -> bool {
+        let (doms, others) = self.read_all(store);
+        let layers = self.layers(&doms, &others);
+        if !layers[0][0] {
+            return false;
+        }
+        let m = self.values.len();
+        for i in 0..self.xs.len() {
+            let states: Vec<usize> = (0..m + 1).filter(|&q| layers[i][q]).collect();
+            let mut supported = vec![];
+            let mut unsupported = vec![];
+            for (j, v) in self.values.iter().enumerate() {
+                if doms[i].contains(v) {
+                    if states
+                        .iter()
+                        .any(|&q| j <= q && layers[i + 1][q.max(j + 1)])
+                    {
+                        supported.push(v.clone());
+                    } else {
+                        unsupported.push(v.clone());
+                    }
+                }
+            }
+            supported.sort();
+            unsupported.sort();
+            let dom = self.xs[i].read(store);
+            let dom = if others[i] && states.iter().any(|&q| layers[i + 1][q]) {
+                remove_values(dom, &unsupported)
+            } else {
+                restrict_to_values(dom, &supported)
+            };
+            if !self.xs[i].update(store, dom) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<VStore> PropagatorDependencies<FDEvent> for ValuePrecede<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self) -> Vec<(usize, FDEvent)> {
+        self.xs
+            .iter()
+            .flat_map(|x| x.dependencies(FDEvent::Inner))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use gcollections::VectorStack;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::test::*;
+    use search::branching::*;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::*;
+    use variable::VStoreSet;
+
+    #[test]
+    fn value_precede_test() {
+        let make_precede = |xs: Vec<FDVar>| ValuePrecede::new(1, 2, xs);
+        let dom0_2 = (0, 2).to_interval();
+        let dom0_1 = (0, 1).to_interval();
+        let zero = (0, 0).to_interval();
+        let one = (1, 1).to_interval();
+        let two = (2, 2).to_interval();
+
+        nary_propagator_test(
+            1,
+            make_precede,
+            vec![dom0_2, dom0_2, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(0, Bound)],
+            true,
+        );
+        nary_propagator_test(
+            2,
+            make_precede,
+            vec![(1, 2).to_interval(), dom0_2, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment)],
+            true,
+        );
+        // The value 2 is taken before any other variable can take 1, so `xs[0]` must be 1.
+        nary_propagator_test(
+            3,
+            make_precede,
+            vec![dom0_1, two, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            4,
+            make_precede,
+            vec![zero, two, dom0_2],
+            False,
+            False,
+            vec![],
+            false,
+        );
+        nary_propagator_test(
+            5,
+            make_precede,
+            vec![one, two, zero],
+            True,
+            True,
+            vec![],
+            true,
+        );
+        nary_propagator_test(
+            6,
+            make_precede,
+            vec![zero, dom0_2, dom0_1],
+            Unknown,
+            Unknown,
+            vec![(1, Bound)],
+            true,
+        );
+    }
+
+    #[test]
+    fn value_precede_chain_test() {
+        let make_chain = |xs: Vec<FDVar>| ValuePrecede::chain(vec![0, 1, 2], xs);
+        let dom0_2 = (0, 2).to_interval();
+        let dom1_2 = (1, 2).to_interval();
+
+        // The first variable is 0 and the second one cannot be 2.
+        nary_propagator_test(
+            1,
+            make_chain,
+            vec![dom0_2, dom0_2, dom0_2],
+            Unknown,
+            Unknown,
+            vec![(0, Assignment), (1, Bound)],
+            true,
+        );
+        // 2 requires 1 before it, which requires 0 before it.
+        nary_propagator_test(
+            2,
+            make_chain,
+            vec![dom0_2, dom0_2, (2, 2).to_interval()],
+            Unknown,
+            True,
+            vec![(0, Assignment), (1, Assignment)],
+            true,
+        );
+        nary_propagator_test(
+            3,
+            make_chain,
+            vec![dom1_2, dom0_2, dom0_2],
+            False,
+            False,
+            vec![],
+            false,
+        );
+    }
+
+    #[test]
+    fn value_precede_holes_test() {
+        let mut store = VStoreSet::empty();
+        let xs: Vec<Var<VStoreSet>> = (0..3)
+            .map(|_| Box::new(store.alloc((0, 3).to_interval_set())) as Var<VStoreSet>)
+            .collect();
+        let mut precede = ValuePrecede::new(1, 2, xs.iter().map(|x| x.bclone()).collect());
+        assert!(precede.propagate(&mut store));
+        assert_eq!(store[0], vec![(0, 1), (3, 3)].to_interval_set());
+        assert_eq!(store[1], (0, 3).to_interval_set());
+        // Neither `xs[0]` nor `xs[1]` can take 1, so 2 is removed from `xs[1]` and `xs[2]`.
+        assert!(xs[0].bclone().update(&mut store, (3, 3).to_interval_set()));
+        assert!(xs[1]
+            .bclone()
+            .update(&mut store, vec![(0, 0), (2, 3)].to_interval_set()));
+        assert!(precede.propagate(&mut store));
+        assert_eq!(store[1], vec![(0, 0), (3, 3)].to_interval_set());
+        assert_eq!(store[2], vec![(0, 1), (3, 3)].to_interval_set());
+    }
+
+    #[test]
+    fn value_precede_search_test() {
+        // Colorings of 4 nodes with at most 3 interchangeable colors, up to a permutation of the colors: the partitions of a set of 4 elements in at most 3 blocks.
+        let mut space = FDSpace::empty();
+        let xs: Vec<_> = (0..4)
+            .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+            .collect();
+        space
+            .cstore
+            .alloc(Box::new(ValuePrecede::chain(vec![0, 1, 2], xs)));
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        assert_eq!(statistics.num_solution, 14);
+        assert_eq!(statistics.num_failed_node, 0);
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Value-level operations on integer domains shared by the propagators achieving domain consistency.
+//!
+//! The values are enumerated from the lower bound of the domain, and `shrink_left` jumps over its holes, so the enumeration is linear in the number of values rather than in the width of the domain.
+//! The functions bounded by a number of values compare it to `size()` before enumerating anything.
+
+use concept::*;
+use num::{Num, NumCast};
+use std::mem;
+
+/// Values of `dom` in increasing order.
+pub fn domain_values<Domain, Bound>(dom: &Domain) -> Vec<Bound>
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    let mut values = vec![];
+    if !dom.is_empty() {
+        let upper = dom.upper();
+        let mut v = dom.lower();
+        loop {
+            values.push(v.clone());
+            if v == upper {
+                break;
+            }
+            // The smallest value of `dom` after `v`.
+            v = dom.shrink_left(v + Bound::one()).lower();
+        }
+    }
+    values
+}
+
+/// Number of values in `dom`, or `cap` if there are more.
+pub fn bounded_size<Domain, Bound>(dom: &Domain, cap: usize) -> usize
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    if dom.size() >= num_of_usize(cap) {
+        cap
+    } else {
+        domain_values(dom).len()
+    }
+}
+
+/// The integer `n` in a numeric type without conversion from `usize`, such as the `Size` of `Cardinality`, computed bit by bit.
+fn num_of_usize<N: Num>(n: usize) -> N {
+    (0..8 * mem::size_of::<usize>())
+        .rev()
+        .fold(N::zero(), |acc, bit| {
+            let digit = if (n >> bit) & 1 == 1 {
+                N::one()
+            } else {
+                N::zero()
+            };
+            acc * (N::one() + N::one()) + digit
+        })
+}
+
+/// Restrict `dom` to the values in `values` (sorted in increasing order).
+/// Domains that cannot represent holes, such as intervals, are only restricted to the bounds of `values`.
+pub fn restrict_to_values<Domain, Bound>(dom: Domain, values: &[Bound]) -> Domain
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    match (values.first(), values.last()) {
+        (Some(first), Some(last)) => {
+            let mut dom = dom.intersection(&Domain::new(first.clone(), last.clone()));
+            for w in values.windows(2) {
+                if w[1] > w[0].clone() + Bound::one() {
+                    let gap = Domain::new(w[0].clone() + Bound::one(), w[1].clone() - Bound::one());
+                    dom = dom.difference(&gap);
+                }
+            }
+            dom
+        }
+        _ => Domain::empty(),
+    }
+}
+
+/// Remove the values in `values` (sorted in increasing order) from `dom`.
+/// Domains that cannot represent holes, such as intervals, are only shrunk at their bounds.
+pub fn remove_values<Domain, Bound>(mut dom: Domain, values: &[Bound]) -> Domain
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    // The second pass removes the upper values of intervals that were not at the bound during the first pass.
+    for v in values.iter().chain(values.iter().rev()) {
+        dom = dom.difference(v);
+    }
+    dom
+}
+
+/// Whether all the values of `dom` are in `values` (sorted in increasing order).
+/// The values of `dom` are only enumerated if it has no more values than `values`.
+pub fn is_subset_of_values<Domain, Bound>(dom: &Domain, values: &[Bound]) -> bool
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    dom.size() <= num_of_usize(values.len())
+        && domain_values(dom)
+            .iter()
+            .all(|v| values.binary_search(v).is_ok())
+}
+
+/// The integer `n` as a bound.
+pub fn bound_of_usize<Bound>(n: usize) -> Bound
+where
+    Bound: IntBound,
+{
+    NumCast::from(n).expect("The integer cannot be represented as a bound.")
+}
+
+/// Smallest domain containing `values` (sorted in increasing order).
+pub fn from_values<Domain, Bound>(values: &[Bound]) -> Domain
+where
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    match (values.first(), values.last()) {
+        (Some(first), Some(last)) => {
+            restrict_to_values(Domain::new(first.clone(), last.clone()), values)
+        }
+        _ => Domain::empty(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+
+    #[test]
+    fn values_test() {
+        let set = vec![(1, 2), (5, 5), (7, 8)].to_interval_set();
+        assert_eq!(domain_values(&set), vec![1, 2, 5, 7, 8]);
+        assert_eq!(bounded_size(&set, 3), 3);
+        assert_eq!(bounded_size(&set, 10), 5);
+        assert_eq!(
+            restrict_to_values(set.clone(), &[2, 3, 7, 8, 9]),
+            vec![(2, 2), (7, 8)].to_interval_set()
+        );
+        assert_eq!(restrict_to_values(set.clone(), &[]), IntervalSet::empty());
+        assert_eq!(
+            from_values::<IntervalSet<isize>, _>(&[0, 2, 3]),
+            vec![(0, 0), (2, 3)].to_interval_set()
+        );
+        assert_eq!(
+            remove_values(set.clone(), &[2, 5, 6]),
+            vec![(1, 1), (7, 8)].to_interval_set()
+        );
+        assert!(is_subset_of_values(&set, &[1, 2, 3, 5, 7, 8]));
+        assert!(!is_subset_of_values(&set, &[1, 2, 5, 7]));
+        assert_eq!(bound_of_usize::<isize>(3), 3);
+        assert_eq!(num_of_usize::<usize>(1 << 40 | 5), 1 << 40 | 5);
+        // The holes are skipped without enumerating them.
+        let far = 1isize << 40;
+        let sparse = vec![(0, 0), (far, far)].to_interval_set();
+        assert_eq!(domain_values(&sparse), vec![0, far]);
+        assert_eq!(bounded_size(&sparse, 3), 2);
+        assert_eq!(bounded_size(&(0, far).to_interval(), 3), 3);
+        assert!(!is_subset_of_values(&(0, far).to_interval(), &[0, 1]));
+        let interval = (0, 10).to_interval();
+        assert_eq!(
+            restrict_to_values(interval, &[2, 5, 7]),
+            (2, 7).to_interval()
+        );
+        assert_eq!(
+            remove_values(interval, &[0, 1, 5, 9, 10]),
+            (2, 8).to_interval()
+        );
+    }
+}
@@ -100,6 +100,7 @@ mod test {
     use gcollections::ops::*;
     use gcollections::VectorStack;
     use interval::interval_set::*;
+    use propagators::linear::*;
     use search::branching::binary_split::*;
     use search::branching::brancher::*;
     use search::branching::first_smallest_var::*;
@@ -136,4 +137,32 @@ mod test {
         assert_eq!(status, EndOfSearch);
         assert_eq!(search.child.child.value, Some(expect));
     }
+
+    #[test]
+    fn knapsack_test() {
+        let mut space = FDSpace::empty();
+        let items: Vec<Var<VStore>> = (0..3)
+            .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+            .collect();
+        let weights = vec![3, 4, 5];
+        let profits = vec![4, 5, 6];
+        space.cstore.alloc(Box::new(Linear::new(
+            weights,
+            items.iter().map(|x| x.bclone()).collect(),
+            LinearRel::Leq,
+            10,
+        )));
+        let profit = Box::new(LinearExpr::new(profits, items)) as Var<VStore>;
+
+        let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+            AllSolution::new(OneSolution::new(BranchAndBound::new(
+                Mode::Maximize,
+                profit,
+                Propagation::new(Brancher::new(FirstSmallestVar, MiddleVal, BinarySplit)),
+            )));
+        search.start(&space);
+        let (_, status) = search.enter(space);
+        assert_eq!(status, EndOfSearch);
+        assert_eq!(search.child.child.value, Some(13));
+    }
 }
@@ -0,0 +1,265 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::ops::*;
+use num::ToPrimitive;
+use propagation::ModifiedVariables;
+use search::branching::learning::*;
+use search::branching::*;
+use search::propagation::*;
+use search::space::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use term::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+use variable::ops::Iterable;
+
+#[derive(Debug)]
+struct Activities<Bound> {
+    activities: Vec<f64>,
+    /// Number of variables modified by the propagation of each decision.
+    decisions: DecisionAverages<Bound>,
+    decay: f64,
+    /// Variable selected at the last branching.
+    var: usize,
+    /// Decision of the last branching, learned from the propagation of the next node.
+    decision: Option<(usize, Bound)>,
+}
+
+impl<Bound> Activities<Bound>
+where
+    Bound: Ord + Clone,
+{
+    /// Decays the activities and increments the ones of the variables `modified`, among `num_vars` variables.
+    fn bump(&mut self, modified: &[usize], num_vars: usize) {
+        self.activities.resize(num_vars, 0.0);
+        for activity in &mut self.activities {
+            *activity *= self.decay;
+        }
+        for &v in modified {
+            self.activities[v] += 1.0;
+        }
+    }
+
+    /// Measure of a decision: the number of variables it modified, or all the variables if it failed.
+    fn learn(&mut self, x: usize, a: Bound, status: SKleene, modified: &[usize], num_vars: usize) {
+        let measure = if status == False {
+            num_vars
+        } else {
+            modified.len()
+        };
+        self.decisions.add(x, a, measure as f64);
+    }
+}
+
+/// Activity-based search (Michel and Van Hentenryck, 2012).
+/// The activity of a variable is the number of times its domain was reduced by propagation, decayed by a factor `decay` at each node so that recent reductions weigh more.
+/// The selected variable maximizes the ratio between its activity and the size of its domain, and the selected value is the one whose decision modified the fewest variables on average (a failure counting as all the variables).
+///
+/// The activities are initialized at the root by probing each decision, and are then learned by the `PropagationObserver` implementation.
+/// The clones of an `ActivityBased` share their activities: a clone is given to the `Brancher` as variable and value selection, and another one to `Propagation::with_observer`.
+/// As for `ImpactBased`, a decision is learned from the next node being propagated, so the distributor must create the branch `x = a` first.
+#[derive(Debug)]
+pub struct ActivityBased<Bound> {
+    state: Rc<RefCell<Activities<Bound>>>,
+}
+
+impl<Bound> ActivityBased<Bound>
+where
+    Bound: Ord + Clone,
+{
+    /// Precondition: `0 < decay <= 1`, a usual value is `0.999`.
+    pub fn new(decay: f64) -> Self {
+        ActivityBased {
+            state: Rc::new(RefCell::new(Activities {
+                activities: vec![],
+                decisions: DecisionAverages::new(),
+                decay,
+                var: 0,
+                decision: None,
+            })),
+        }
+    }
+
+    /// Activity of the variable `x`.
+    pub fn activity(&self, x: usize) -> f64 {
+        self.state
+            .borrow()
+            .activities
+            .get(x)
+            .cloned()
+            .unwrap_or(0.0)
+    }
+
+    /// Average number of variables modified by `x = a`, 0 if it was never measured.
+    pub fn decision_activity(&self, x: usize, a: &Bound) -> f64 {
+        self.state.borrow().decisions.get(x, a).unwrap_or(0.0)
+    }
+}
+
+impl<Bound> Clone for ActivityBased<Bound> {
+    fn clone(&self) -> Self {
+        ActivityBased {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<VStore, CStore, R, Domain, Bound, Size> VarSelection<Space<VStore, CStore, R>>
+    for ActivityBased<Bound>
+where
+    VStore: Iterable<Item = Domain>,
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size>,
+    Bound: IntBound,
+    Size: ToPrimitive,
+{
+    fn select(&mut self, space: &Space<VStore, CStore, R>) -> usize {
+        let var = space
+            .vstore
+            .iter()
+            .enumerate()
+            .filter(|&(_, dom)| !dom.is_singleton())
+            .map(|(x, dom)| (x, self.activity(x) / dom.size().to_f64().unwrap()))
+            // The first variable with the greatest ratio.
+            .min_by(|a, b| b.1.partial_cmp(&a.1).unwrap())
+            .expect("Cannot select a variable in a space where all variables are assigned.")
+            .0;
+        self.state.borrow_mut().var = var;
+        var
+    }
+}
+
+impl<Domain, Bound, Size> ValSelection<Domain> for ActivityBased<Bound>
+where
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size>,
+    Bound: IntBound,
+    Size: ToPrimitive,
+{
+    fn select(&mut self, dom: Domain) -> Bound {
+        let x = self.state.borrow().var;
+        let a = sampled_values(&dom)
+            .into_iter()
+            .map(|a| (self.decision_activity(x, &a), a))
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .expect("Cannot select a value in an empty domain.")
+            .1;
+        self.state.borrow_mut().decision = Some((x, a.clone()));
+        a
+    }
+}
+
+impl<VStore, CStore, R, Domain, Bound, Size> PropagationObserver<Space<VStore, CStore, R>>
+    for ActivityBased<Bound>
+where
+    VStore: VStoreConcept<Item = Domain, Location = Identity<Domain>, Output = Domain>
+        + Clone
+        + 'static,
+    CStore: IntCStore<VStore> + ModifiedVariables,
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size> + 'static,
+    Bound: IntBound + 'static,
+    Size: ToPrimitive,
+{
+    fn start(&mut self, root: &Space<VStore, CStore, R>) {
+        let mut state = self.state.borrow_mut();
+        let num_vars = root.vstore.size();
+        state.activities = vec![0.0; num_vars];
+        state.decision = None;
+        probe(root, |x, a, status, _, probe| {
+            let modified = probe.cstore.modified_variables();
+            for &v in &modified {
+                state.activities[v] += 1.0;
+            }
+            state.learn(x, a, status, &modified, num_vars);
+        });
+    }
+
+    fn after_propagation(&mut self, space: &Space<VStore, CStore, R>, status: SKleene) {
+        let mut state = self.state.borrow_mut();
+        let num_vars = space.vstore.size();
+        let modified = space.cstore.modified_variables();
+        state.bump(&modified, num_vars);
+        if let Some((x, a)) = state.decision.take() {
+            state.learn(x, a, status, &modified, num_vars);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::VectorStack;
+    use interval::interval_set::*;
+    use search::branching::first_smallest_var::test::test_selector;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::statistics::*;
+    use search::test::*;
+    use search::*;
+
+    #[test]
+    fn activity_selection() {
+        let selector = ActivityBased::new(0.5);
+        // Without activities, the first unassigned variable is selected.
+        test_selector(selector.clone(), vec![(1, 1), (1, 10), (2, 4)], 1);
+        selector.state.borrow_mut().bump(&[0, 2], 3);
+        selector.state.borrow_mut().bump(&[2], 3);
+        assert_eq!(selector.activity(0), 0.5);
+        assert_eq!(selector.activity(1), 0.0);
+        assert_eq!(selector.activity(2), 1.5);
+        // `0.5 / 2` against `1.5 / 3`.
+        test_selector(selector.clone(), vec![(1, 2), (1, 10), (2, 4)], 2);
+        // `0.5 / 2` against `1.5 / 10`.
+        test_selector(selector.clone(), vec![(1, 2), (1, 10), (1, 10)], 0);
+
+        let mut val: ActivityBased<isize> = selector.clone();
+        selector.state.borrow_mut().var = 1;
+        selector.state.borrow_mut().learn(1, 3, Unknown, &[1, 2], 3);
+        selector.state.borrow_mut().learn(1, 4, Unknown, &[1], 3);
+        selector.state.borrow_mut().learn(1, 5, False, &[1], 3);
+        assert_eq!(ValSelection::select(&mut val, (3, 5).to_interval_set()), 4);
+        assert_eq!(selector.state.borrow().decision, Some((1, 4)));
+    }
+
+    #[test]
+    fn activity_nqueens() {
+        for &(n, expect) in &[(4, 2), (6, 4), (8, 92)] {
+            let mut space = FDSpace::empty();
+            nqueens(n, &mut space);
+            let activity_based = ActivityBased::new(0.999);
+            let mut statistics = Statistics::new();
+            {
+                let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                    AllSolution::new(OneSolution::new(Monitor::new(
+                        &mut statistics,
+                        Propagation::with_observer(
+                            activity_based.clone(),
+                            Brancher::new(
+                                activity_based.clone(),
+                                activity_based.clone(),
+                                Enumerate,
+                            ),
+                        ),
+                    )));
+                search.start(&space);
+                let (_, status) = search.enter(space);
+                assert_eq!(status, Status::EndOfSearch);
+            }
+            assert_eq!(statistics.num_solution, expect);
+            assert!((0..n).all(|x| activity_based.activity(x) > 0.0));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gcollections::ops::*;
+use kernel::*;
+use num::traits::Unsigned;
+use num::{Integer, ToPrimitive};
+use propagation::PropagatorIncidence;
+use search::branching::*;
+use search::monitor::*;
+use search::space::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use variable::ops::Iterable;
+
+/// Selects the variable minimizing the ratio between the size of its domain and its weighted degree (Boussemart et al., 2004).
+/// Each propagator has a weight, starting at 1, incremented each time it fails; the weighted degree of a variable is the sum of the weights of the active propagators depending on it and on at least one other unassigned variable.
+///
+/// The weights are learned by the `SearchMonitor` implementation, which must observe the failed nodes.
+/// The clones of a `DomWDeg` share their weights: a clone is given to the `Brancher` and another one to a `Monitor` placed above `Propagation`.
+#[derive(Clone, Debug, Default)]
+pub struct DomWDeg {
+    weights: Rc<RefCell<Vec<usize>>>,
+}
+
+impl DomWDeg {
+    pub fn new() -> Self {
+        DomWDeg::default()
+    }
+
+    /// Weight of the propagator `p_idx`.
+    pub fn weight(&self, p_idx: usize) -> usize {
+        self.weights.borrow().get(p_idx).cloned().unwrap_or(1)
+    }
+
+    fn increment(&mut self, p_idx: usize) {
+        let mut weights = self.weights.borrow_mut();
+        if weights.len() <= p_idx {
+            weights.resize(p_idx + 1, 1);
+        }
+        weights[p_idx] += 1;
+    }
+}
+
+impl<VStore, CStore, R, Domain, Size> VarSelection<Space<VStore, CStore, R>> for DomWDeg
+where
+    VStore: Iterable<Item = Domain> + Cardinality<Size = usize>,
+    CStore: PropagatorIncidence + Cardinality<Size = usize>,
+    Domain: Cardinality<Size = Size>,
+    Size: Ord + Unsigned + Integer + ToPrimitive,
+{
+    fn select(&mut self, space: &Space<VStore, CStore, R>) -> usize {
+        let incidence = space.cstore.incidence(space.vstore.size());
+        let unassigned: Vec<bool> = space
+            .vstore
+            .iter()
+            .map(|v| v.size() > Size::one())
+            .collect();
+        // Number of unassigned variables of each propagator.
+        let mut future = vec![0; space.cstore.size()];
+        for (x, props) in incidence.iter().enumerate() {
+            if unassigned[x] {
+                for &p in props {
+                    future[p] += 1;
+                }
+            }
+        }
+        let score = |(x, dom): (usize, &Domain)| {
+            let wdeg: usize = incidence[x]
+                .iter()
+                .filter(|&&p| future[p] > 1)
+                .map(|&p| self.weight(p))
+                .sum();
+            // A variable without constraint is selected last.
+            if wdeg == 0 {
+                (x, f64::INFINITY)
+            } else {
+                (x, dom.size().to_f64().unwrap() / wdeg as f64)
+            }
+        };
+        space
+            .vstore
+            .iter()
+            .enumerate()
+            .filter(|&(x, _)| unassigned[x])
+            .map(score)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .expect("Cannot select a variable in a space where all variables are assigned.")
+            .0
+    }
+}
+
+impl<VStore, CStore, R> SearchMonitor<Space<VStore, CStore, R>> for DomWDeg
+where
+    Space<VStore, CStore, R>: Freeze,
+    CStore: PropagatorIncidence,
+{
+    fn on_failure(&mut self, space: &Space<VStore, CStore, R>) {
+        if let Some(p_idx) = space.cstore.failed_propagator() {
+            self.increment(p_idx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use concept::*;
+    use gcollections::VectorStack;
+    use interval::interval_set::*;
+    use propagators::cmp::*;
+    use search::branching::first_smallest_var::test::test_selector;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::propagation::*;
+    use search::statistics::*;
+    use search::test::*;
+    use search::*;
+
+    #[test]
+    fn dom_wdeg_selection() {
+        // Without constraints, it behaves as `FirstSmallestVar` among the unassigned variables.
+        test_selector(DomWDeg::new(), vec![(1, 10), (2, 4), (1, 1)], 0);
+        test_selector(DomWDeg::new(), vec![(1, 1), (1, 10), (2, 4)], 1);
+
+        let mut space = FDSpace::empty();
+        let x = Box::new(space.vstore.alloc((1, 10).to_interval_set())) as Var<VStore>;
+        let y = Box::new(space.vstore.alloc((1, 4).to_interval_set())) as Var<VStore>;
+        let z = Box::new(space.vstore.alloc((1, 3).to_interval_set())) as Var<VStore>;
+        let w = Box::new(space.vstore.alloc((1, 1).to_interval_set())) as Var<VStore>;
+        space
+            .cstore
+            .alloc(Box::new(XLessY::new(x.bclone(), y.bclone())));
+        space.cstore.alloc(Box::new(XLessY::new(x.bclone(), z)));
+        space.cstore.alloc(Box::new(XLessY::new(x.bclone(), w)));
+        let mut dom_wdeg = DomWDeg::new();
+        // `x` has a weighted degree of 2 (the propagator with the assigned `w` does not count), `y` and `z` of 1.
+        assert_eq!(dom_wdeg.select(&space), 2);
+        // The propagator between `x` and `y` failed 6 times.
+        for _ in 0..6 {
+            dom_wdeg.increment(0);
+        }
+        assert_eq!(dom_wdeg.weight(0), 7);
+        assert_eq!(dom_wdeg.select(&space), 1);
+    }
+
+    #[test]
+    fn dom_wdeg_nqueens() {
+        for &(n, expect) in &[(4, 2), (6, 4), (8, 92)] {
+            let mut space = FDSpace::empty();
+            nqueens(n, &mut space);
+            let dom_wdeg = DomWDeg::new();
+            let mut learner = dom_wdeg.clone();
+            let mut statistics = Statistics::new();
+            {
+                let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                    AllSolution::new(OneSolution::new(Monitor::new(
+                        &mut statistics,
+                        Monitor::new(
+                            &mut learner,
+                            Propagation::new(Brancher::new(dom_wdeg.clone(), MinVal, Enumerate)),
+                        ),
+                    )));
+                search.start(&space);
+                let (_, status) = search.enter(space);
+                assert_eq!(status, Status::EndOfSearch);
+            }
+            assert_eq!(statistics.num_solution, expect);
+            // Each failed node increased the weight of one propagator.
+            let weights = dom_wdeg.weights.borrow();
+            let increments: usize = weights.iter().map(|w| w - 1).sum();
+            assert_eq!(increments, statistics.num_failed_node);
+        }
+    }
+}
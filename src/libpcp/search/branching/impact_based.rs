@@ -0,0 +1,268 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::ops::*;
+use num::ToPrimitive;
+use propagation::ModifiedVariables;
+use search::branching::learning::*;
+use search::branching::*;
+use search::propagation::*;
+use search::space::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use term::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+use variable::ops::Iterable;
+
+#[derive(Debug)]
+struct Impacts<Bound> {
+    impacts: DecisionAverages<Bound>,
+    /// Variable selected at the last branching.
+    var: usize,
+    /// Decision of the last branching, learned from the propagation of the next node.
+    decision: Option<(usize, Bound)>,
+    /// Sizes of the domains before the propagation of the current node.
+    before: Vec<f64>,
+}
+
+/// Impact-based search (Refalo, 2004).
+/// The impact of a decision `x = a` is `1 - P_after / P_before` where `P_before` and `P_after` are the sizes of the search space (the product of the domain sizes) before and after its propagation, averaged over the nodes where the decision was taken; a failure has an impact of 1.
+/// The selected variable minimizes the sum of `1 - impact(x = a)` over the values of its domain, which estimates the size of the search space left after branching on it, and the selected value has the smallest impact.
+///
+/// The impacts are initialized at the root by probing each decision, and are then learned by the `PropagationObserver` implementation.
+/// The values of a domain wider than `MAX_VALUES` are sampled, and such a variable is only selected, the smallest first, when all the unassigned variables are that wide.
+/// The clones of an `ImpactBased` share their impacts: a clone is given to the `Brancher` as variable and value selection, and another one to `Propagation::with_observer`.
+/// The decision is learned from the next node being propagated, which is its left branch in a depth-first exploration; the distributor must therefore create the branch `x = a` first (for example with `Enumerate`).
+#[derive(Debug)]
+pub struct ImpactBased<Bound> {
+    state: Rc<RefCell<Impacts<Bound>>>,
+}
+
+impl<Bound> ImpactBased<Bound>
+where
+    Bound: Ord + Clone,
+{
+    pub fn new() -> Self {
+        ImpactBased {
+            state: Rc::new(RefCell::new(Impacts {
+                impacts: DecisionAverages::new(),
+                var: 0,
+                decision: None,
+                before: vec![],
+            })),
+        }
+    }
+
+    /// Average impact of `x = a`, 0 if it was never measured.
+    pub fn impact(&self, x: usize, a: &Bound) -> f64 {
+        self.state.borrow().impacts.get(x, a).unwrap_or(0.0)
+    }
+
+    fn learn(&mut self, x: usize, a: Bound, impact: f64) {
+        self.state.borrow_mut().impacts.add(x, a, impact);
+    }
+}
+
+impl<Bound> Clone for ImpactBased<Bound> {
+    fn clone(&self) -> Self {
+        ImpactBased {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<Bound> Default for ImpactBased<Bound>
+where
+    Bound: Ord + Clone,
+{
+    fn default() -> Self {
+        ImpactBased::new()
+    }
+}
+
+/// Impact of a propagation, `before` being the sizes of the domains before it.
+fn impact<VStore, CStore, R, Domain, Size>(
+    status: SKleene,
+    before: &[f64],
+    space: &Space<VStore, CStore, R>,
+) -> f64
+where
+    VStore: VStoreConcept<Item = Domain>,
+    CStore: ModifiedVariables,
+    Domain: Cardinality<Size = Size>,
+    Size: ToPrimitive,
+{
+    if status == False {
+        1.0
+    } else {
+        1.0 - reduction(before, space)
+    }
+}
+
+impl<VStore, CStore, R, Domain, Bound, Size> VarSelection<Space<VStore, CStore, R>>
+    for ImpactBased<Bound>
+where
+    VStore: Iterable<Item = Domain>,
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size>,
+    Bound: IntBound,
+    Size: ToPrimitive,
+{
+    fn select(&mut self, space: &Space<VStore, CStore, R>) -> usize {
+        let var = space
+            .vstore
+            .iter()
+            .enumerate()
+            .filter(|&(_, dom)| !dom.is_singleton())
+            .map(|(x, dom)| {
+                let size = dom.size().to_f64().unwrap();
+                // The domains wider than `MAX_VALUES` are skipped, unless all of them are wide, in which case the smallest one is selected.
+                let estimate = if size > MAX_VALUES as f64 {
+                    (1.0, size)
+                } else {
+                    let remaining: f64 = sampled_values(dom)
+                        .iter()
+                        .map(|a| 1.0 - self.impact(x, a))
+                        .sum();
+                    (0.0, remaining)
+                };
+                (x, estimate)
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .expect("Cannot select a variable in a space where all variables are assigned.")
+            .0;
+        self.state.borrow_mut().var = var;
+        var
+    }
+}
+
+impl<Domain, Bound, Size> ValSelection<Domain> for ImpactBased<Bound>
+where
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size>,
+    Bound: IntBound,
+    Size: ToPrimitive,
+{
+    fn select(&mut self, dom: Domain) -> Bound {
+        let x = self.state.borrow().var;
+        let a = sampled_values(&dom)
+            .into_iter()
+            .map(|a| (self.impact(x, &a), a))
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .expect("Cannot select a value in an empty domain.")
+            .1;
+        self.state.borrow_mut().decision = Some((x, a.clone()));
+        a
+    }
+}
+
+impl<VStore, CStore, R, Domain, Bound, Size> PropagationObserver<Space<VStore, CStore, R>>
+    for ImpactBased<Bound>
+where
+    VStore: VStoreConcept<Item = Domain, Location = Identity<Domain>, Output = Domain>
+        + Clone
+        + 'static,
+    CStore: IntCStore<VStore> + ModifiedVariables,
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size> + 'static,
+    Bound: IntBound + 'static,
+    Size: ToPrimitive,
+{
+    fn start(&mut self, root: &Space<VStore, CStore, R>) {
+        let mut state = self.state.borrow_mut();
+        state.decision = None;
+        probe(root, |x, a, status, before, probe| {
+            state.impacts.add(x, a, impact(status, before, probe))
+        });
+    }
+
+    fn before_propagation(&mut self, space: &Space<VStore, CStore, R>) {
+        let mut state = self.state.borrow_mut();
+        if state.decision.is_some() {
+            state.before = domain_sizes(&space.vstore);
+        }
+    }
+
+    fn after_propagation(&mut self, space: &Space<VStore, CStore, R>, status: SKleene) {
+        let decision = self.state.borrow_mut().decision.take();
+        if let Some((x, a)) = decision {
+            let impact = impact(status, &self.state.borrow().before, space);
+            self.learn(x, a, impact);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::VectorStack;
+    use interval::interval_set::*;
+    use search::branching::first_smallest_var::test::test_selector;
+    use search::engine::all_solution::*;
+    use search::engine::one_solution::*;
+    use search::monitor::*;
+    use search::statistics::*;
+    use search::test::*;
+    use search::*;
+
+    #[test]
+    fn impact_selection() {
+        // Without impacts, it behaves as `FirstSmallestVar`.
+        test_selector(ImpactBased::new(), vec![(1, 10), (2, 4), (1, 1)], 1);
+
+        let mut selector = ImpactBased::new();
+        selector.learn(0, 3, 0.9);
+        selector.learn(0, 4, 0.8);
+        selector.learn(0, 4, 0.6);
+        assert!((selector.impact(0, &4) - 0.7).abs() < 1e-9);
+        // `0.1 + 0.3 + 1` for the first variable against `3` for the second.
+        test_selector(selector.clone(), vec![(2, 4), (2, 4)], 0);
+        let mut val: ImpactBased<isize> = selector.clone();
+        assert_eq!(ValSelection::select(&mut val, (2, 4).to_interval_set()), 2);
+        selector.learn(0, 2, 1.0);
+        assert_eq!(ValSelection::select(&mut val, (2, 4).to_interval_set()), 4);
+        // The wide domains are skipped.
+        test_selector(selector.clone(), vec![(0, 1 << 40), (2, 3), (2, 4)], 1);
+        test_selector(
+            selector.clone(),
+            vec![(0, 1 << 50), (0, 1 << 40), (1, 1)],
+            1,
+        );
+    }
+
+    #[test]
+    fn impact_nqueens() {
+        for &(n, expect) in &[(4, 2), (6, 4), (8, 92)] {
+            let mut space = FDSpace::empty();
+            nqueens(n, &mut space);
+            let impact_based = ImpactBased::new();
+            let mut statistics = Statistics::new();
+            {
+                let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                    AllSolution::new(OneSolution::new(Monitor::new(
+                        &mut statistics,
+                        Propagation::with_observer(
+                            impact_based.clone(),
+                            Brancher::new(impact_based.clone(), impact_based.clone(), Enumerate),
+                        ),
+                    )));
+                search.start(&space);
+                let (_, status) = search.enter(space);
+                assert_eq!(status, Status::EndOfSearch);
+            }
+            assert_eq!(statistics.num_solution, expect);
+            // The queens of the first row were probed at the root.
+            assert!(impact_based.impact(0, &1) > 0.0);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Components shared by the search heuristics learning from the domain reductions observed during propagation (`ActivityBased` and `ImpactBased`).
+
+use concept::*;
+use gcollections::ops::*;
+use num::ToPrimitive;
+use propagation::ModifiedVariables;
+use propagators::cmp::*;
+use propagators::values::{bound_of_usize, domain_values};
+use search::space::*;
+use std::collections::BTreeMap;
+use term::*;
+use trilean::SKleene;
+use trilean::SKleene::*;
+use variable::ops::Iterable;
+
+/// Size of the domain of each variable.
+pub fn domain_sizes<VStore, Domain, Size>(vstore: &VStore) -> Vec<f64>
+where
+    VStore: Iterable<Item = Domain>,
+    Domain: Cardinality<Size = Size>,
+    Size: ToPrimitive,
+{
+    vstore.iter().map(|d| d.size().to_f64().unwrap()).collect()
+}
+
+/// Maximal number of values of a domain probed at the root or compared during the search.
+/// The values of wider domains are sampled, otherwise the probing would not terminate in reasonable time on domains such as `[0, 10^9]`.
+pub const MAX_VALUES: usize = 64;
+
+/// Values of `dom` in increasing order, as given by `values::domain_values`, or at most `MAX_VALUES` of them evenly spread between its bounds if it has more values.
+pub fn sampled_values<Domain, Bound, Size>(dom: &Domain) -> Vec<Bound>
+where
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size>,
+    Bound: IntBound,
+    Size: ToPrimitive,
+{
+    if dom.is_empty() {
+        return vec![];
+    }
+    if dom.size().to_f64().unwrap() <= MAX_VALUES as f64 {
+        return domain_values(dom);
+    }
+    let (lower, upper) = (dom.lower(), dom.upper());
+    // Rounded up and computed without `upper - lower`, which can overflow.
+    let parts = bound_of_usize::<Bound>(MAX_VALUES - 1);
+    let step = upper.clone() / parts.clone() - lower.clone() / parts + Bound::one();
+    let mut values = vec![];
+    let mut value = lower;
+    loop {
+        values.push(value.clone());
+        if value > upper.clone() - step.clone() {
+            break;
+        }
+        // The smallest value of `dom` from `value + step`, which skips the holes.
+        value = dom.shrink_left(value + step.clone()).lower();
+    }
+    values
+}
+
+/// Ratio between the size of the search space of `space` and the one before its propagation, where `before` are the sizes of the domains before propagation.
+/// Only the variables modified by the last propagation are visited.
+pub fn reduction<VStore, CStore, R, Domain, Size>(
+    before: &[f64],
+    space: &Space<VStore, CStore, R>,
+) -> f64
+where
+    VStore: VStoreConcept<Item = Domain>,
+    CStore: ModifiedVariables,
+    Domain: Cardinality<Size = Size>,
+    Size: ToPrimitive,
+{
+    space
+        .cstore
+        .modified_variables()
+        .into_iter()
+        .map(|v| space.vstore[v].size().to_f64().unwrap() / before[v])
+        .product()
+}
+
+/// Probes the decisions `x = a` for each unassigned variable `x` of `root` and each value `a` of `sampled_values`, which samples the wide domains.
+/// Each decision is propagated on a copy of `root` (propagated beforehand), and `f(x, a, status, before, probe)` is called where `before` are the sizes of the domains of the propagated root and `probe` is the space after propagation.
+pub fn probe<VStore, CStore, R, Domain, Bound, Size, F>(root: &Space<VStore, CStore, R>, mut f: F)
+where
+    VStore: VStoreConcept<Item = Domain, Location = Identity<Domain>, Output = Domain>
+        + Clone
+        + 'static,
+    CStore: IntCStore<VStore>,
+    Domain: IntDomain<Item = Bound> + Cardinality<Size = Size> + 'static,
+    Bound: IntBound + 'static,
+    Size: ToPrimitive,
+    F: FnMut(usize, Bound, SKleene, &[f64], &Space<VStore, CStore, R>),
+{
+    let mut root: Space<VStore, CStore, R> = Space::new(root.vstore.clone(), root.cstore.clone());
+    if root.consistency() != Unknown {
+        return;
+    }
+    let before = domain_sizes(&root.vstore);
+    for x in 0..before.len() {
+        if before[x] <= 1.0 {
+            continue;
+        }
+        for a in sampled_values(&root.vstore[x]) {
+            let mut probe: Space<VStore, CStore, R> =
+                Space::new(root.vstore.clone(), root.cstore.clone());
+            let var = Box::new(Identity::<Domain>::new(x)) as Var<VStore>;
+            let val = Box::new(Constant::new(a.clone())) as Var<VStore>;
+            probe.cstore.alloc(Box::new(XEqY::new(var, val)));
+            let status = probe.consistency();
+            f(x, a, status, &before, &probe);
+        }
+    }
+}
+
+/// Running average of a measure taken on each decision `x = a`.
+#[derive(Clone, Debug)]
+pub struct DecisionAverages<Bound> {
+    averages: Vec<BTreeMap<Bound, (f64, usize)>>,
+}
+
+impl<Bound> DecisionAverages<Bound>
+where
+    Bound: Ord + Clone,
+{
+    pub fn new() -> Self {
+        DecisionAverages { averages: vec![] }
+    }
+
+    pub fn add(&mut self, x: usize, a: Bound, measure: f64) {
+        if self.averages.len() <= x {
+            self.averages.resize(x + 1, BTreeMap::new());
+        }
+        let entry = self.averages[x].entry(a).or_insert((0.0, 0));
+        entry.1 += 1;
+        entry.0 += (measure - entry.0) / entry.1 as f64;
+    }
+
+    /// Average of the measures of `x = a`, or `None` if it was never measured.
+    pub fn get(&self, x: usize, a: &Bound) -> Option<f64> {
+        self.averages
+            .get(x)
+            .and_then(|values| values.get(a))
+            .map(|&(average, _)| average)
+    }
+}
+
+impl<Bound> Default for DecisionAverages<Bound>
+where
+    Bound: Ord + Clone,
+{
+    fn default() -> Self {
+        DecisionAverages::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use interval::interval_set::*;
+    use search::test::*;
+    use search::*;
+
+    #[test]
+    fn sampled_values_test() {
+        let dom = vec![(1, 3), (6, 7)].to_interval_set();
+        assert_eq!(sampled_values(&dom), vec![1, 2, 3, 6, 7]);
+        let dom = vec![(0, 10), (1isize << 40, 1 << 41)].to_interval_set();
+        let values = sampled_values(&dom);
+        assert!(values.len() <= MAX_VALUES);
+        assert_eq!(values[0], 0);
+        assert!(values.iter().all(|v| dom.contains(v)));
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        assert!(*values.last().unwrap() > 3isize << 39);
+    }
+
+    #[test]
+    fn averages_test() {
+        let mut averages = DecisionAverages::new();
+        averages.add(2, 5, 1.0);
+        averages.add(2, 5, 0.5);
+        averages.add(2, 5, 0.0);
+        assert_eq!(averages.get(2, &5), Some(0.5));
+        assert_eq!(averages.get(2, &4), None);
+        assert_eq!(averages.get(0, &5), None);
+    }
+
+    #[test]
+    fn probe_nqueens_test() {
+        let mut space = FDSpace::empty();
+        nqueens(4, &mut space);
+        let mut probes = 0;
+        let mut failures = 0;
+        probe(&space, |x, _, status, before, probe| {
+            probes += 1;
+            if status == False {
+                failures += 1;
+            } else {
+                assert!(reduction(before, probe) <= 0.25);
+                assert!(probe.cstore.modified_variables().contains(&x));
+            }
+        });
+        assert_eq!(probes, 16);
+        // The propagation fails when a queen of the two inner rows is in one of the two middle columns.
+        assert_eq!(failures, 4);
+    }
+
+    #[test]
+    fn probe_wide_domain_test() {
+        let mut space = FDSpace::empty();
+        let x = Box::new(space.vstore.alloc((0, 1isize << 40).to_interval_set())) as Var<VStore>;
+        let y = Box::new(space.vstore.alloc((0, 1isize << 40).to_interval_set())) as Var<VStore>;
+        space.cstore.alloc(Box::new(XNeqY::new(x, y)));
+        let mut probes = 0;
+        probe(&space, |_, _, _, _, _| probes += 1);
+        assert!(probes <= 2 * MAX_VALUES);
+    }
+}
@@ -12,19 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod activity_based;
 pub mod branch;
 pub mod brancher;
+pub mod dom_wdeg;
 pub mod first_smallest_var;
+pub mod impact_based;
 pub mod input_order;
+pub mod learning;
 pub mod middle_val;
 pub mod min_val;
 pub mod binary_split;
 pub mod enumerate;
 
+pub use search::branching::activity_based::*;
 pub use search::branching::binary_split::*;
 pub use search::branching::enumerate::*;
 pub use search::branching::brancher::*;
+pub use search::branching::dom_wdeg::*;
 pub use search::branching::first_smallest_var::*;
+pub use search::branching::impact_based::*;
 pub use search::branching::input_order::*;
 pub use search::branching::middle_val::*;
 pub use search::branching::min_val::*;
@@ -0,0 +1,256 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `LDS` combinator explores the tree by iterations, such that the `k`-th iteration only explores the branches deviating from the leftmost branch of the distributor (a *discrepancy*) at most `k` times (Harvey and Ginsberg, 1995). It is useful when the heuristic is good, since the solutions close to it are found first.
+//! In the depth-bounded variant (Walsh, 1997), the `k`-th iteration allows discrepancies at the depths smaller than `k` and forces a discrepancy at the depth `k - 1`, so the nodes deeper than `k - 1` were not visited by the previous iterations.
+//! As with `OneSolution`, `enter` returns each time a solution is found, and it returns `EndOfSearch` once an iteration explored the tree entirely. A solution is only returned once: the iteration `k` returns the solutions with exactly `k` discrepancies, or deeper than `k - 1` in the depth-bounded variant.
+
+use kernel::*;
+use search::search_tree_visitor::*;
+use search::search_tree_visitor::Status::*;
+use search::branching::branch::*;
+use gcollections::ops::multiset::*;
+use gcollections::*;
+use std::marker::PhantomData;
+
+/// How the discrepancies of an iteration are bounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Discrepancy {
+  /// At most `k` discrepancies on each path.
+  Limited,
+  /// Discrepancies only at the depths smaller than `k`.
+  DepthBounded
+}
+
+/// A branch with the number of discrepancies on its path from the root and the depth of the node it leads to.
+pub struct DiscrepancyBranch<Space> where
+ Space: Freeze
+{
+  branch: Branch<Space>,
+  discrepancies: usize,
+  depth: usize
+}
+
+pub struct LDS<C, Q, Space> where
+ Space: Freeze
+{
+  pub child: C,
+  /// Limit of the current iteration.
+  pub limit: usize,
+  discrepancy: Discrepancy,
+  queue: Q,
+  root: Option<Branch<Space>>,
+  /// `true` if a branch was not explored because of the limit in the current iteration.
+  cut: bool,
+  started_exploration: bool,
+  phantom_space: PhantomData<Space>
+}
+
+impl<C, Q, Space> LDS<C, Q, Space> where
+ Space: Freeze,
+ C: SearchTreeVisitor<Space>,
+ Q: Multiset + Collection<Item=DiscrepancyBranch<Space>>
+{
+  pub fn new(child: C) -> LDS<C, Q, Space> {
+    LDS::with_discrepancy(Discrepancy::Limited, child)
+  }
+
+  pub fn depth_bounded(child: C) -> LDS<C, Q, Space> {
+    LDS::with_discrepancy(Discrepancy::DepthBounded, child)
+  }
+
+  pub fn with_discrepancy(discrepancy: Discrepancy, child: C) -> LDS<C, Q, Space> {
+    LDS {
+      child,
+      limit: 0,
+      discrepancy,
+      queue: Q::empty(),
+      root: None,
+      cut: false,
+      started_exploration: false,
+      phantom_space: PhantomData
+    }
+  }
+
+  /// `true` if the `i`-th branch of a node at `depth` is explored in the current iteration, where `discrepancies` are the ones of the branch path.
+  fn in_limit(&self, i: usize, discrepancies: usize, depth: usize) -> bool {
+    match self.discrepancy {
+      Discrepancy::Limited => discrepancies <= self.limit,
+      Discrepancy::DepthBounded =>
+        if depth + 1 < self.limit { true }
+        else if depth + 1 == self.limit { i > 0 }
+        else { i == 0 }
+    }
+  }
+
+  /// `true` if a solution at `depth` was not returned by the previous iterations.
+  fn is_new(&self, discrepancies: usize, depth: usize) -> bool {
+    match self.discrepancy {
+      Discrepancy::Limited => discrepancies == self.limit,
+      // The nodes deeper than `limit - 1` have a discrepancy at this depth, the others were visited before.
+      Discrepancy::DepthBounded => self.limit == 0 || depth >= self.limit
+    }
+  }
+
+  fn push_branches(&mut self, branches: Vec<Branch<Space>>, discrepancies: usize, depth: usize)
+  {
+    // For traversing the tree from left to right.
+    for (i, branch) in branches.into_iter().enumerate().rev() {
+      let discrepancies = discrepancies + if i > 0 { 1 } else { 0 };
+      if self.in_limit(i, discrepancies, depth) {
+        self.queue.insert(DiscrepancyBranch { branch, discrepancies, depth: depth + 1 });
+      }
+      // With `DepthBounded`, the leftmost branch at the depth `limit - 1` was explored by the previous iteration.
+      else if i > 0 {
+        self.cut = true;
+      }
+    }
+  }
+
+  fn enter_child(&mut self, current: Space, discrepancies: usize, depth: usize, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    let (immutable_state, child_status) = self.child.enter(current);
+    match child_status {
+      Unknown(ref branches) if branches.is_empty() => *status = Status::pruned(),
+      Unknown(branches) => self.push_branches(branches, discrepancies, depth),
+      Satisfiable if self.is_new(discrepancies, depth) => *status = Satisfiable,
+      EndOfSearch => *status = EndOfSearch,
+      _ => ()
+    }
+    immutable_state
+  }
+
+  /// Explores the tree from `root` with the current limit; `root` is kept in a branch without alternative for the next iteration.
+  fn enter_root(&mut self, root: Space, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    let (immutable_state, mut branches) = Branch::distribute(root,
+      vec![Box::new(|_: &mut Space| ()) as Box<dyn Fn(&mut Space)>, Box::new(|_: &mut Space| ())]);
+    self.root = branches.pop();
+    let root = branches.pop().unwrap().commit(immutable_state);
+    self.queue = Q::empty();
+    self.cut = false;
+    self.enter_child(root, 0, 0, status)
+  }
+
+  fn next_iteration(&mut self, immutable_state: Space::FrozenState, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    self.limit += 1;
+    let root = self.root.take().unwrap().commit(immutable_state);
+    self.enter_root(root, status)
+  }
+}
+
+impl<C, Q, Space> SearchTreeVisitor<Space> for LDS<C, Q, Space> where
+ Space: Freeze,
+ C: SearchTreeVisitor<Space>,
+ Q: Multiset + Collection<Item=DiscrepancyBranch<Space>>
+{
+  fn start(&mut self, root: &Space) {
+    self.queue = Q::empty();
+    self.root = None;
+    self.limit = 0;
+    self.started_exploration = false;
+    self.child.start(root);
+  }
+
+  fn enter(&mut self, root: Space) -> (Space::FrozenState, Status<Space>) {
+    let mut status = Unsatisfiable;
+    let mut immutable_state =
+      if !self.started_exploration {
+        self.started_exploration = true;
+        self.enter_root(root, &mut status)
+      }
+      else {
+        root.freeze()
+      };
+    while status != EndOfSearch && status != Satisfiable {
+      if !self.queue.is_empty() {
+        let node = self.queue.extract().unwrap();
+        let child = node.branch.commit(immutable_state);
+        immutable_state = self.enter_child(child, node.discrepancies, node.depth, &mut status);
+      }
+      else if self.cut {
+        immutable_state = self.next_iteration(immutable_state, &mut status);
+      }
+      else {
+        status = EndOfSearch;
+      }
+    }
+    (immutable_state, status)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use search::test::*;
+  use search::FDSpace;
+  use search::monitor::*;
+  use search::statistics::*;
+  use search::engine::all_solution::*;
+  use search::propagation::*;
+  use search::branching::*;
+  use gcollections::VectorStack;
+  use gcollections::ops::*;
+  use variable::ops::Iterable;
+
+  #[test]
+  fn lds_nqueens() {
+    // Data from Wikipedia.
+    let nqueens_solution = vec![1, 0, 0, 2, 10, 4, 40];
+    for (n, sol) in nqueens_solution.into_iter().enumerate() {
+      test_nqueens(n + 1, sol, Discrepancy::Limited);
+      test_nqueens(n + 1, sol, Discrepancy::DepthBounded);
+    }
+  }
+
+  fn test_nqueens(n: usize, sol_expected: usize, discrepancy: Discrepancy) {
+    let mut space = FDSpace::empty();
+    nqueens(n, &mut space);
+    let mut statistics = Statistics::new();
+    {
+      // The monitor is above `LDS` to only count the solutions it returns.
+      let mut search: AllSolution<Monitor<Statistics, LDS<_, VectorStack<_>, FDSpace>>> =
+        AllSolution::new(Monitor::new(&mut statistics, LDS::with_discrepancy(discrepancy,
+          Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)))));
+      search.start(&space);
+      let (_, status) = search.enter(space);
+      assert_eq!(status, EndOfSearch);
+    }
+    assert_eq!(statistics.num_solution, sol_expected);
+  }
+
+  #[test]
+  fn lds_first_solution() {
+    // With `MinVal` and `Enumerate`, the leftmost path assigns the queens to the smallest column consistent with the previous ones.
+    // On 5 queens it leads to the solution `[1, 3, 5, 2, 4]` without discrepancy.
+    for &discrepancy in &[Discrepancy::Limited, Discrepancy::DepthBounded] {
+      let mut space = FDSpace::empty();
+      nqueens(5, &mut space);
+      let mut search: LDS<_, VectorStack<_>, FDSpace> =
+        LDS::with_discrepancy(discrepancy, Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)));
+      search.start(&space);
+      let (frozen_space, status) = search.enter(space);
+      assert_eq!(status, Satisfiable);
+      assert_eq!(search.limit, 0);
+      let space = frozen_space.unfreeze();
+      let queens: Vec<_> = space.vstore.iter().map(|dom| dom.lower()).collect();
+      assert_eq!(queens, vec![1, 3, 5, 2, 4]);
+      // The iteration `1` explores a single discrepancy.
+      let (_, status) = search.enter(space);
+      assert_eq!(status, Satisfiable);
+      assert_eq!(search.limit, 1);
+    }
+  }
+}
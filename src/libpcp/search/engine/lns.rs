@@ -0,0 +1,305 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `LNS` combinator (large neighbourhood search, Shaw 1998) improves a solution by iterations instead of proving its optimality.
+//! Each iteration restarts from the root where the variables chosen by a `Neighbourhood` are frozen to their value in the best solution found so far, and the objective must be improved. The child searches this smaller problem, and should be limited with `StopNode` so an iteration does not last too long, for example `OneSolution(StopNode(Propagation(Brancher)))`.
+//! The first iteration searches for a first solution without freezing any variable. As with `OneSolution`, `enter` returns each time a better solution is found, and it returns `EndOfSearch` once the number of iterations is reached.
+
+use concept::*;
+use gcollections::*;
+use kernel::*;
+use propagators::cmp::*;
+use search::branch_and_bound::Mode;
+use search::search_tree_visitor::*;
+use search::search_tree_visitor::Status::*;
+use search::branching::branch::*;
+use search::space::*;
+use term::*;
+
+/// Chooses the variables to freeze at each iteration.
+pub trait Neighbourhood<Bound> {
+  /// Returns the indexes of the variables frozen to their value in `solution`, the best solution found so far.
+  fn freeze(&mut self, solution: &[Bound]) -> Vec<usize>;
+}
+
+impl<Bound, F> Neighbourhood<Bound> for F where
+ F: FnMut(&[Bound]) -> Vec<usize>
+{
+  fn freeze(&mut self, solution: &[Bound]) -> Vec<usize> {
+    self(solution)
+  }
+}
+
+/// Freezes all the variables but `relaxed` of them, chosen uniformly at random.
+/// The pseudo-random generator is xorshift64* (Vigna, 2016), so an LNS seeded with the same `seed` is reproducible.
+pub struct RandomNeighbourhood {
+  relaxed: usize,
+  state: u64
+}
+
+impl RandomNeighbourhood {
+  pub fn new(relaxed: usize, seed: u64) -> Self {
+    RandomNeighbourhood {
+      relaxed,
+      // The state of xorshift must not be zero.
+      state: seed.max(1)
+    }
+  }
+
+  /// Random number in `0..n`.
+  fn next(&mut self, n: usize) -> usize {
+    self.state ^= self.state >> 12;
+    self.state ^= self.state << 25;
+    self.state ^= self.state >> 27;
+    (self.state.wrapping_mul(0x2545_f491_4f6c_dd1d) % (n as u64)) as usize
+  }
+}
+
+impl<Bound> Neighbourhood<Bound> for RandomNeighbourhood {
+  fn freeze(&mut self, solution: &[Bound]) -> Vec<usize> {
+    let n = solution.len();
+    let mut vars: Vec<usize> = (0..n).collect();
+    // The relaxed variables are moved to the end by a partial Fisher-Yates shuffle.
+    for i in 0..self.relaxed.min(n) {
+      let j = self.next(n - i);
+      vars.swap(j, n - i - 1);
+    }
+    vars.truncate(n - self.relaxed.min(n));
+    vars
+  }
+}
+
+pub struct LNS<VStore, N, C, Space> where
+ VStore: VStoreConcept,
+ VStore::Item: Collection,
+ Space: Freeze
+{
+  pub mode: Mode,
+  /// The objective variable.
+  pub var: Var<VStore>,
+  /// Value of the objective in the best solution.
+  pub value: Option<<VStore::Item as Collection>::Item>,
+  /// Values of `vars` in the best solution.
+  pub solution: Vec<<VStore::Item as Collection>::Item>,
+  pub num_iterations: usize,
+  pub child: C,
+  vars: Vec<Var<VStore>>,
+  neighbourhood: N,
+  iterations: usize,
+  root: Option<Branch<Space>>
+}
+
+impl<VStore, N, C, Space> LNS<VStore, N, C, Space> where
+ VStore: VStoreConcept,
+ VStore::Item: Collection,
+ Space: Freeze
+{
+  /// `vars` are the variables that can be frozen by `neighbourhood`, and `iterations` is the number of sub-searches.
+  pub fn new(mode: Mode, var: Var<VStore>, vars: Vec<Var<VStore>>, neighbourhood: N, iterations: usize, child: C) -> Self {
+    LNS {
+      mode,
+      var,
+      value: None,
+      solution: vec![],
+      num_iterations: 0,
+      child,
+      vars,
+      neighbourhood,
+      iterations,
+      root: None
+    }
+  }
+}
+
+impl<VStore, N, C, CStore, R, Dom, Bound> LNS<VStore, N, C, Space<VStore, CStore, R>> where
+ VStore: VStoreConcept<Item=Dom> + 'static,
+ CStore: IntCStore<VStore>,
+ Dom: IntDomain<Item=Bound> + 'static,
+ Bound: IntBound + 'static,
+ N: Neighbourhood<Bound>,
+ R: FreezeSpace<VStore, CStore> + Snapshot<State=Space<VStore, CStore, R>>
+{
+  /// Keeps `root` in a branch without alternative, to restart from it at each iteration.
+  fn save_root(&mut self, root: Space<VStore, CStore, R>) -> Space<VStore, CStore, R> {
+    let (immutable_state, mut branches) = Branch::distribute(root,
+      vec![Box::new(|_: &mut Space<VStore, CStore, R>| ()) as Box<dyn Fn(&mut Space<VStore, CStore, R>)>,
+        Box::new(|_: &mut Space<VStore, CStore, R>| ())]);
+    self.root = branches.pop();
+    branches.pop().unwrap().commit(immutable_state)
+  }
+
+  fn restore_root(&mut self, immutable_state: R) -> Space<VStore, CStore, R> {
+    let root = self.root.take().unwrap().commit(immutable_state);
+    self.save_root(root)
+  }
+
+  /// Freezes the variables of the neighbourhood and constrains the objective to be better than `value`.
+  fn neighbour(&mut self, space: &mut Space<VStore, CStore, R>, value: Bound) {
+    for x in self.neighbourhood.freeze(&self.solution) {
+      let v = Box::new(Constant::new(self.solution[x].clone())) as Var<VStore>;
+      space.cstore.alloc(Box::new(XEqY::new(self.vars[x].bclone(), v)));
+    }
+    let bound = Box::new(Constant::new(value)) as Var<VStore>;
+    match self.mode {
+      Mode::Minimize => space.cstore.alloc(Box::new(XLessY::new(self.var.bclone(), bound))),
+      Mode::Maximize => space.cstore.alloc(Box::new(x_greater_y(self.var.bclone(), bound)))
+    };
+  }
+
+  fn update_solution(&mut self, space: &Space<VStore, CStore, R>) {
+    self.value = Some(self.var.read(&space.vstore).lower());
+    self.solution = self.vars.iter().map(|x| x.read(&space.vstore).lower()).collect();
+  }
+}
+
+impl<VStore, N, C, CStore, R, Dom, Bound> SearchTreeVisitor<Space<VStore, CStore, R>>
+  for LNS<VStore, N, C, Space<VStore, CStore, R>> where
+ VStore: VStoreConcept<Item=Dom> + 'static,
+ CStore: IntCStore<VStore>,
+ Dom: IntDomain<Item=Bound> + 'static,
+ Bound: IntBound + 'static,
+ N: Neighbourhood<Bound>,
+ C: SearchTreeVisitor<Space<VStore, CStore, R>>,
+ R: FreezeSpace<VStore, CStore> + Snapshot<State=Space<VStore, CStore, R>>
+{
+  // The child is started at each iteration, from the neighbourhood.
+  fn start(&mut self, _root: &Space<VStore, CStore, R>) {
+    self.value = None;
+    self.solution = vec![];
+    self.num_iterations = 0;
+    self.root = None;
+  }
+
+  fn enter(&mut self, current: Space<VStore, CStore, R>)
+    -> (<Space<VStore, CStore, R> as Freeze>::FrozenState, Status<Space<VStore, CStore, R>>)
+  {
+    let mut space =
+      if self.root.is_none() { self.save_root(current) }
+      else { self.restore_root(current.freeze()) };
+    while self.num_iterations < self.iterations {
+      self.num_iterations += 1;
+      if let Some(value) = self.value.clone() {
+        self.neighbour(&mut space, value);
+      }
+      self.child.start(&space);
+      let (immutable_state, status) = self.child.enter(space);
+      if status == Satisfiable {
+        let solution = immutable_state.unfreeze();
+        self.update_solution(&solution);
+        return (solution.freeze(), Satisfiable);
+      }
+      space = self.restore_root(immutable_state);
+    }
+    (space.freeze(), EndOfSearch)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use search::FDSpace;
+  use search::VStore;
+  use search::engine::one_solution::*;
+  use search::stop_node::*;
+  use search::propagation::*;
+  use search::branching::*;
+  use propagators::linear::*;
+  use term::LinearExpr;
+  use interval::interval_set::*;
+  use gcollections::VectorStack;
+  use gcollections::ops::*;
+
+  #[test]
+  fn random_neighbourhood_test() {
+    let solution = vec![0; 10];
+    let mut neighbourhood = RandomNeighbourhood::new(3, 42);
+    let mut frozen = Neighbourhood::<i32>::freeze(&mut neighbourhood, &solution);
+    assert_eq!(frozen.len(), 7);
+    frozen.sort();
+    frozen.dedup();
+    assert_eq!(frozen.len(), 7);
+    assert!(frozen.iter().all(|&x| x < 10));
+    // The same seed gives the same neighbourhoods.
+    let mut first = RandomNeighbourhood::new(3, 7);
+    let mut second = RandomNeighbourhood::new(3, 7);
+    for _ in 0..5 {
+      assert_eq!(Neighbourhood::<i32>::freeze(&mut first, &solution),
+        Neighbourhood::<i32>::freeze(&mut second, &solution));
+    }
+    // Relaxing more variables than available frees them all.
+    let mut neighbourhood = RandomNeighbourhood::new(20, 42);
+    assert!(Neighbourhood::<i32>::freeze(&mut neighbourhood, &solution).is_empty());
+  }
+
+  fn knapsack(space: &mut FDSpace) -> (Vec<Var<VStore>>, Var<VStore>) {
+    let items: Vec<Var<VStore>> = (0..3)
+      .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+      .collect();
+    space.cstore.alloc(Box::new(Linear::new(
+      vec![3, 4, 5],
+      items.iter().map(|x| x.bclone()).collect(),
+      LinearRel::Leq,
+      10)));
+    let profit = Box::new(LinearExpr::new(vec![4, 5, 6], items.iter().map(|x| x.bclone()).collect())) as Var<VStore>;
+    (items, profit)
+  }
+
+  type SubSearch = OneSolution<StopNode<Propagation<Brancher<InputOrder, MinVal, Enumerate>>>, VectorStack<Branch<FDSpace>>, FDSpace>;
+
+  /// Calls `search` until the end of the search and returns the number of solutions.
+  fn solve<N>(search: &mut LNS<VStore, N, SubSearch, FDSpace>, space: FDSpace) -> usize where
+   N: Neighbourhood<isize>
+  {
+    search.start(&space);
+    let mut num_solutions = 0;
+    let (mut immutable_state, mut status) = search.enter(space);
+    while status != EndOfSearch {
+      assert_eq!(status, Satisfiable);
+      num_solutions += 1;
+      let result = search.enter(immutable_state.unfreeze());
+      immutable_state = result.0;
+      status = result.1;
+    }
+    num_solutions
+  }
+
+  fn sub_search(limit: usize) -> SubSearch {
+    OneSolution::new(StopNode::new(limit, Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate))))
+  }
+
+  #[test]
+  fn lns_knapsack_test() {
+    let mut space = FDSpace::empty();
+    let (items, profit) = knapsack(&mut space);
+    let mut search = LNS::new(Mode::Maximize, profit, items, RandomNeighbourhood::new(2, 3), 30, sub_search(20));
+    let num_solutions = solve(&mut search, space);
+    assert_eq!(search.num_iterations, 30);
+    assert_eq!(search.value, Some(13));
+    assert_eq!(search.solution, vec![2, 1, 0]);
+    // Each solution improves the previous one, the first being `[0, 0, 0]`.
+    assert!(num_solutions >= 2);
+  }
+
+  #[test]
+  fn lns_user_neighbourhood_test() {
+    let mut space = FDSpace::empty();
+    let (items, profit) = knapsack(&mut space);
+    // The first item is always frozen, so the optimum `13` (with `[2, 1, 0]`) is not reachable from `[0, 0, 0]`.
+    let first_item = |_: &[isize]| vec![0];
+    let mut search = LNS::new(Mode::Maximize, profit, items, first_item, 10, sub_search(100));
+    solve(&mut search, space);
+    assert_eq!(search.value, Some(12));
+    assert_eq!(search.solution, vec![0, 0, 2]);
+  }
+}
@@ -14,3 +14,6 @@
 
 pub mod one_solution;
 pub mod all_solution;
+pub mod restart;
+pub mod lds;
+pub mod lns;
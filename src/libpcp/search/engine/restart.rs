@@ -0,0 +1,383 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `Restart` combinator explores the tree as `OneSolution` does, but abandons it once a number of failed (or explored) nodes is reached, and starts again from the root. The successive cutoffs are given by a `RestartPolicy`.
+//! The child visitor is kept across restarts, so a `BranchAndBound` child keeps its best bound, and randomized or learning heuristics explore a different tree after each restart.
+//! The search is complete if the cutoffs grow without bound (as with `Luby` and `Geometric`): `EndOfSearch` is returned once a tree is fully explored before reaching its cutoff.
+
+use kernel::*;
+use search::search_tree_visitor::*;
+use search::search_tree_visitor::Status::*;
+use search::branching::branch::*;
+use gcollections::ops::multiset::*;
+use gcollections::*;
+use std::marker::PhantomData;
+
+/// Gives the cutoff of each run of the search.
+/// A cutoff of `0` restarts before exploring anything, so the cutoffs must be positive.
+pub trait RestartPolicy {
+  fn next_cutoff(&mut self) -> usize;
+  /// Goes back to the cutoff of the first run, for a new search.
+  fn reset(&mut self);
+}
+
+/// The same cutoff for every run.
+/// The search is incomplete: if no solution can be found within `cutoff` nodes, it restarts forever instead of returning `EndOfSearch`.
+pub struct Fixed {
+  cutoff: usize
+}
+
+impl Fixed {
+  /// Precondition: `cutoff > 0`.
+  pub fn new(cutoff: usize) -> Self {
+    assert!(cutoff > 0, "The cutoff of `Fixed` must be positive.");
+    Fixed {
+      cutoff
+    }
+  }
+}
+
+impl RestartPolicy for Fixed {
+  fn next_cutoff(&mut self) -> usize {
+    self.cutoff
+  }
+
+  fn reset(&mut self) {}
+}
+
+/// The cutoff of the run `i` is `scale * factor^i` (rounded down).
+pub struct Geometric {
+  scale: usize,
+  cutoff: f64,
+  factor: f64
+}
+
+impl Geometric {
+  /// Precondition: `scale > 0` and `factor > 1`.
+  pub fn new(scale: usize, factor: f64) -> Self {
+    assert!(scale > 0, "The scale of `Geometric` must be positive.");
+    assert!(factor > 1.0, "The factor of `Geometric` must be greater than 1.");
+    Geometric {
+      scale,
+      cutoff: scale as f64,
+      factor
+    }
+  }
+}
+
+impl RestartPolicy for Geometric {
+  fn next_cutoff(&mut self) -> usize {
+    let cutoff = self.cutoff as usize;
+    self.cutoff *= self.factor;
+    cutoff
+  }
+
+  fn reset(&mut self) {
+    self.cutoff = self.scale as f64;
+  }
+}
+
+/// The cutoff of the run `i` is `scale * luby(i)` where `luby` is the sequence 1, 1, 2, 1, 1, 2, 4, 1, 1, 2, ... of Luby, Sinclair and Zuckerman (1993).
+pub struct Luby {
+  scale: usize,
+  run: usize
+}
+
+impl Luby {
+  /// Precondition: `scale > 0`.
+  pub fn new(scale: usize) -> Self {
+    assert!(scale > 0, "The scale of `Luby` must be positive.");
+    Luby {
+      scale,
+      run: 0
+    }
+  }
+}
+
+/// The `i`-th term of the Luby sequence, starting at `i = 1`.
+fn luby(mut i: usize) -> usize {
+  loop {
+    let mut k = 1;
+    while (1 << k) - 1 < i {
+      k += 1;
+    }
+    if (1 << k) - 1 == i {
+      return 1 << (k - 1);
+    }
+    // The sequence up to `2^k - 1` repeats the sequence up to `2^(k-1) - 1` before its last term.
+    i -= (1 << (k - 1)) - 1;
+  }
+}
+
+impl RestartPolicy for Luby {
+  fn next_cutoff(&mut self) -> usize {
+    self.run += 1;
+    self.scale * luby(self.run)
+  }
+
+  fn reset(&mut self) {
+    self.run = 0;
+  }
+}
+
+/// The nodes counted to reach the cutoff.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cutoff {
+  Failures,
+  Nodes
+}
+
+pub struct Restart<C, Q, Space, P> where
+ Space: Freeze
+{
+  pub child: C,
+  pub num_restarts: usize,
+  queue: Q,
+  policy: P,
+  cutoff: Cutoff,
+  limit: usize,
+  count: usize,
+  root: Option<Branch<Space>>,
+  started_exploration: bool,
+  phantom_space: PhantomData<Space>
+}
+
+impl<C, Q, Space, P> Restart<C, Q, Space, P> where
+ Space: Freeze,
+ C: SearchTreeVisitor<Space>,
+ Q: Multiset + Collection<Item=Branch<Space>>,
+ P: RestartPolicy
+{
+  pub fn new(cutoff: Cutoff, policy: P, child: C) -> Restart<C, Q, Space, P>
+  {
+    Restart {
+      child,
+      num_restarts: 0,
+      queue: Q::empty(),
+      policy,
+      cutoff,
+      limit: 0,
+      count: 0,
+      root: None,
+      started_exploration: false,
+      phantom_space: PhantomData
+    }
+  }
+
+  fn push_branches(&mut self, branches: Vec<Branch<Space>>)
+  {
+    // For traversing the tree from left to right.
+    for branch in branches.into_iter().rev() {
+      self.queue.insert(branch);
+    }
+  }
+
+  fn enter_child(&mut self, current: Space, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    let (immutable_state, child_status) = self.child.enter(current);
+    if self.cutoff == Cutoff::Nodes || child_status == Unsatisfiable {
+      self.count += 1;
+    }
+    match child_status {
+      Unknown(ref branches) if branches.is_empty() => *status = Status::pruned(),
+      Unknown(branches) => self.push_branches(branches),
+      Satisfiable => *status = Satisfiable,
+      EndOfSearch => *status = EndOfSearch,
+      _ => ()
+    }
+    immutable_state
+  }
+
+  /// Explores a new tree from `root`, which is kept in a branch without alternative to restart from it later.
+  fn enter_root(&mut self, root: Space, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    let (immutable_state, mut branches) = Branch::distribute(root,
+      vec![Box::new(|_: &mut Space| ()) as Box<dyn Fn(&mut Space)>, Box::new(|_: &mut Space| ())]);
+    self.root = branches.pop();
+    let root = branches.pop().unwrap().commit(immutable_state);
+    self.queue = Q::empty();
+    self.count = 0;
+    self.limit = self.policy.next_cutoff();
+    self.enter_child(root, status)
+  }
+
+  fn restart(&mut self, immutable_state: Space::FrozenState, status: &mut Status<Space>) -> Space::FrozenState
+  {
+    self.num_restarts += 1;
+    let root = self.root.take().unwrap().commit(immutable_state);
+    self.enter_root(root, status)
+  }
+}
+
+impl<C, Q, Space, P> SearchTreeVisitor<Space> for Restart<C, Q, Space, P> where
+ Space: Freeze,
+ C: SearchTreeVisitor<Space>,
+ Q: Multiset + Collection<Item=Branch<Space>>,
+ P: RestartPolicy
+{
+  fn start(&mut self, root: &Space) {
+    self.num_restarts = 0;
+    self.policy.reset();
+    self.queue = Q::empty();
+    self.root = None;
+    self.started_exploration = false;
+    self.child.start(root);
+  }
+
+  fn enter(&mut self, root: Space) -> (Space::FrozenState, Status<Space>) {
+    let mut status = Unsatisfiable;
+    let mut immutable_state =
+      if !self.started_exploration {
+        self.started_exploration = true;
+        self.enter_root(root, &mut status)
+      }
+      else {
+        root.freeze()
+      };
+    while status != EndOfSearch && status != Satisfiable {
+      if self.queue.is_empty() {
+        // The tree was fully explored before reaching the cutoff.
+        status = EndOfSearch;
+      }
+      else if self.count >= self.limit {
+        immutable_state = self.restart(immutable_state, &mut status);
+      }
+      else {
+        let branch = self.queue.extract().unwrap();
+        let child = branch.commit(immutable_state);
+        immutable_state = self.enter_child(child, &mut status);
+      }
+    }
+    (immutable_state, status)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use concept::*;
+  use search::test::*;
+  use search::FDSpace;
+  use search::VStore;
+  use search::monitor::*;
+  use search::statistics::*;
+  use search::engine::all_solution::*;
+  use search::branch_and_bound::*;
+  use search::propagation::*;
+  use search::branching::*;
+  use propagators::linear::*;
+  use term::LinearExpr;
+  use interval::interval_set::*;
+  use gcollections::VectorStack;
+  use gcollections::ops::*;
+
+  #[test]
+  fn policies_test() {
+    let mut policy = Luby::new(2);
+    let cutoffs: Vec<usize> = (0..15).map(|_| policy.next_cutoff()).collect();
+    assert_eq!(cutoffs, vec![2, 2, 4, 2, 2, 4, 8, 2, 2, 4, 2, 2, 4, 8, 16]);
+    let mut policy = Geometric::new(2, 1.5);
+    let cutoffs: Vec<usize> = (0..4).map(|_| policy.next_cutoff()).collect();
+    assert_eq!(cutoffs, vec![2, 3, 4, 6]);
+    policy.reset();
+    assert_eq!(policy.next_cutoff(), 2);
+    let mut policy = Fixed::new(5);
+    assert_eq!(policy.next_cutoff(), 5);
+    assert_eq!(policy.next_cutoff(), 5);
+  }
+
+  #[test]
+  #[should_panic]
+  fn zero_cutoff_test() {
+    Fixed::new(0);
+  }
+
+  #[test]
+  #[should_panic]
+  fn zero_scale_test() {
+    Luby::new(0);
+  }
+
+  #[test]
+  fn restart_nqueens_test() {
+    for n in 1..9 {
+      test_nqueens(n, Cutoff::Failures, Luby::new(1));
+      test_nqueens(n, Cutoff::Nodes, Geometric::new(2, 2.0));
+    }
+  }
+
+  fn test_nqueens<P: RestartPolicy>(n: usize, cutoff: Cutoff, policy: P) {
+    let mut space = FDSpace::empty();
+    nqueens(n, &mut space);
+    let mut statistics = Statistics::new();
+    let num_restarts = {
+      let mut search: Restart<_, VectorStack<_>, FDSpace, _> =
+        Restart::new(cutoff, policy, Monitor::new(&mut statistics,
+          Propagation::new(Brancher::new(FirstSmallestVar, MiddleVal, BinarySplit))));
+      search.start(&space);
+      let (_, status) = search.enter(space);
+      let expect = if n == 2 || n == 3 { EndOfSearch } else { Satisfiable };
+      assert_eq!(status, expect);
+      search.num_restarts
+    };
+    // Each run explores at least the root.
+    assert!(statistics.num_nodes > num_restarts);
+    if n == 3 {
+      assert!(num_restarts > 0);
+    }
+  }
+
+  #[test]
+  fn restart_twice_test() {
+    let mut search: Restart<_, VectorStack<_>, FDSpace, _> =
+      Restart::new(Cutoff::Failures, Luby::new(1),
+        Propagation::new(Brancher::new(FirstSmallestVar, MiddleVal, BinarySplit)));
+    let mut num_restarts = vec![];
+    // The second search starts again from the first cutoff of the policy.
+    for _ in 0..2 {
+      let mut space = FDSpace::empty();
+      nqueens(3, &mut space);
+      search.start(&space);
+      let (_, status) = search.enter(space);
+      assert_eq!(status, EndOfSearch);
+      num_restarts.push(search.num_restarts);
+    }
+    assert!(num_restarts[0] > 0);
+    assert_eq!(num_restarts[0], num_restarts[1]);
+  }
+
+  #[test]
+  fn restart_knapsack_test() {
+    let mut space = FDSpace::empty();
+    let items: Vec<Var<VStore>> = (0..3)
+      .map(|_| Box::new(space.vstore.alloc((0, 2).to_interval_set())) as Var<VStore>)
+      .collect();
+    space.cstore.alloc(Box::new(Linear::new(
+      vec![3, 4, 5],
+      items.iter().map(|x| x.bclone()).collect(),
+      LinearRel::Leq,
+      10)));
+    let profit = Box::new(LinearExpr::new(vec![4, 5, 6], items)) as Var<VStore>;
+
+    let mut search: AllSolution<Restart<_, VectorStack<_>, FDSpace, _>> =
+      AllSolution::new(Restart::new(Cutoff::Failures, Luby::new(1), BranchAndBound::new(
+        Mode::Maximize,
+        profit,
+        Propagation::new(Brancher::new(FirstSmallestVar, MiddleVal, BinarySplit)))));
+    search.start(&space);
+    let (_, status) = search.enter(space);
+    assert_eq!(status, EndOfSearch);
+    assert_eq!(search.child.child.value, Some(13));
+  }
+}
@@ -59,9 +59,17 @@ mod test {
     use interval::interval_set::*;
     use propagators::cmp::*;
     use propagators::distinct::*;
+    use search::engine::all_solution::*;
+    use search::monitor::*;
+    use search::statistics::*;
     use term::*;
 
     pub fn nqueens(n: usize, space: &mut FDSpace) {
+        nqueens_with_strength(n, space, DistinctStrength::Decomposition);
+    }
+
+    /// Same as `nqueens` where the columns constraint is propagated with `strength`.
+    pub fn nqueens_with_strength(n: usize, space: &mut FDSpace, strength: DistinctStrength) {
         let mut queens: Vec<Var<VStore>> = vec![];
         // 2 queens can't share the same line.
         for _ in 0..n {
@@ -87,6 +95,42 @@ mod test {
             }
         }
         // 2 queens can't share the same column.
-        space.cstore.alloc(Box::new(Distinct::new(queens)));
+        space
+            .cstore
+            .alloc(Box::new(Distinct::with_strength(queens, strength)));
+    }
+
+    /// Explores the whole search tree of the `n`-queens with a static variable ordering, returns the number of solutions and of nodes.
+    fn explore_nqueens(n: usize, strength: DistinctStrength) -> (usize, usize) {
+        let mut space = FDSpace::empty();
+        nqueens_with_strength(n, &mut space, strength);
+        let mut statistics = Statistics::new();
+        {
+            let mut search: AllSolution<OneSolution<_, VectorStack<_>, FDSpace>> =
+                AllSolution::new(OneSolution::new(Monitor::new(
+                    &mut statistics,
+                    Propagation::new(Brancher::new(InputOrder, MinVal, Enumerate)),
+                )));
+            search.start(&space);
+            let (_, status) = search.enter(space);
+            assert_eq!(status, Status::EndOfSearch);
+        }
+        (statistics.num_solution, statistics.num_nodes)
+    }
+
+    #[test]
+    fn distinct_strengths_nqueens() {
+        for n in 4..9 {
+            let (sols, decomposition) = explore_nqueens(n, DistinctStrength::Decomposition);
+            let (bounds_sols, bounds) = explore_nqueens(n, DistinctStrength::Bounds);
+            let (domain_sols, domain) = explore_nqueens(n, DistinctStrength::Domain);
+            assert_eq!(sols, bounds_sols);
+            assert_eq!(sols, domain_sols);
+            // With a static variable ordering, a stronger propagation explores a subtree.
+            assert!(domain <= bounds && bounds <= decomposition);
+            if n >= 6 {
+                assert!(domain < decomposition);
+            }
+        }
     }
 }
@@ -13,37 +13,63 @@
 // limitations under the License.
 
 use kernel::*;
+use trilean::SKleene;
 use trilean::SKleene::*;
 use search::space::*;
 use search::search_tree_visitor::*;
 use concept::*;
 
-pub struct Propagation<C> {
-  child: C
+/// Observes the propagation of each node, for the search heuristics learning from domain reductions.
+pub trait PropagationObserver<Space>
+{
+  /// Called once with the root of the search tree, before the exploration starts.
+  fn start(&mut self, _root: &Space) {}
+  /// Called before the propagation of `space`.
+  fn before_propagation(&mut self, _space: &Space) {}
+  /// Called after the propagation of `space`, where `status` is the result of the consistency check.
+  fn after_propagation(&mut self, _space: &Space, _status: SKleene) {}
+}
+
+impl<Space> PropagationObserver<Space> for () {}
+
+pub struct Propagation<C, O = ()> {
+  child: C,
+  observer: O
 }
 
 impl<C> Propagation<C> {
   pub fn new(child: C) -> Propagation<C> {
+    Propagation::with_observer((), child)
+  }
+}
+
+impl<C, O> Propagation<C, O> {
+  pub fn with_observer(observer: O, child: C) -> Propagation<C, O> {
     Propagation {
-      child: child
+      child,
+      observer
     }
   }
 }
 
-impl<VStore, CStore, R, C> SearchTreeVisitor<Space<VStore, CStore, R>> for Propagation<C> where
+impl<VStore, CStore, R, C, O> SearchTreeVisitor<Space<VStore, CStore, R>> for Propagation<C, O> where
   VStore: VStoreConcept,
   CStore: IntCStore<VStore>,
   C: SearchTreeVisitor<Space<VStore, CStore, R>>,
+  O: PropagationObserver<Space<VStore, CStore, R>>,
   R: FreezeSpace<VStore, CStore> + Snapshot<State=Space<VStore, CStore, R>>
 {
   fn start(&mut self, root: &Space<VStore, CStore, R>) {
+    self.observer.start(root);
     self.child.start(root);
   }
 
   fn enter(&mut self, mut current: Space<VStore, CStore, R>)
     -> (<Space<VStore, CStore, R> as Freeze>::FrozenState, Status<Space<VStore, CStore, R>>)
   {
+    self.observer.before_propagation(&current);
     let status = current.consistency();
+    self.observer.after_propagation(&current, status);
     match status {
       True => (current.freeze(), Status::Satisfiable),
       False => (current.freeze(), Status::Unsatisfiable),
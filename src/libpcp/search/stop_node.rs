@@ -40,6 +40,7 @@ impl<VStore, CStore, R, C> SearchTreeVisitor<Space<VStore, CStore, R>> for StopN
   R: FreezeSpace<VStore, CStore> + Snapshot<State=Space<VStore, CStore, R>>
 {
   fn start(&mut self, root: &Space<VStore, CStore, R>) {
+    self.nodes_explored = 0;
     self.child.start(root);
   }
 
@@ -95,4 +96,25 @@ mod test {
     }
     assert_eq!(statistics.num_nodes, nodes_limit);
   }
+
+  #[test]
+  fn test_stop_started_twice() {
+    let nodes_limit = 10;
+    let mut statistics = Statistics::new();
+    {
+      let mut search: OneSolution<_, VectorStack<_>, FDSpace> =
+        OneSolution::new(
+          Monitor::new(&mut statistics, StopNode::new(nodes_limit,
+            Propagation::new(Brancher::new(FirstSmallestVar, MiddleVal, BinarySplit)))));
+      // Starting the search again resets the node count.
+      for _ in 0..2 {
+        let mut space = FDSpace::empty();
+        nqueens(6, &mut space);
+        search.start(&space);
+        let (_, status) = search.enter(space);
+        assert_eq!(status, Status::EndOfSearch);
+      }
+    }
+    assert_eq!(statistics.num_nodes, 2 * nodes_limit);
+  }
 }
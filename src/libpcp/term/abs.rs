@@ -0,0 +1,146 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::kind::*;
+use kernel::*;
+use model::*;
+use propagation::events::*;
+use term::ops::*;
+
+/// View on `|x|`.
+/// Reading the view gives the bounds of the absolute value, and updating it filters the bounds of `x` (bounds consistency).
+/// The values of `x` strictly between `-lower` and `lower` are also removed if the domain can represent holes.
+#[derive(Debug)]
+pub struct Abs<VStore> {
+    x: Var<VStore>,
+}
+
+impl<VStore> Abs<VStore> {
+    pub fn new(x: Var<VStore>) -> Self {
+        Abs { x }
+    }
+}
+
+impl<VStore> Clone for Abs<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Abs::new(self.x.bclone())
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Abs<VStore> {
+    fn display(&self, model: &Model) {
+        print!("|");
+        self.x.display(model);
+        print!("|");
+    }
+}
+
+impl<VStore, Domain, Bound> StoreMonotonicUpdate<VStore> for Abs<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn update(&mut self, store: &mut VStore, value: Domain) -> bool {
+        let value = value.shrink_left(Bound::zero());
+        if value.is_empty() {
+            return false;
+        }
+        let upper = value.upper();
+        let lower = value.lower();
+        let mut x = self
+            .x
+            .read(store)
+            .shrink_left(-upper.clone())
+            .shrink_right(upper);
+        if lower > Bound::zero() {
+            let one = Bound::one();
+            x = x.difference(&Domain::new(-lower.clone() + one.clone(), lower - one));
+        }
+        self.x.update(store, x)
+    }
+}
+
+impl<VStore, Domain, Bound> StoreRead<VStore> for Abs<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read(&self, store: &VStore) -> Domain {
+        let x = self.x.read(store);
+        if x.is_empty() {
+            return Domain::empty();
+        }
+        let positive = x.clone().shrink_left(Bound::zero());
+        let negative = x.clone().shrink_right(Bound::zero());
+        // The smallest absolute value is the closest value to zero on either side.
+        let lower = match (positive.is_empty(), negative.is_empty()) {
+            (false, false) => ::std::cmp::min(positive.lower(), -negative.upper()),
+            (false, true) => positive.lower(),
+            _ => -negative.upper(),
+        };
+        let upper = ::std::cmp::max(-x.lower(), x.upper());
+        Domain::new(lower, upper)
+    }
+}
+
+impl<VStore> ViewDependencies<FDEvent> for Abs<VStore> {
+    fn dependencies(&self, event: FDEvent) -> Vec<(usize, FDEvent)> {
+        // Removing values around zero changes the lower bound of the view.
+        let event = match event {
+            FDEvent::Assignment => FDEvent::Assignment,
+            _ => FDEvent::Inner,
+        };
+        self.x.dependencies(event)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use variable::{VStoreFD, VStoreSet};
+
+    #[test]
+    fn abs_read_update() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((-5, 3).to_interval())) as Var<VStoreFD>;
+        let mut abs = Abs::new(x.bclone());
+        assert_eq!(abs.read(&store), (0, 5).to_interval());
+        assert!(abs.update(&mut store, (2, 4).to_interval()));
+        assert_eq!(x.read(&store), (-4, 3).to_interval());
+        assert!(x.bclone().update(&mut store, (-1, 3).to_interval()));
+        assert!(abs.update(&mut store, (2, 4).to_interval()));
+        assert_eq!(x.read(&store), (2, 3).to_interval());
+        assert_eq!(abs.read(&store), (2, 3).to_interval());
+        assert!(!abs.update(&mut store, (-3, -1).to_interval()));
+    }
+
+    #[test]
+    fn abs_holes() {
+        let mut store = VStoreSet::empty();
+        let x = Box::new(store.alloc(vec![(-7, -3), (4, 9)].to_interval_set())) as Var<VStoreSet>;
+        let mut abs = Abs::new(x.bclone());
+        assert_eq!(abs.read(&store), (3, 9).to_interval_set());
+        assert!(abs.update(&mut store, (5, 6).to_interval_set()));
+        assert_eq!(x.read(&store), vec![(-6, -5), (5, 6)].to_interval_set());
+    }
+}
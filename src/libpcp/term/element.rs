@@ -0,0 +1,231 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::kind::*;
+use kernel::*;
+use model::*;
+use propagation::events::*;
+use propagators::values::*;
+use term::constant::Constant;
+use term::ops::*;
+
+/// View on `array[index]` where the positions of `array` start at 0.
+/// Reading the view gives the union of the elements at the positions still in the domain of `index` (approximated by its bounds if these elements are not all assigned).
+/// Updating the view removes from `index` the positions of the elements not compatible with the new value, and updates the element itself once `index` is assigned.
+#[derive(Debug)]
+pub struct Element<VStore> {
+    index: Var<VStore>,
+    array: Vec<Var<VStore>>,
+}
+
+impl<VStore> Element<VStore> {
+    /// Precondition: `array.len() > 0`.
+    pub fn new(index: Var<VStore>, array: Vec<Var<VStore>>) -> Self {
+        assert!(!array.is_empty(), "Array in `Element` must be non-empty.");
+        Element { index, array }
+    }
+}
+
+impl<VStore, Domain, Bound> Element<VStore>
+where
+    VStore: VStoreConcept<Item = Domain> + 'static,
+    Domain: IntDomain<Item = Bound> + 'static,
+    Bound: IntBound + 'static,
+{
+    /// View on `values[index]`.
+    pub fn constant(index: Var<VStore>, values: Vec<Bound>) -> Self {
+        let array = values
+            .into_iter()
+            .map(|v| Box::new(Constant::new(v)) as Var<VStore>)
+            .collect();
+        Element::new(index, array)
+    }
+}
+
+impl<VStore, Domain, Bound> Element<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Positions of `array` in the domain of `index`, given as pairs `(i, b)` where `b` is the bound representation of `i`.
+    fn positions(&self, store: &VStore) -> Vec<(usize, Bound)> {
+        let index = self.index.read(store);
+        let mut b = Bound::zero();
+        let mut positions = vec![];
+        for i in 0..self.array.len() {
+            if index.contains(&b) {
+                positions.push((i, b.clone()));
+            }
+            b = b + Bound::one();
+        }
+        positions
+    }
+}
+
+impl<VStore> Clone for Element<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Element::new(
+            self.index.bclone(),
+            self.array.iter().map(|v| v.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Element<VStore> {
+    fn display(&self, model: &Model) {
+        print!("[");
+        for (i, v) in self.array.iter().enumerate() {
+            if i > 0 {
+                print!(", ");
+            }
+            v.display(model);
+        }
+        print!("][");
+        self.index.display(model);
+        print!("]");
+    }
+}
+
+impl<VStore, Domain, Bound> StoreMonotonicUpdate<VStore> for Element<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn update(&mut self, store: &mut VStore, value: Domain) -> bool {
+        let supported: Vec<(usize, Bound)> = self
+            .positions(store)
+            .into_iter()
+            .filter(|&(i, _)| self.array[i].read(store).overlap(&value))
+            .collect();
+        let index = self.index.read(store);
+        let positions: Vec<Bound> = supported.iter().map(|p| p.1.clone()).collect();
+        if !self
+            .index
+            .update(store, restrict_to_values(index, &positions))
+        {
+            return false;
+        }
+        if supported.len() == 1 {
+            let i = supported[0].0;
+            let element = self.array[i].read(store);
+            self.array[i].update(store, element.intersection(&value))
+        } else {
+            true
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> StoreRead<VStore> for Element<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read(&self, store: &VStore) -> Domain {
+        let elements: Vec<Domain> = self
+            .positions(store)
+            .into_iter()
+            .map(|(i, _)| self.array[i].read(store))
+            .collect();
+        if elements.iter().all(|e| e.is_singleton()) {
+            let mut values: Vec<Bound> = elements.iter().map(|e| e.lower()).collect();
+            values.sort();
+            values.dedup();
+            from_values(&values)
+        } else {
+            let lower = elements.iter().map(|e| e.lower()).min().unwrap();
+            let upper = elements.iter().map(|e| e.upper()).max().unwrap();
+            Domain::new(lower, upper)
+        }
+    }
+}
+
+impl<VStore> ViewDependencies<FDEvent> for Element<VStore> {
+    fn dependencies(&self, event: FDEvent) -> Vec<(usize, FDEvent)> {
+        // Any position removed from `index` can change the value of the view.
+        let mut deps = self.index.dependencies(FDEvent::Inner);
+        for v in &self.array {
+            deps.append(&mut v.dependencies(event));
+        }
+        deps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use interval::interval_set::*;
+    use propagation::events::FDEvent::*;
+    use propagators::cmp::*;
+    use propagators::test::*;
+    use trilean::SKleene::*;
+    use variable::{VStoreFD, VStoreSet};
+
+    #[test]
+    fn element_read_update() {
+        let mut store = VStoreSet::empty();
+        let i = Box::new(store.alloc((0, 3).to_interval_set())) as Var<VStoreSet>;
+        let mut view = Element::constant(i.bclone(), vec![5, 1, 5, 9]);
+        assert_eq!(
+            view.read(&store),
+            vec![(1, 1), (5, 5), (9, 9)].to_interval_set()
+        );
+        assert!(view.update(&mut store, (4, 8).to_interval_set()));
+        assert_eq!(i.read(&store), vec![(0, 0), (2, 2)].to_interval_set());
+        assert_eq!(view.read(&store), (5, 5).to_interval_set());
+        assert!(!view.update(&mut store, (6, 8).to_interval_set()));
+    }
+
+    #[test]
+    fn element_variable_array() {
+        let mut store = VStoreFD::empty();
+        let i = Box::new(store.alloc((0, 5).to_interval())) as Var<VStoreFD>;
+        let a = Box::new(store.alloc((0, 3).to_interval())) as Var<VStoreFD>;
+        let b = Box::new(store.alloc((5, 8).to_interval())) as Var<VStoreFD>;
+        let mut view = Element::new(i.bclone(), vec![a.bclone(), b.bclone()]);
+        assert_eq!(view.read(&store), (0, 8).to_interval());
+        // Only `b` can be greater than 4, so the index is fixed and `b` is updated.
+        assert!(view.update(&mut store, (4, 6).to_interval()));
+        assert_eq!(i.read(&store), (1, 1).to_interval());
+        assert_eq!(b.read(&store), (5, 6).to_interval());
+        assert_eq!(a.read(&store), (0, 3).to_interval());
+    }
+
+    #[test]
+    fn x_less_element() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((4, 10).to_interval())) as Var<VStoreFD>;
+        let i = Box::new(store.alloc((0, 2).to_interval())) as Var<VStoreFD>;
+        let view = Box::new(Element::constant(i, vec![3, 6, 8])) as Var<VStoreFD>;
+        test_propagation(
+            1,
+            XLessY::new(x, view),
+            &mut store,
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (1, Bound)],
+            true,
+        );
+        assert_eq!(store[0], (4, 7).to_interval());
+        assert_eq!(store[1], (1, 2).to_interval());
+    }
+}
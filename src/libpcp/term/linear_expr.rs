@@ -0,0 +1,311 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::kind::*;
+use kernel::*;
+use model::*;
+use propagation::events::*;
+use std::fmt::{Debug, Formatter, Result};
+use term::ops::*;
+
+/// View on the scalar product `coefs[0] * vars[0] + ... + coefs[n-1] * vars[n-1]`.
+/// Reading the view gives the bounds of the expression, and updating it filters the bounds of the variables (bounds consistency) in `O(n)`.
+pub struct LinearExpr<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    coefs: Vec<<VStore::Item as Collection>::Item>,
+    vars: Vec<Var<VStore>>,
+}
+
+impl<VStore, Domain, Bound> LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+{
+    /// Precondition: `coefs.len() == vars.len()`.
+    pub fn new(coefs: Vec<Bound>, vars: Vec<Var<VStore>>) -> Self {
+        assert_eq!(
+            coefs.len(),
+            vars.len(),
+            "`LinearExpr` must have one coefficient per variable."
+        );
+        LinearExpr { coefs, vars }
+    }
+}
+
+impl<VStore, Domain, Bound> LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    /// Bounds of `coefs[i] * vars[i]`.
+    fn term_bounds(&self, store: &VStore, i: usize) -> (Bound, Bound) {
+        let dom = self.vars[i].read(store);
+        let a = self.coefs[i].clone();
+        let (l, u) = (a.clone() * dom.lower(), a * dom.upper());
+        if l <= u {
+            (l, u)
+        } else {
+            (u, l)
+        }
+    }
+
+    fn bounds(&self, store: &VStore) -> (Bound, Bound) {
+        (0..self.vars.len()).fold((Bound::zero(), Bound::zero()), |(l, u), i| {
+            let (tl, tu) = self.term_bounds(store, i);
+            (l + tl, u + tu)
+        })
+    }
+
+    /// Filters the variables such that `coefs[i] * vars[i] <= ub - (min - min_i)` where `min` is the lower bound of the expression and `min_i` the one of the term `i`.
+    fn filter_upper(&mut self, store: &mut VStore, ub: Bound) -> bool {
+        let min = self.bounds(store).0;
+        if min > ub {
+            return false;
+        }
+        for i in 0..self.vars.len() {
+            let (term_min, term_max) = self.term_bounds(store, i);
+            let slack = ub.clone() - (min.clone() - term_min);
+            if term_max > slack {
+                let a = self.coefs[i].clone();
+                let dom = self.vars[i].read(store);
+                let restricted = if a > Bound::zero() {
+                    Domain::new(dom.lower(), slack.div_floor(&a))
+                } else {
+                    Domain::new(slack.div_ceil(&a), dom.upper())
+                };
+                if !self.vars[i].update(store, dom.intersection(&restricted)) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Symmetric to `filter_upper`: `coefs[i] * vars[i] >= lb - (max - max_i)`.
+    fn filter_lower(&mut self, store: &mut VStore, lb: Bound) -> bool {
+        let max = self.bounds(store).1;
+        if max < lb {
+            return false;
+        }
+        for i in 0..self.vars.len() {
+            let (term_min, term_max) = self.term_bounds(store, i);
+            let slack = lb.clone() - (max.clone() - term_max);
+            if term_min < slack {
+                let a = self.coefs[i].clone();
+                let dom = self.vars[i].read(store);
+                let restricted = if a > Bound::zero() {
+                    Domain::new(slack.div_ceil(&a), dom.upper())
+                } else {
+                    Domain::new(dom.lower(), slack.div_floor(&a))
+                };
+                if !self.vars[i].update(store, dom.intersection(&restricted)) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Removes `value` from the expression when all its variables but one are assigned (forward checking).
+    /// Returns `false` if the expression is assigned to `value`.
+    pub fn exclude(&mut self, store: &mut VStore, value: Bound) -> bool {
+        let mut unassigned = None;
+        let mut rest = Bound::zero();
+        for i in 0..self.vars.len() {
+            let dom = self.vars[i].read(store);
+            if self.coefs[i].is_zero() {
+                continue;
+            } else if dom.is_singleton() {
+                rest = rest + self.coefs[i].clone() * dom.lower();
+            } else if unassigned.is_none() {
+                unassigned = Some(i);
+            } else {
+                return true;
+            }
+        }
+        match unassigned {
+            None => rest != value,
+            Some(i) => {
+                let a = self.coefs[i].clone();
+                let remainder = value - rest;
+                if (remainder.clone() % a.clone()).is_zero() {
+                    let dom = self.vars[i].read(store);
+                    let removed = dom.difference(&(remainder / a));
+                    self.vars[i].update(store, removed)
+                } else {
+                    true
+                }
+            }
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> Debug for LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        fmt.debug_struct("LinearExpr")
+            .field("coefs", &self.coefs)
+            .field("vars", &self.vars)
+            .finish()
+    }
+}
+
+impl<VStore, Domain, Bound> Clone for LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Clone,
+{
+    fn clone(&self) -> Self {
+        LinearExpr::new(
+            self.coefs.clone(),
+            self.vars.iter().map(|v| v.bclone()).collect(),
+        )
+    }
+}
+
+impl<VStore, Domain, Bound> DisplayStateful<Model> for LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: Collection<Item = Bound>,
+    Bound: Debug,
+{
+    fn display(&self, model: &Model) {
+        for (i, (a, v)) in self.coefs.iter().zip(self.vars.iter()).enumerate() {
+            if i > 0 {
+                print!(" + ");
+            }
+            print!("{:?} * ", a);
+            v.display(model);
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> StoreMonotonicUpdate<VStore> for LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn update(&mut self, store: &mut VStore, value: Domain) -> bool {
+        !value.is_empty()
+            && self.filter_upper(store, value.upper())
+            && self.filter_lower(store, value.lower())
+    }
+}
+
+impl<VStore, Domain, Bound> StoreRead<VStore> for LinearExpr<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read(&self, store: &VStore) -> Domain {
+        let (lb, ub) = self.bounds(store);
+        Domain::new(lb, ub)
+    }
+}
+
+impl<VStore> ViewDependencies<FDEvent> for LinearExpr<VStore>
+where
+    VStore: VStoreConcept,
+    VStore::Item: Collection,
+{
+    fn dependencies(&self, event: FDEvent) -> Vec<(usize, FDEvent)> {
+        self.vars
+            .iter()
+            .flat_map(|v| v.dependencies(event))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use propagation::events::FDEvent::*;
+    use propagators::cmp::*;
+    use propagators::test::*;
+    use term::constant::Constant;
+    use trilean::SKleene::*;
+    use variable::VStoreFD;
+
+    #[test]
+    fn linear_expr_read_update() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((0, 10).to_interval())) as Var<VStoreFD>;
+        let y = Box::new(store.alloc((-2, 3).to_interval())) as Var<VStoreFD>;
+        let mut expr = LinearExpr::new(vec![2, -3], vec![x.bclone(), y.bclone()]);
+        assert_eq!(expr.read(&store), (-9, 26).to_interval());
+        // 2x - 3y in [20, 30]
+        assert!(expr.update(&mut store, (20, 30).to_interval()));
+        assert_eq!(x.read(&store), (7, 10).to_interval());
+        assert_eq!(y.read(&store), (-2, 0).to_interval());
+        assert!(!expr.update(&mut store, (27, 30).to_interval()));
+    }
+
+    #[test]
+    fn linear_expr_exclude() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((1, 1).to_interval())) as Var<VStoreFD>;
+        let y = Box::new(store.alloc((0, 3).to_interval())) as Var<VStoreFD>;
+        let mut expr = LinearExpr::new(vec![2, 3], vec![x.bclone(), y.bclone()]);
+        // 2 + 3y != 11 removes the upper bound of `y`.
+        assert!(expr.exclude(&mut store, 11));
+        assert_eq!(y.read(&store), (0, 2).to_interval());
+        // 2 + 3y != 4 does not remove anything.
+        assert!(expr.exclude(&mut store, 4));
+        assert_eq!(y.read(&store), (0, 2).to_interval());
+    }
+
+    #[test]
+    fn x_less_linear_expr() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((3, 10).to_interval())) as Var<VStoreFD>;
+        let y = Box::new(store.alloc((0, 2).to_interval())) as Var<VStoreFD>;
+        let z = Box::new(store.alloc((0, 2).to_interval())) as Var<VStoreFD>;
+        let expr = Box::new(LinearExpr::new(vec![1, 2], vec![y, z])) as Var<VStoreFD>;
+        let bound = Box::new(Constant::new(4isize)) as Var<VStoreFD>;
+        // x < y + 2z < 4
+        test_propagation(
+            1,
+            XLessY::new(x, expr.bclone()),
+            &mut store,
+            Unknown,
+            Unknown,
+            vec![(0, Bound), (2, Bound)],
+            true,
+        );
+        test_propagation(
+            2,
+            XLessY::new(expr, bound),
+            &mut store,
+            Unknown,
+            True,
+            vec![(1, Bound), (2, Assignment)],
+            true,
+        );
+        assert_eq!(store[1], (0, 1).to_interval());
+    }
+}
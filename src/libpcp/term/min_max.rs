@@ -0,0 +1,230 @@
+// Copyright 2026 Pierre Talbot (IRCAM)
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use concept::*;
+use gcollections::kind::*;
+use kernel::*;
+use model::*;
+use propagation::events::*;
+use term::ops::*;
+
+/// The bound of a view over an array changes whenever the bound of one of its variables changes, even if none of them is assigned.
+fn array_dependencies<VStore>(vars: &[Var<VStore>], event: FDEvent) -> Vec<(usize, FDEvent)> {
+    let event = match event {
+        FDEvent::Assignment => FDEvent::Bound,
+        e => e,
+    };
+    vars.iter().flat_map(|v| v.dependencies(event)).collect()
+}
+
+/// View on `min(vars[0], ..., vars[n-1])`.
+/// Reading the view gives the bounds of the minimum, and updating it filters the bounds of the variables (bounds consistency).
+#[derive(Debug)]
+pub struct Min<VStore> {
+    vars: Vec<Var<VStore>>,
+}
+
+impl<VStore> Min<VStore> {
+    /// Precondition: `vars.len() > 0`.
+    pub fn new(vars: Vec<Var<VStore>>) -> Self {
+        assert!(!vars.is_empty(), "`Min` must have at least one variable.");
+        Min { vars }
+    }
+}
+
+impl<VStore> Clone for Min<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Min::new(self.vars.iter().map(|v| v.bclone()).collect())
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Min<VStore> {
+    fn display(&self, model: &Model) {
+        model.display_call("min", &self.vars);
+    }
+}
+
+impl<VStore, Domain, Bound> StoreMonotonicUpdate<VStore> for Min<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn update(&mut self, store: &mut VStore, value: Domain) -> bool {
+        if value.is_empty() {
+            return false;
+        }
+        // Every variable is greater than the minimum.
+        for v in &mut self.vars {
+            let dom = v.read(store).shrink_left(value.lower());
+            if !v.update(store, dom) {
+                return false;
+            }
+        }
+        // At least one variable is smaller than the upper bound of the minimum.
+        let candidates: Vec<usize> = (0..self.vars.len())
+            .filter(|&i| self.vars[i].read(store).lower() <= value.upper())
+            .collect();
+        match candidates.len() {
+            0 => false,
+            1 => {
+                let v = &mut self.vars[candidates[0]];
+                let dom = v.read(store).shrink_right(value.upper());
+                v.update(store, dom)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> StoreRead<VStore> for Min<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read(&self, store: &VStore) -> Domain {
+        let doms: Vec<Domain> = self.vars.iter().map(|v| v.read(store)).collect();
+        if doms.iter().any(|d| d.is_empty()) {
+            return Domain::empty();
+        }
+        let lower = doms.iter().map(|d| d.lower()).min().unwrap();
+        let upper = doms.iter().map(|d| d.upper()).min().unwrap();
+        Domain::new(lower, upper)
+    }
+}
+
+impl<VStore> ViewDependencies<FDEvent> for Min<VStore> {
+    fn dependencies(&self, event: FDEvent) -> Vec<(usize, FDEvent)> {
+        array_dependencies(&self.vars, event)
+    }
+}
+
+/// View on `max(vars[0], ..., vars[n-1])`.
+/// Reading the view gives the bounds of the maximum, and updating it filters the bounds of the variables (bounds consistency).
+#[derive(Debug)]
+pub struct Max<VStore> {
+    vars: Vec<Var<VStore>>,
+}
+
+impl<VStore> Max<VStore> {
+    /// Precondition: `vars.len() > 0`.
+    pub fn new(vars: Vec<Var<VStore>>) -> Self {
+        assert!(!vars.is_empty(), "`Max` must have at least one variable.");
+        Max { vars }
+    }
+}
+
+impl<VStore> Clone for Max<VStore>
+where
+    VStore: Collection,
+{
+    fn clone(&self) -> Self {
+        Max::new(self.vars.iter().map(|v| v.bclone()).collect())
+    }
+}
+
+impl<VStore> DisplayStateful<Model> for Max<VStore> {
+    fn display(&self, model: &Model) {
+        model.display_call("max", &self.vars);
+    }
+}
+
+impl<VStore, Domain, Bound> StoreMonotonicUpdate<VStore> for Max<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn update(&mut self, store: &mut VStore, value: Domain) -> bool {
+        if value.is_empty() {
+            return false;
+        }
+        // Every variable is smaller than the maximum.
+        for v in &mut self.vars {
+            let dom = v.read(store).shrink_right(value.upper());
+            if !v.update(store, dom) {
+                return false;
+            }
+        }
+        // At least one variable is greater than the lower bound of the maximum.
+        let candidates: Vec<usize> = (0..self.vars.len())
+            .filter(|&i| self.vars[i].read(store).upper() >= value.lower())
+            .collect();
+        match candidates.len() {
+            0 => false,
+            1 => {
+                let v = &mut self.vars[candidates[0]];
+                let dom = v.read(store).shrink_left(value.lower());
+                v.update(store, dom)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<VStore, Domain, Bound> StoreRead<VStore> for Max<VStore>
+where
+    VStore: VStoreConcept<Item = Domain>,
+    Domain: IntDomain<Item = Bound>,
+    Bound: IntBound,
+{
+    fn read(&self, store: &VStore) -> Domain {
+        let doms: Vec<Domain> = self.vars.iter().map(|v| v.read(store)).collect();
+        if doms.iter().any(|d| d.is_empty()) {
+            return Domain::empty();
+        }
+        let lower = doms.iter().map(|d| d.lower()).max().unwrap();
+        let upper = doms.iter().map(|d| d.upper()).max().unwrap();
+        Domain::new(lower, upper)
+    }
+}
+
+impl<VStore> ViewDependencies<FDEvent> for Max<VStore> {
+    fn dependencies(&self, event: FDEvent) -> Vec<(usize, FDEvent)> {
+        array_dependencies(&self.vars, event)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcollections::ops::*;
+    use interval::interval::*;
+    use variable::VStoreFD;
+
+    #[test]
+    fn min_max_read_update() {
+        let mut store = VStoreFD::empty();
+        let x = Box::new(store.alloc((2, 8).to_interval())) as Var<VStoreFD>;
+        let y = Box::new(store.alloc((5, 6).to_interval())) as Var<VStoreFD>;
+        let mut min = Min::new(vec![x.bclone(), y.bclone()]);
+        let mut max = Max::new(vec![x.bclone(), y.bclone()]);
+        assert_eq!(min.read(&store), (2, 6).to_interval());
+        assert_eq!(max.read(&store), (5, 8).to_interval());
+        // Only `x` can be greater than 7.
+        assert!(max.update(&mut store, (7, 10).to_interval()));
+        assert_eq!(x.read(&store), (7, 8).to_interval());
+        assert_eq!(y.read(&store), (5, 6).to_interval());
+        // Only `y` can be smaller than 6.
+        assert!(min.update(&mut store, (4, 6).to_interval()));
+        assert_eq!(y.read(&store), (5, 6).to_interval());
+        assert!(min.update(&mut store, (6, 6).to_interval()));
+        assert_eq!(y.read(&store), (6, 6).to_interval());
+        assert!(!min.update(&mut store, (7, 9).to_interval()));
+    }
+}
@@ -17,8 +17,16 @@ pub mod constant;
 pub mod addition;
 pub mod ops;
 pub mod sum;
+pub mod element;
+pub mod linear_expr;
+pub mod min_max;
+pub mod abs;
 
 pub use term::identity::Identity;
 pub use term::constant::Constant;
 pub use term::addition::Addition;
 pub use term::sum::Sum;
+pub use term::element::Element;
+pub use term::linear_expr::LinearExpr;
+pub use term::min_max::{Min, Max};
+pub use term::abs::Abs;